@@ -0,0 +1,312 @@
+//! Packed bit fields over an underlying integer.
+//!
+//! The `bitfield` module provides the [`bitfield!`](macro@crate::bitfield) macro,
+//! which declares a newtype over an unsigned integer with typed getters and
+//! setters for ranges of bits. The generated type implements `Readable`,
+//! `Peekable` and `Writable`, reading and writing the underlying integer with
+//! the endianness of the stream.
+//!
+//! # Reserved Bits
+//!
+//! Bits not covered by any field are reserved and must be zero. Reading a
+//! value with reserved bits set fails with [`Error::NotValid`](crate::error::Error::NotValid).
+//! Use `from_bits_retain()` to construct a value without this check.
+//!
+//! # Field Types
+//!
+//! Field values are converted with the [`FieldValue`] trait, implemented for
+//! `bool` and unsigned integers. Overlapping fields and ranges outside of the
+//! underlying integer are rejected at compile time.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{bitfield, common::Endian, error::Error, reader::ByteReader};
+//!
+//! bitfield! {
+//!     /// Header flag word.
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub struct Flags(u16) {
+//!         /// Record type, bits 0 to 3.
+//!         pub kind, set_kind: u8 [0..4],
+//!         /// Priority, bits 4 to 5.
+//!         pub priority, set_priority: u8 [4..6],
+//!         /// Last record marker, bit 15.
+//!         pub last, set_last: bool [15],
+//!     }
+//! }
+//!
+//! let mut reader = ByteReader::with_endian(&[0x80, 0x23, 0x00, 0x40], Endian::Big);
+//!
+//! let flags: Flags = reader.read().unwrap();
+//! assert_eq!(flags.kind(), 3);
+//! assert_eq!(flags.priority(), 2);
+//! assert!(flags.last());
+//!
+//! // Bit 14 is reserved
+//! assert!(matches!(reader.read::<Flags>(), Err(Error::NotValid)));
+//! ```
+
+/// A value that can be stored in a range of bits.
+///
+/// The bits are passed as `u64` aligned to the least significant bit. Values
+/// wider than the range are truncated when stored.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{bitfield, bitfield::FieldValue};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Mode {
+///     Off,
+///     On,
+///     Auto(u8),
+/// }
+///
+/// impl FieldValue for Mode {
+///     const BITS: u32 = 2;
+///
+///     fn from_field(bits: u64) -> Self {
+///         match bits {
+///             0 => Mode::Off,
+///             1 => Mode::On,
+///             other => Mode::Auto(other as u8),
+///         }
+///     }
+///
+///     fn into_field(self) -> u64 {
+///         match self {
+///             Mode::Off => 0,
+///             Mode::On => 1,
+///             Mode::Auto(value) => value as u64,
+///         }
+///     }
+/// }
+///
+/// bitfield! {
+///     struct Control(u8) {
+///         pub mode, set_mode: Mode [6..8],
+///     }
+/// }
+///
+/// let mut control = Control::from_bits_retain(0);
+/// control.set_mode(Mode::On);
+/// assert_eq!(control.bits(), 0b0100_0000);
+/// assert_eq!(control.mode(), Mode::On);
+/// ```
+pub trait FieldValue {
+    /// The maximum number of bits of the value.
+    const BITS: u32;
+
+    /// Converts the bits of the field into the value.
+    fn from_field(bits: u64) -> Self;
+
+    /// Converts the value into the bits of the field.
+    fn into_field(self) -> u64;
+}
+
+impl FieldValue for bool {
+    const BITS: u32 = 1;
+
+    fn from_field(bits: u64) -> Self {
+        bits != 0
+    }
+
+    fn into_field(self) -> u64 {
+        self as u64
+    }
+}
+
+macro_rules! impl_field_value {
+    ($Type:ty) => {
+        impl FieldValue for $Type {
+            const BITS: u32 = <$Type>::BITS;
+
+            fn from_field(bits: u64) -> Self {
+                bits as $Type
+            }
+
+            fn into_field(self) -> u64 {
+                self as u64
+            }
+        }
+    };
+}
+
+impl_field_value!(u8);
+impl_field_value!(u16);
+impl_field_value!(u32);
+impl_field_value!(u64);
+
+/// Declares a newtype over an unsigned integer with typed bit fields.
+///
+/// See the [module-level documentation](mod@crate::bitfield) for the description.
+///
+/// # Syntax
+///
+/// ```text
+/// bitfield! {
+///     #[attributes]
+///     visibility struct Name(UnsignedInteger) {
+///         visibility getter, setter: FieldType [bit],
+///         visibility getter, setter: FieldType [start..end],
+///     }
+/// }
+/// ```
+///
+/// Bit ranges are half-open, like Rust ranges, and bit `0` is the least
+/// significant bit. Besides the getters and setters, the macro generates:
+///
+/// - `const RESERVED`: The mask of bits not covered by any field
+/// - `fn from_bits(bits) -> Result<Self>`: Fails with [`Error::NotValid`](crate::error::Error::NotValid) if reserved bits are set
+/// - `const fn from_bits_retain(bits) -> Self`: Keeps all bits as is
+/// - `const fn bits(&self)`: Returns the underlying integer
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{bitfield, writer::ByteWriter};
+///
+/// bitfield! {
+///     #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+///     pub struct Status(u8) {
+///         pub code, set_code: u8 [0..3],
+///         pub ready, set_ready: bool [7],
+///     }
+/// }
+///
+/// let mut status = Status::default();
+/// status.set_code(5);
+/// status.set_ready(true);
+/// assert_eq!(status.bits(), 0b1000_0101);
+/// assert_eq!(Status::RESERVED, 0b0111_1000);
+/// assert!(Status::from_bits(0b0000_1000).is_err());
+///
+/// let mut buffer = [0u8; 1];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&status).unwrap();
+/// assert_eq!(buffer, [0x85]);
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (@mask $Int:ty, $bit:literal) => {
+        (1 as $Int) << $bit
+    };
+
+    (@mask $Int:ty, $start:literal, $end:literal) => {
+        (<$Int>::MAX >> (<$Int>::BITS - ($end - $start))) << $start
+    };
+
+    (@check $Int:ty, $Field:ty, $bit:literal) => {
+        ::core::assert!($bit < <$Int>::BITS, "bit is outside of the underlying integer");
+    };
+
+    (@check $Int:ty, $Field:ty, $start:literal, $end:literal) => {
+        ::core::assert!($start < $end, "bit range is empty");
+        ::core::assert!($end <= <$Int>::BITS, "bit range is outside of the underlying integer");
+        ::core::assert!(
+            $end - $start <= <$Field as $crate::bitfield::FieldValue>::BITS,
+            "bit range is wider than the field type"
+        );
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $Name:ident($Int:ty) {
+            $(
+                $(#[$fmeta:meta])*
+                $fvis:vis $get:ident, $set:ident: $Field:ty [$start:literal $(.. $end:literal)?]
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $Name($Int);
+
+        const _: () = {
+            let masks: &[$Int] = &[$($crate::bitfield!(@mask $Int, $start $(, $end)?)),*];
+            let mut used: $Int = 0;
+            let mut i: usize = 0;
+
+            $($crate::bitfield!(@check $Int, $Field, $start $(, $end)?);)*
+
+            while i < masks.len() {
+                ::core::assert!(used & masks[i] == 0, "bit fields overlap");
+                used |= masks[i];
+                i += 1;
+            }
+        };
+
+        impl $Name {
+            /// The mask of reserved bits, which are not covered by any field.
+            pub const RESERVED: $Int = !(0 $(| $crate::bitfield!(@mask $Int, $start $(, $end)?))*);
+
+            /// Creates a value from the underlying integer.
+            ///
+            /// Returns [`Error::NotValid`]($crate::error::Error::NotValid) if reserved bits are set.
+            pub fn from_bits(bits: $Int) -> $crate::error::Result<Self> {
+                match bits & Self::RESERVED {
+                    0 => ::core::result::Result::Ok(Self(bits)),
+                    _ => ::core::result::Result::Err($crate::error::Error::NotValid),
+                }
+            }
+
+            /// Creates a value from the underlying integer, keeping reserved bits.
+            pub const fn from_bits_retain(bits: $Int) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the underlying integer.
+            pub const fn bits(&self) -> $Int {
+                self.0
+            }
+
+            $(
+                $(#[$fmeta])*
+                $fvis fn $get(&self) -> $Field {
+                    let mask: $Int = $crate::bitfield!(@mask $Int, $start $(, $end)?);
+                    <$Field as $crate::bitfield::FieldValue>::from_field(((self.0 & mask) >> $start) as u64)
+                }
+
+                #[doc = ::core::concat!("Sets the value returned by [`", ::core::stringify!($get), "()`](Self::", ::core::stringify!($get), ").")]
+                ///
+                /// Bits of the value outside of the field range are discarded.
+                $fvis fn $set(&mut self, value: $Field) {
+                    let mask: $Int = $crate::bitfield!(@mask $Int, $start $(, $end)?);
+                    let bits: u64 = <$Field as $crate::bitfield::FieldValue>::into_field(value);
+                    self.0 = (self.0 & !mask) | (((bits as $Int) << $start) & mask);
+                }
+            )*
+        }
+
+        impl ::core::convert::From<$Name> for $Int {
+            fn from(value: $Name) -> Self {
+                value.0
+            }
+        }
+
+        impl<'a> $crate::readable::Readable<'a> for $Name {
+            /// Reads the underlying integer and validates the reserved bits.
+            fn read(mut s: $crate::reader::ReadStream<'a, '_>) -> $crate::error::Result<Self> {
+                Self::from_bits(s.read::<$Int>()?)
+            }
+        }
+
+        impl<'a> $crate::peekable::Peekable<'a> for $Name {
+            /// Peeks the underlying integer and validates the reserved bits.
+            fn peek(s: $crate::reader::PeekStream<'a, '_>) -> $crate::error::Result<Self> {
+                Self::from_bits(s.peek::<$Int>()?)
+            }
+        }
+
+        impl $crate::writable::Writable for $Name {
+            /// Writes the underlying integer.
+            fn write<__B>(mut s: $crate::writer::WriteStream<__B>, val: &Self) -> $crate::error::Result<()>
+            where
+                __B: ::core::convert::AsRef<[u8]> + ::core::convert::AsMut<[u8]>,
+            {
+                s.write::<$Int>(&val.0)
+            }
+        }
+    };
+}
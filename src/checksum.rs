@@ -0,0 +1,541 @@
+//! Checksums over the bytes read or written in a scope.
+//!
+//! The `checksum` module provides the [`Checksum`] trait and the built-in
+//! algorithms used by common file formats and protocols:
+//!
+//! - [`Crc32`] - CRC-32 (IEEE 802.3), used by Ethernet, ZIP, PNG and gzip
+//! - [`Crc32c`] - CRC-32C (Castagnoli), used by iSCSI, SCTP and ext4
+//! - [`Crc16Arc`], [`Crc16Modbus`], [`Crc16Kermit`], [`Crc16CcittFalse`] and
+//!   [`Crc16Xmodem`] - common CRC-16 variants
+//! - [`Adler32`] - used by zlib
+//! - [`Fletcher16`] and [`Fletcher32`]
+//! - [`InternetChecksum`] - the RFC 1071 checksum of IP, TCP and UDP headers
+//!
+//! A checksum is computed over the bytes consumed by a closure with
+//! [`ByteReader::digest`](crate::reader::ByteReader::digest) and
+//! [`ByteWriter::digest`](crate::writer::ByteWriter::digest).
+//! [`ByteReader::verify`](crate::reader::ByteReader::verify) also reads the
+//! trailing checksum and compares it, and
+//! [`ByteWriter::write_checksum`](crate::writer::ByteWriter::write_checksum)
+//! writes it. The trailing checksum uses the endianness of the reader or
+//! writer. A mismatch results in an [`Error::Custom`] holding a
+//! [`ChecksumMismatch`] error.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{checksum::Crc32, common::Endian, reader::ByteReader, writer::ByteWriter};
+//!
+//! let mut buffer = [0u8; 8];
+//! let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+//!
+//! writer.write_checksum::<Crc32, _>(|s| s.write_exact(b"IEND")).unwrap();
+//! assert_eq!(buffer, [b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]);
+//!
+//! let mut reader = ByteReader::with_endian(&buffer, Endian::Big);
+//! let chunk: &[u8] = reader.verify::<Crc32, _>(|s| s.read_exact(4)).unwrap();
+//! assert_eq!(chunk, b"IEND");
+//! assert!(reader.is_eof());
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+use crate::error::Error;
+
+/// A checksum or hash computed incrementally over bytes.
+///
+/// Implement this trait to use a custom algorithm with
+/// [`ByteReader::digest`](crate::reader::ByteReader::digest) and
+/// [`ByteWriter::digest`](crate::writer::ByteWriter::digest).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{checksum::Checksum, reader::ByteReader};
+///
+/// #[derive(Default)]
+/// struct Xor(u8);
+///
+/// impl Checksum for Xor {
+///     type Output = u8;
+///
+///     fn update(&mut self, bytes: &[u8]) {
+///         self.0 = bytes.iter().fold(self.0, |acc, byte| acc ^ byte);
+///     }
+///
+///     fn finish(&self) -> u8 {
+///         self.0
+///     }
+/// }
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x02, 0x03]);
+/// let value: u16 = reader.verify::<Xor, _>(|s| s.read()).unwrap();
+/// assert_eq!(value, 0x0201); // Assuming little endian
+/// assert_eq!(Xor::checksum(&[0x01, 0x02]), 0x03);
+/// ```
+pub trait Checksum: Default {
+    /// The type of the checksum value.
+    type Output: Copy + PartialEq + Into<u64>;
+
+    /// Feeds bytes into the checksum.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The next bytes of the data
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the checksum of the bytes fed so far.
+    fn finish(&self) -> Self::Output;
+
+    /// Computes the checksum of `bytes` at once.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The data
+    fn checksum(bytes: &[u8]) -> Self::Output {
+        let mut checksum: Self = Self::default();
+        checksum.update(bytes);
+        checksum.finish()
+    }
+}
+
+/// An error returned when a stored checksum does not match the calculated one.
+///
+/// The error is wrapped into [`Error::Custom`] and can be recovered with
+/// `downcast_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{checksum::{ChecksumMismatch, Crc32}, error::Error, reader::ByteReader};
+///
+/// let mut reader = ByteReader::new(&[0x00, 0x01, 0x00, 0x00, 0x00]); // Assuming little endian
+/// match reader.verify::<Crc32, _>(|s| s.read::<u8>()) {
+///     Err(Error::Custom(err)) => {
+///         let err: &ChecksumMismatch = err.downcast_ref().unwrap();
+///         assert_eq!(err.expected(), 1);
+///         assert_eq!(err.actual(), 0xD202EF8D);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChecksumMismatch {
+    expected: u64,
+    actual: u64,
+}
+
+impl ChecksumMismatch {
+    /// Creates a new error with the stored and the calculated checksum.
+    ///
+    /// # Parameters
+    ///
+    /// - `expected`: The checksum stored in the data
+    /// - `actual`: The checksum calculated over the data
+    pub fn new(expected: u64, actual: u64) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Returns the checksum stored in the data.
+    pub fn expected(&self) -> u64 {
+        self.expected
+    }
+
+    /// Returns the checksum calculated over the data.
+    pub fn actual(&self) -> u64 {
+        self.actual
+    }
+}
+
+impl Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Checksum mismatch: expected 0x{:X}, calculated 0x{:X}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for ChecksumMismatch {}
+
+impl From<ChecksumMismatch> for Error {
+    fn from(value: ChecksumMismatch) -> Self {
+        Error::Custom(Box::new(value))
+    }
+}
+
+/// Builds the lookup table of a reflected CRC-32.
+const fn crc32_table(poly: u32) -> [u32; 256] {
+    let mut table: [u32; 256] = [0u32; 256];
+    let mut index: usize = 0;
+
+    while index < 256 {
+        let mut crc: u32 = index as u32;
+        let mut bit: u32 = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+/// Builds the lookup table of a CRC-16, `reflected` for least significant bit first.
+const fn crc16_table(poly: u16, reflected: bool) -> [u16; 256] {
+    let mut table: [u16; 256] = [0u16; 256];
+    let mut index: usize = 0;
+
+    while index < 256 {
+        let mut crc: u16 = if reflected {
+            index as u16
+        } else {
+            (index as u16) << 8
+        };
+        let mut bit: u32 = 0;
+
+        while bit < 8 {
+            crc = match reflected {
+                true if crc & 0x0001 != 0 => (crc >> 1) ^ poly,
+                true => crc >> 1,
+                false if crc & 0x8000 != 0 => (crc << 1) ^ poly,
+                false => crc << 1,
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+macro_rules! impl_crc32 {
+    ($(#[$meta:meta])* $Name:ident, $Poly:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $Name(u32);
+
+        impl $Name {
+            const TABLE: [u32; 256] = crc32_table($Poly);
+        }
+
+        impl Default for $Name {
+            fn default() -> Self {
+                Self(0xFFFF_FFFF)
+            }
+        }
+
+        impl Checksum for $Name {
+            type Output = u32;
+
+            fn update(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = Self::TABLE[((self.0 ^ *byte as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+                }
+            }
+
+            fn finish(&self) -> u32 {
+                !self.0
+            }
+        }
+    };
+}
+
+macro_rules! impl_crc16 {
+    ($(#[$meta:meta])* $Name:ident, $Poly:expr, $Init:expr, $Reflected:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $Name(u16);
+
+        impl $Name {
+            const TABLE: [u16; 256] = crc16_table($Poly, $Reflected);
+        }
+
+        impl Default for $Name {
+            fn default() -> Self {
+                Self($Init)
+            }
+        }
+
+        impl Checksum for $Name {
+            type Output = u16;
+
+            fn update(&mut self, bytes: &[u8]) {
+                for byte in bytes {
+                    self.0 = match $Reflected {
+                        true => Self::TABLE[(self.0 as u8 ^ *byte) as usize] ^ (self.0 >> 8),
+                        false => Self::TABLE[((self.0 >> 8) as u8 ^ *byte) as usize] ^ (self.0 << 8),
+                    };
+                }
+            }
+
+            fn finish(&self) -> u16 {
+                self.0
+            }
+        }
+    };
+}
+
+impl_crc32!(
+    /// CRC-32 (IEEE 802.3), used by Ethernet, ZIP, PNG and gzip.
+    ///
+    /// Reflected polynomial `0xEDB88320`, the check value of `"123456789"`
+    /// is `0xCBF43926`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::checksum::{Checksum, Crc32};
+    ///
+    /// assert_eq!(Crc32::checksum(b"123456789"), 0xCBF43926);
+    /// ```
+    Crc32,
+    0xEDB8_8320
+);
+
+impl_crc32!(
+    /// CRC-32C (Castagnoli), used by iSCSI, SCTP, ext4 and Btrfs.
+    ///
+    /// Reflected polynomial `0x82F63B78`, the check value of `"123456789"`
+    /// is `0xE3069283`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::checksum::{Checksum, Crc32c};
+    ///
+    /// assert_eq!(Crc32c::checksum(b"123456789"), 0xE3069283);
+    /// ```
+    Crc32c,
+    0x82F6_3B78
+);
+
+impl_crc16!(
+    /// CRC-16/ARC, also known as CRC-16/IBM and CRC-16/LHA.
+    ///
+    /// Reflected polynomial `0xA001`, initial value `0x0000`, the check value
+    /// of `"123456789"` is `0xBB3D`.
+    Crc16Arc,
+    0xA001,
+    0x0000,
+    true
+);
+
+impl_crc16!(
+    /// CRC-16/MODBUS, used by the Modbus RTU protocol.
+    ///
+    /// Reflected polynomial `0xA001`, initial value `0xFFFF`, the check value
+    /// of `"123456789"` is `0x4B37`.
+    Crc16Modbus,
+    0xA001,
+    0xFFFF,
+    true
+);
+
+impl_crc16!(
+    /// CRC-16/KERMIT, also known as CRC-16/CCITT, used by Bluetooth and X.25.
+    ///
+    /// Reflected polynomial `0x8408`, initial value `0x0000`, the check value
+    /// of `"123456789"` is `0x2189`.
+    Crc16Kermit,
+    0x8408,
+    0x0000,
+    true
+);
+
+impl_crc16!(
+    /// CRC-16/CCITT-FALSE, also known as CRC-16/IBM-3740.
+    ///
+    /// Polynomial `0x1021` processed most significant bit first, initial value
+    /// `0xFFFF`, the check value of `"123456789"` is `0x29B1`.
+    Crc16CcittFalse,
+    0x1021,
+    0xFFFF,
+    false
+);
+
+impl_crc16!(
+    /// CRC-16/XMODEM, used by the XMODEM protocol.
+    ///
+    /// Polynomial `0x1021` processed most significant bit first, initial value
+    /// `0x0000`, the check value of `"123456789"` is `0x31C3`.
+    Crc16Xmodem,
+    0x1021,
+    0x0000,
+    false
+);
+
+/// Adler-32 checksum, used by zlib.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::checksum::{Adler32, Checksum};
+///
+/// assert_eq!(Adler32::checksum(b"Wikipedia"), 0x11E60398);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Adler32 {
+    a: u32,
+    b: u32,
+}
+
+impl Default for Adler32 {
+    fn default() -> Self {
+        Self { a: 1, b: 0 }
+    }
+}
+
+impl Checksum for Adler32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.a = (self.a + *byte as u32) % 65521;
+            self.b = (self.b + self.a) % 65521;
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+}
+
+/// Fletcher-16 checksum over bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::checksum::{Checksum, Fletcher16};
+///
+/// assert_eq!(Fletcher16::checksum(b"abcde"), 0xC8F0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fletcher16 {
+    sum1: u16,
+    sum2: u16,
+}
+
+impl Checksum for Fletcher16 {
+    type Output = u16;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.sum1 = (self.sum1 + *byte as u16) % 255;
+            self.sum2 = (self.sum2 + self.sum1) % 255;
+        }
+    }
+
+    fn finish(&self) -> u16 {
+        (self.sum2 << 8) | self.sum1
+    }
+}
+
+/// Fletcher-32 checksum over 16-bit little-endian words.
+///
+/// An odd trailing byte is padded with zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::checksum::{Checksum, Fletcher32};
+///
+/// assert_eq!(Fletcher32::checksum(b"abcde"), 0xF04FC729);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fletcher32 {
+    sum1: u32,
+    sum2: u32,
+    pending: Option<u8>,
+}
+
+impl Fletcher32 {
+    fn add(&mut self, word: u16) {
+        self.sum1 = (self.sum1 + word as u32) % 65535;
+        self.sum2 = (self.sum2 + self.sum1) % 65535;
+    }
+}
+
+impl Checksum for Fletcher32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            match self.pending.take() {
+                Some(low) => self.add(u16::from_le_bytes([low, *byte])),
+                None => self.pending = Some(*byte),
+            }
+        }
+    }
+
+    fn finish(&self) -> u32 {
+        let mut checksum: Self = *self;
+
+        if let Some(low) = checksum.pending.take() {
+            checksum.add(low as u16);
+        }
+
+        (checksum.sum2 << 16) | checksum.sum1
+    }
+}
+
+/// The Internet checksum of RFC 1071, used by IPv4, ICMP, TCP and UDP.
+///
+/// The one's complement of the one's complement sum of 16-bit big-endian
+/// words. An odd trailing byte is padded with zero. Verifying a header that
+/// includes its checksum field results in `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::checksum::{Checksum, InternetChecksum};
+///
+/// let header = [0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7];
+/// assert_eq!(InternetChecksum::checksum(&header), 0x220D);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct InternetChecksum {
+    sum: u32,
+    pending: Option<u8>,
+}
+
+impl InternetChecksum {
+    fn add(&mut self, word: u16) {
+        let sum: u32 = self.sum + word as u32;
+        self.sum = (sum & 0xFFFF) + (sum >> 16);
+    }
+}
+
+impl Checksum for InternetChecksum {
+    type Output = u16;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            match self.pending.take() {
+                Some(high) => self.add(u16::from_be_bytes([high, *byte])),
+                None => self.pending = Some(*byte),
+            }
+        }
+    }
+
+    fn finish(&self) -> u16 {
+        let mut checksum: Self = *self;
+
+        if let Some(high) = checksum.pending.take() {
+            checksum.add(u16::from_be_bytes([high, 0]));
+        }
+
+        !(checksum.sum as u16)
+    }
+}
@@ -0,0 +1,600 @@
+//! Compressed chunks inside binary data.
+//!
+//! The `compression` module provides the [`Codec`] trait and the built-in
+//! codecs:
+//!
+//! - [`Rle`] - the PackBits run-length encoding of TIFF and Mac resources
+//! - [`Lz4Block`] - the LZ4 block format, without the frame header
+//! - `Deflate`, `Zlib` and `Gzip` - DEFLATE streams (RFC 1951) without
+//!   a wrapper, with the zlib wrapper (RFC 1950) and with the gzip wrapper
+//!   (RFC 1952). Available with the `deflate` feature
+//!
+//! A compressed chunk of a known size is read with
+//! [`ByteReader::decompress`](crate::reader::ByteReader::decompress), which
+//! returns an [`OwnedReader`](crate::reader::owned::OwnedReader) over the
+//! decompressed data. A nested write is compressed with
+//! [`ByteWriter::compressed`](crate::writer::ByteWriter::compressed).
+//!
+//! # Decompression Bombs
+//!
+//! A few bytes of compressed data can expand to gigabytes. The size of the
+//! decompressed data is limited to [`DEFAULT_LIMIT`] bytes, or to a custom
+//! limit passed to
+//! [`ByteReader::decompress_with_limit`](crate::reader::ByteReader::decompress_with_limit).
+//! Exceeding the limit results in an [`Error::Custom`] holding a
+//! [`LimitExceeded`] error. Corrupted data results in [`Error::NotValid`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{compression::Lz4Block, reader::ByteReader, writer::ByteWriter};
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut writer = ByteWriter::new(&mut buffer[..]);
+//!
+//! let ((), size) = writer
+//!     .compressed::<Lz4Block, _>(|s| s.write_exact(&[0xAB; 48]))
+//!     .unwrap();
+//! assert!(size < 48);
+//!
+//! let mut reader = ByteReader::new(&buffer);
+//! let chunk = reader.decompress::<Lz4Block>(size).unwrap();
+//! assert_eq!(chunk.as_slice(), [0xAB; 48]);
+//! assert_eq!(reader.position(), size);
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+#[cfg(feature = "deflate")]
+use crate::checksum::{Checksum, ChecksumMismatch, Crc32};
+#[cfg(feature = "deflate")]
+use crate::common::Endian;
+use crate::error::{Error, Result};
+#[cfg(feature = "deflate")]
+use crate::reader::ByteReader;
+
+/// The default limit of the decompressed data size, 64 MiB.
+pub const DEFAULT_LIMIT: usize = 64 * 1024 * 1024;
+
+/// A compression algorithm.
+///
+/// Implement this trait to use a custom algorithm with
+/// [`ByteReader::decompress`](crate::reader::ByteReader::decompress) and
+/// [`ByteWriter::compressed`](crate::writer::ByteWriter::compressed).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, LimitExceeded};
+/// use bytecraft::error::Result;
+/// use bytecraft::reader::ByteReader;
+///
+/// /// Every byte is stored twice.
+/// struct Doubled;
+///
+/// impl Codec for Doubled {
+///     fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+///         Ok(bytes.iter().flat_map(|&byte| [byte, byte]).collect())
+///     }
+///
+///     fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+///         if bytes.len() / 2 > limit {
+///             return Err(LimitExceeded::new(limit).into());
+///         }
+///         Ok(bytes.iter().step_by(2).copied().collect())
+///     }
+/// }
+///
+/// let mut reader = ByteReader::new(&[1, 1, 2, 2]);
+/// assert_eq!(reader.decompress::<Doubled>(4).unwrap().as_slice(), [1, 2]);
+/// ```
+pub trait Codec {
+    /// Compresses the data.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The uncompressed data
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>>;
+
+    /// Decompresses the data.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The compressed data
+    /// - `limit`: The maximum size of the decompressed data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(data)` containing the decompressed data
+    /// - [`LimitExceeded`] if the decompressed data is larger than `limit`
+    /// - [`Error::NotValid`] if the data is corrupted
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>>;
+}
+
+/// An error returned when the decompressed data exceeds the size limit.
+///
+/// The error is wrapped into [`Error::Custom`] and can be recovered with
+/// `downcast_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{compression::{LimitExceeded, Rle}, error::Error, reader::ByteReader};
+///
+/// // 128 zeros
+/// let mut reader = ByteReader::new(&[0x81, 0x00]);
+/// match reader.decompress_with_limit::<Rle>(2, 100) {
+///     Err(Error::Custom(err)) => {
+///         let err: &LimitExceeded = err.downcast_ref().unwrap();
+///         assert_eq!(err.limit(), 100);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceeded {
+    limit: usize,
+}
+
+impl LimitExceeded {
+    /// Creates a new error for the exceeded limit.
+    ///
+    /// # Parameters
+    ///
+    /// - `limit`: The maximum size of the decompressed data
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+
+    /// Returns the maximum size of the decompressed data.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+}
+
+impl Display for LimitExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Decompressed data exceeds the limit of {} bytes",
+            self.limit
+        )
+    }
+}
+
+impl std::error::Error for LimitExceeded {}
+
+impl From<LimitExceeded> for Error {
+    fn from(value: LimitExceeded) -> Self {
+        Error::Custom(Box::new(value))
+    }
+}
+
+/// Checks that `extra` more bytes of output fit in the limit.
+fn reserve(output: &[u8], extra: usize, limit: usize) -> Result<()> {
+    match output.len().checked_add(extra) {
+        Some(len) if len <= limit => Ok(()),
+        _ => Err(LimitExceeded::new(limit).into()),
+    }
+}
+
+/// The PackBits run-length encoding.
+///
+/// Every block starts with a header byte `n`:
+///
+/// - `0..=127` - `n + 1` literal bytes follow
+/// - `129..=255` - the next byte is repeated `257 - n` times
+/// - `128` - no operation
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, Rle};
+///
+/// let data = [0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A];
+/// let packed = Rle::compress(&data).unwrap();
+///
+/// assert_eq!(packed, [0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A]);
+/// assert_eq!(Rle::decompress(&packed, 6).unwrap(), data);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rle;
+
+impl Codec for Rle {
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+        let mut output: Vec<u8> = Vec::with_capacity(bytes.len() + bytes.len() / 128 + 1);
+        let mut pos: usize = 0;
+
+        while pos < bytes.len() {
+            let run: usize = bytes[pos..]
+                .iter()
+                .take(128)
+                .take_while(|&&byte| byte == bytes[pos])
+                .count();
+
+            if run >= 3 {
+                output.extend_from_slice(&[(257 - run) as u8, bytes[pos]]);
+                pos += run;
+                continue;
+            }
+
+            let start: usize = pos;
+            while pos < bytes.len() && pos - start < 128 {
+                if bytes[pos..].starts_with(&[bytes[pos]; 3]) {
+                    break;
+                }
+                pos += 1;
+            }
+
+            output.push((pos - start - 1) as u8);
+            output.extend_from_slice(&bytes[start..pos]);
+        }
+
+        Ok(output)
+    }
+
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+        let mut output: Vec<u8> = Vec::new();
+        let mut pos: usize = 0;
+
+        while let Some(&header) = bytes.get(pos) {
+            pos += 1;
+
+            match header {
+                0..=127 => {
+                    let size: usize = header as usize + 1;
+                    let literals: &[u8] = bytes.get(pos..pos + size).ok_or(Error::NotValid)?;
+                    reserve(&output, size, limit)?;
+                    output.extend_from_slice(literals);
+                    pos += size;
+                }
+                128 => {}
+                _ => {
+                    let size: usize = 257 - header as usize;
+                    let &byte = bytes.get(pos).ok_or(Error::NotValid)?;
+                    reserve(&output, size, limit)?;
+                    output.resize(output.len() + size, byte);
+                    pos += 1;
+                }
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+/// The LZ4 block format.
+///
+/// Compatible with `LZ4_compress_default` and `LZ4_decompress_safe` of the
+/// reference implementation. The block does not store the size of the
+/// decompressed data, so it is usually stored next to the block.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, Lz4Block};
+///
+/// let data = b"abcabcabcabcabcabcabcabcabcabc";
+/// let block = Lz4Block::compress(data).unwrap();
+///
+/// assert!(block.len() < data.len());
+/// assert_eq!(Lz4Block::decompress(&block, data.len()).unwrap(), data);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Lz4Block;
+
+impl Lz4Block {
+    const MIN_MATCH: usize = 4;
+    /// The last match must start at least 12 bytes before the end of the block.
+    const MF_LIMIT: usize = 12;
+    /// The last 5 bytes of the block are always literals.
+    const LAST_LITERALS: usize = 5;
+    const MAX_OFFSET: usize = 65535;
+    const HASH_BITS: u32 = 12;
+
+    fn hash(bytes: &[u8], pos: usize) -> usize {
+        let sequence: u32 =
+            u32::from_le_bytes([bytes[pos], bytes[pos + 1], bytes[pos + 2], bytes[pos + 3]]);
+        (sequence.wrapping_mul(2654435761) >> (32 - Self::HASH_BITS)) as usize
+    }
+
+    fn write_length(output: &mut Vec<u8>, mut length: usize) {
+        while length >= 255 {
+            output.push(255);
+            length -= 255;
+        }
+        output.push(length as u8);
+    }
+
+    fn read_length(bytes: &[u8], pos: &mut usize, nibble: u8) -> Result<usize> {
+        let mut length: usize = nibble as usize;
+
+        if nibble == 15 {
+            loop {
+                let &byte = bytes.get(*pos).ok_or(Error::NotValid)?;
+                *pos += 1;
+                length = length.checked_add(byte as usize).ok_or(Error::NotValid)?;
+                if byte != 255 {
+                    break;
+                }
+            }
+        }
+
+        Ok(length)
+    }
+
+    fn write_sequence(output: &mut Vec<u8>, literals: &[u8], matched: Option<(usize, usize)>) {
+        let literal_nibble: usize = literals.len().min(15);
+        let match_length: usize = matched.map_or(0, |(_, length)| length - Self::MIN_MATCH);
+        let match_nibble: usize = match_length.min(15);
+
+        output.push(((literal_nibble << 4) | match_nibble) as u8);
+        if literal_nibble == 15 {
+            Self::write_length(output, literals.len() - 15);
+        }
+        output.extend_from_slice(literals);
+
+        if let Some((offset, _)) = matched {
+            output.extend_from_slice(&(offset as u16).to_le_bytes());
+            if match_nibble == 15 {
+                Self::write_length(output, match_length - 15);
+            }
+        }
+    }
+}
+
+impl Codec for Lz4Block {
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+        let mut output: Vec<u8> = Vec::with_capacity(bytes.len() + bytes.len() / 255 + 16);
+        let mut table: Vec<usize> = vec![usize::MAX; 1 << Self::HASH_BITS];
+        let mut anchor: usize = 0;
+        let mut pos: usize = 0;
+
+        if bytes.len() > Self::MF_LIMIT {
+            let match_limit: usize = bytes.len() - Self::MF_LIMIT;
+            let end_limit: usize = bytes.len() - Self::LAST_LITERALS;
+
+            while pos < match_limit {
+                let hash: usize = Self::hash(bytes, pos);
+                let candidate: usize = core::mem::replace(&mut table[hash], pos);
+
+                let found: bool = candidate != usize::MAX
+                    && pos - candidate <= Self::MAX_OFFSET
+                    && bytes[candidate..candidate + Self::MIN_MATCH]
+                        == bytes[pos..pos + Self::MIN_MATCH];
+
+                if !found {
+                    pos += 1;
+                    continue;
+                }
+
+                let mut length: usize = Self::MIN_MATCH;
+                while pos + length < end_limit && bytes[candidate + length] == bytes[pos + length] {
+                    length += 1;
+                }
+
+                Self::write_sequence(
+                    &mut output,
+                    &bytes[anchor..pos],
+                    Some((pos - candidate, length)),
+                );
+                pos += length;
+                anchor = pos;
+            }
+        }
+
+        Self::write_sequence(&mut output, &bytes[anchor..], None);
+        Ok(output)
+    }
+
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+        let mut output: Vec<u8> = Vec::new();
+        let mut pos: usize = 0;
+
+        loop {
+            let &token = bytes.get(pos).ok_or(Error::NotValid)?;
+            pos += 1;
+
+            let size: usize = Self::read_length(bytes, &mut pos, token >> 4)?;
+            let literals: &[u8] = pos
+                .checked_add(size)
+                .and_then(|end| bytes.get(pos..end))
+                .ok_or(Error::NotValid)?;
+            reserve(&output, size, limit)?;
+            output.extend_from_slice(literals);
+            pos += size;
+
+            if pos == bytes.len() {
+                return Ok(output);
+            }
+
+            let offset: usize = match bytes.get(pos..pos + 2) {
+                Some(&[low, high]) => u16::from_le_bytes([low, high]) as usize,
+                _ => return Err(Error::NotValid),
+            };
+            pos += 2;
+
+            if offset == 0 || offset > output.len() {
+                return Err(Error::NotValid);
+            }
+
+            let length: usize = Self::read_length(bytes, &mut pos, token & 0x0F)? + Self::MIN_MATCH;
+            reserve(&output, length, limit)?;
+
+            let start: usize = output.len() - offset;
+            for index in start..start + length {
+                output.push(output[index]);
+            }
+        }
+    }
+}
+
+/// The compression level of the DEFLATE codecs.
+#[cfg(feature = "deflate")]
+const DEFLATE_LEVEL: u8 = 6;
+
+#[cfg(feature = "deflate")]
+fn inflate_error(err: miniz_oxide::inflate::DecompressError, limit: usize) -> Error {
+    match err.status {
+        miniz_oxide::inflate::TINFLStatus::HasMoreOutput => LimitExceeded::new(limit).into(),
+        _ => Error::NotValid,
+    }
+}
+
+/// A raw DEFLATE stream (RFC 1951).
+///
+/// Available with the `deflate` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, Deflate};
+///
+/// let data = [0x42u8; 1000];
+/// let stream = Deflate::compress(&data).unwrap();
+///
+/// assert!(stream.len() < 20);
+/// assert_eq!(Deflate::decompress(&stream, 1000).unwrap(), data);
+/// assert!(Deflate::decompress(&stream, 999).is_err());
+/// ```
+#[cfg(feature = "deflate")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Deflate;
+
+#[cfg(feature = "deflate")]
+impl Codec for Deflate {
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+        Ok(miniz_oxide::deflate::compress_to_vec(bytes, DEFLATE_LEVEL))
+    }
+
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+        miniz_oxide::inflate::decompress_to_vec_with_limit(bytes, limit)
+            .map_err(|err| inflate_error(err, limit))
+    }
+}
+
+/// A DEFLATE stream with the zlib wrapper (RFC 1950).
+///
+/// The Adler-32 checksum of the wrapper is verified on decompression.
+/// Available with the `deflate` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, Zlib};
+///
+/// let stream = Zlib::compress(b"hello").unwrap();
+/// assert_eq!(stream[0], 0x78);
+/// assert_eq!(Zlib::decompress(&stream, 5).unwrap(), b"hello");
+/// ```
+#[cfg(feature = "deflate")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Zlib;
+
+#[cfg(feature = "deflate")]
+impl Codec for Zlib {
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+        Ok(miniz_oxide::deflate::compress_to_vec_zlib(
+            bytes,
+            DEFLATE_LEVEL,
+        ))
+    }
+
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+        miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(bytes, limit)
+            .map_err(|err| inflate_error(err, limit))
+    }
+}
+
+/// A DEFLATE stream with the gzip wrapper (RFC 1952).
+///
+/// Only a single gzip member is supported. The optional header fields are
+/// skipped, and the CRC-32 and the size of the trailer are verified on
+/// decompression. A wrong CRC-32 results in an [`Error::Custom`] holding a
+/// [`ChecksumMismatch`] error. Available with the `deflate` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::compression::{Codec, Gzip};
+///
+/// let stream = Gzip::compress(b"hello").unwrap();
+/// assert_eq!(stream[..3], [0x1F, 0x8B, 0x08]);
+/// assert_eq!(Gzip::decompress(&stream, 5).unwrap(), b"hello");
+/// ```
+#[cfg(feature = "deflate")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Gzip;
+
+#[cfg(feature = "deflate")]
+impl Gzip {
+    const MAGIC: [u8; 2] = [0x1F, 0x8B];
+    const METHOD_DEFLATE: u8 = 8;
+    const FLAG_HCRC: u8 = 0x02;
+    const FLAG_EXTRA: u8 = 0x04;
+    const FLAG_NAME: u8 = 0x08;
+    const FLAG_COMMENT: u8 = 0x10;
+    const FLAG_RESERVED: u8 = 0xE0;
+    const OS_UNKNOWN: u8 = 0xFF;
+}
+
+#[cfg(feature = "deflate")]
+impl Codec for Gzip {
+    fn compress(bytes: &[u8]) -> Result<Vec<u8>> {
+        let mut output: Vec<u8> = Vec::with_capacity(bytes.len() / 2 + 18);
+
+        output.extend_from_slice(&Self::MAGIC);
+        output.extend_from_slice(&[Self::METHOD_DEFLATE, 0, 0, 0, 0, 0, 0, Self::OS_UNKNOWN]);
+        output.extend_from_slice(&Deflate::compress(bytes)?);
+        output.extend_from_slice(&Crc32::checksum(bytes).to_le_bytes());
+        output.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+
+        Ok(output)
+    }
+
+    fn decompress(bytes: &[u8], limit: usize) -> Result<Vec<u8>> {
+        let mut reader: ByteReader = ByteReader::with_endian(bytes, Endian::Little);
+
+        let (magic, method, flags): ([u8; 2], u8, u8) = reader.read()?;
+        if magic != Self::MAGIC
+            || method != Self::METHOD_DEFLATE
+            || flags & Self::FLAG_RESERVED != 0
+        {
+            return Err(Error::NotValid);
+        }
+
+        // Modification time, extra flags and operating system
+        reader.skip(6)?;
+
+        if flags & Self::FLAG_EXTRA != 0 {
+            let size: u16 = reader.read()?;
+            reader.skip(size as usize)?;
+        }
+        if flags & Self::FLAG_NAME != 0 {
+            reader.read_until(&[0])?;
+        }
+        if flags & Self::FLAG_COMMENT != 0 {
+            reader.read_until(&[0])?;
+        }
+        if flags & Self::FLAG_HCRC != 0 {
+            reader.skip(2)?;
+        }
+
+        let body: &[u8] = reader.rest_bytes();
+        let stream_size: usize = body.len().checked_sub(8).ok_or(Error::NotValid)?;
+        let data: Vec<u8> = Deflate::decompress(&body[..stream_size], limit)?;
+
+        reader.skip(stream_size)?;
+        let (crc, size): (u32, u32) = reader.read()?;
+
+        let actual: u32 = Crc32::checksum(&data);
+        if crc != actual {
+            return Err(ChecksumMismatch::new(crc as u64, actual as u64).into());
+        }
+        if size != data.len() as u32 {
+            return Err(Error::NotValid);
+        }
+
+        Ok(data)
+    }
+}
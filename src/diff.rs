@@ -0,0 +1,580 @@
+//! Byte-level differences between two buffers.
+//!
+//! The `diff` module provides [`Diff`], which compares an old and a new
+//! buffer and describes their differences as a list of [`Hunk`]s: contiguous
+//! ranges of bytes that were replaced in place, inserted or deleted. The
+//! differences are computed with the Myers algorithm, so the hunks form a
+//! shortest edit script.
+//!
+//! `Diff` implements `Display`, rendering every group of nearby hunks as a
+//! summary line per hunk followed by the affected rows of both buffers side
+//! by side, in the [hexdump](crate::hexdump) format with the differing bytes
+//! highlighted. The rows follow the layout of a [`HexDump`] set with
+//! [Diff::layout()]. With a [`ParseMap`] recorded while reading either
+//! buffer, the summary lines name the fields that differ.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{diff::{Diff, HunkKind}, hexdump::HexDump};
+//!
+//! let old = b"version 1, flags 0x00";
+//! let new = b"version 12, flags 0x01";
+//! let diff = Diff::new(old, new).layout(HexDump::default().width(8).group(0).ascii(false));
+//!
+//! let kinds: Vec<HunkKind> = diff.hunks().iter().map(|hunk| hunk.kind()).collect();
+//! assert_eq!(kinds, [HunkKind::Insert, HunkKind::Replace]);
+//!
+//! assert_eq!(
+//!     diff.to_string(),
+//!     "insert 0x9..0x9 -> 0x9..0xa\n\
+//!      00000008  31 2c 20 66 6c 61 67 73 | 00000008  31[32]2c 20 66 6c 61 67\n\
+//!      replace 0x14..0x15 -> 0x15..0x16\n\
+//!      00000010  20 30 78 30[30]         | 00000010  73 20 30 78 30[31]"
+//! );
+//! ```
+
+use core::fmt::{Display, Formatter, Write};
+use core::ops::Range;
+
+use crate::hexdump::HexDump;
+use crate::reader::trace::ParseMap;
+
+/// The default maximum number of inserted and deleted bytes searched for
+/// a shortest edit script, see [Diff::with_limit()].
+pub const DEFAULT_LIMIT: usize = 1024;
+
+/// The kind of a [`Hunk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HunkKind {
+    /// Bytes of the old buffer were replaced with bytes of the new buffer.
+    /// The lengths of the ranges may differ.
+    Replace,
+    /// Bytes were inserted into the new buffer.
+    Insert,
+    /// Bytes were deleted from the old buffer.
+    Delete,
+}
+
+impl Display for HunkKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            HunkKind::Replace => "replace",
+            HunkKind::Insert => "insert",
+            HunkKind::Delete => "delete",
+        })
+    }
+}
+
+/// A contiguous difference between two buffers.
+///
+/// The old range is replaced by the new range. An empty old range is the
+/// position of an insertion, and an empty new range is the position of a
+/// deletion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hunk {
+    old: Range<usize>,
+    new: Range<usize>,
+}
+
+impl Hunk {
+    /// Returns the indices of the differing bytes in the old buffer.
+    pub fn old_range(&self) -> Range<usize> {
+        self.old.clone()
+    }
+
+    /// Returns the indices of the differing bytes in the new buffer.
+    pub fn new_range(&self) -> Range<usize> {
+        self.new.clone()
+    }
+
+    /// Returns the kind of the difference.
+    pub fn kind(&self) -> HunkKind {
+        match (self.old.is_empty(), self.new.is_empty()) {
+            (true, _) => HunkKind::Insert,
+            (false, true) => HunkKind::Delete,
+            (false, false) => HunkKind::Replace,
+        }
+    }
+
+    /// Returns `true` if the bytes were replaced without changing the length.
+    pub fn is_in_place(&self) -> bool {
+        self.old.len() == self.new.len()
+    }
+
+    fn shift(mut self, old: usize, new: usize) -> Self {
+        self.old = self.old.start + old..self.old.end + old;
+        self.new = self.new.start + new..self.new.end + new;
+        self
+    }
+}
+
+/// The differences between two buffers.
+///
+/// The differences are computed when the `Diff` is created. The builder
+/// methods only configure the rendering of the `Display` implementation,
+/// which uses the layout of a [`HexDump`] and writes nothing for identical
+/// buffers.
+///
+/// See the [module-level documentation](self) for examples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diff<'d> {
+    old: &'d [u8],
+    new: &'d [u8],
+    hunks: Vec<Hunk>,
+    layout: HexDump<'static>,
+    old_map: Option<&'d ParseMap>,
+    new_map: Option<&'d ParseMap>,
+}
+
+impl<'d> Diff<'d> {
+    /// Compares two buffers with the [`DEFAULT_LIMIT`].
+    ///
+    /// # Parameters
+    ///
+    /// - `old`: The original data
+    /// - `new`: The changed data
+    pub fn new(old: &'d [u8], new: &'d [u8]) -> Self {
+        Self::with_limit(old, new, DEFAULT_LIMIT)
+    }
+
+    /// Compares two buffers with a limit on the search for a shortest edit script.
+    ///
+    /// The time and the memory of the search grow with the square of the
+    /// number of inserted and deleted bytes. Common leading and trailing
+    /// bytes are skipped first. If the rest of the buffers differs by more
+    /// than `limit` inserted and deleted bytes, it is compared in place,
+    /// byte by byte, and the difference in length is reported at the end.
+    ///
+    /// # Parameters
+    ///
+    /// - `old`: The original data
+    /// - `new`: The changed data
+    /// - `limit`: The maximum number of inserted and deleted bytes to search for
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::diff::{Diff, HunkKind};
+    ///
+    /// let old = [0x01, 0x02, 0x03, 0x04];
+    /// let new = [0x00, 0x01, 0x02, 0x03, 0x05];
+    ///
+    /// let hunks = Diff::new(&old, &new).hunks().to_vec();
+    /// assert_eq!(hunks.len(), 2);
+    /// assert_eq!((hunks[0].kind(), hunks[0].new_range()), (HunkKind::Insert, 0..1));
+    /// assert_eq!((hunks[1].kind(), hunks[1].new_range()), (HunkKind::Replace, 4..5));
+    ///
+    /// // Without the search, every byte is compared in place
+    /// let hunks = Diff::with_limit(&old, &new, 0).hunks().to_vec();
+    /// assert_eq!(hunks.len(), 1);
+    /// assert_eq!((hunks[0].old_range(), hunks[0].new_range()), (0..4, 0..5));
+    /// ```
+    pub fn with_limit(old: &'d [u8], new: &'d [u8], limit: usize) -> Self {
+        Self {
+            old,
+            new,
+            hunks: Self::compare(old, new, limit),
+            layout: HexDump::default(),
+            old_map: None,
+            new_map: None,
+        }
+    }
+
+    /// Returns the differences, ordered by position.
+    pub fn hunks(&self) -> &[Hunk] {
+        &self.hunks
+    }
+
+    /// Returns `true` if the buffers are equal.
+    pub fn is_identical(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Sets the layout of the rendered rows, the default [`HexDump`] layout by default.
+    ///
+    /// The width, the grouping, the ASCII gutter, the case of the digits,
+    /// the colors and the address of `layout` are used. Its data, range,
+    /// highlights and header are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `layout`: A hex dump with the layout to use, usually [HexDump::default()]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{diff::Diff, hexdump::HexDump};
+    ///
+    /// let layout = HexDump::default().width(4).group(0).ascii(false);
+    /// let diff = Diff::new(&[0x00, 0xAB], &[0x00, 0xCD])
+    ///     .layout(layout.uppercase(true).address(0x100));
+    ///
+    /// assert_eq!(
+    ///     diff.to_string(),
+    ///     "replace 0x1..0x2 -> 0x1..0x2\n\
+    ///      00000100  00[AB]      | 00000100  00[CD]"
+    /// );
+    /// ```
+    pub fn layout(mut self, layout: HexDump<'_>) -> Self {
+        self.layout = layout.with_data(&[]);
+        self
+    }
+
+    /// Names the differing fields of the old buffer with a parse map.
+    ///
+    /// # Parameters
+    ///
+    /// - `map`: The parse map recorded while reading the old buffer
+    pub fn old_map(mut self, map: &'d ParseMap) -> Self {
+        self.old_map = Some(map);
+        self
+    }
+
+    /// Names the differing fields of the new buffer with a parse map.
+    ///
+    /// # Parameters
+    ///
+    /// - `map`: The parse map recorded while reading the new buffer
+    pub fn new_map(mut self, map: &'d ParseMap) -> Self {
+        self.new_map = Some(map);
+        self
+    }
+
+    /// Names the fields affected by a hunk.
+    ///
+    /// The labels of the differing bytes are taken from the parse maps set
+    /// with [old_map()](Diff::old_map) and [new_map()](Diff::new_map), see
+    /// [ParseMap::label()]. An insertion without a map of the new buffer is
+    /// named after the fields of the old buffer around the insertion position.
+    ///
+    /// # Parameters
+    ///
+    /// - `hunk`: A hunk of this diff
+    ///
+    /// # Returns
+    ///
+    /// The distinct labels, empty if no parse map covers the hunk.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, diff::Diff, reader::ByteReader};
+    ///
+    /// let old = [0x00, 0x01, 0x00, 0x10];
+    /// let new = [0x00, 0x02, 0x00, 0x10];
+    ///
+    /// let mut reader = ByteReader::with_endian(&old, Endian::Big);
+    /// reader.start_trace();
+    /// let _: u16 = reader.read_field("version").unwrap();
+    /// let _: u16 = reader.read_field("length").unwrap();
+    /// let map = reader.take_trace().unwrap();
+    ///
+    /// let diff = Diff::new(&old, &new).old_map(&map);
+    /// assert_eq!(diff.labels(&diff.hunks()[0]), ["version: u16"]);
+    /// ```
+    pub fn labels(&self, hunk: &Hunk) -> Vec<String> {
+        let mut labels: Vec<String> = Vec::new();
+        let mut extend = |found: Vec<String>| {
+            for label in found {
+                if !labels.contains(&label) {
+                    labels.push(label);
+                }
+            }
+        };
+
+        if let Some(map) = self.old_map {
+            extend(map.labels(hunk.old.clone()));
+        }
+        match self.new_map {
+            Some(map) => extend(map.labels(hunk.new.clone())),
+            None if hunk.old.is_empty() => {
+                if let Some(map) = self.old_map {
+                    extend(map.labels(hunk.old.start.saturating_sub(1)..hunk.old.start + 1));
+                }
+            }
+            None => {}
+        }
+        labels
+    }
+
+    fn compare(old: &[u8], new: &[u8], limit: usize) -> Vec<Hunk> {
+        let prefix: usize = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+        let suffix: usize = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        let old_rest: &[u8] = &old[prefix..old.len() - suffix];
+        let new_rest: &[u8] = &new[prefix..new.len() - suffix];
+
+        Self::shortest(old_rest, new_rest, limit)
+            .unwrap_or_else(|| Self::in_place(old_rest, new_rest))
+            .into_iter()
+            .map(|hunk| hunk.shift(prefix, prefix))
+            .collect()
+    }
+
+    /// Finds a shortest edit script with the Myers algorithm.
+    fn shortest(old: &[u8], new: &[u8], limit: usize) -> Option<Vec<Hunk>> {
+        let (n, m) = (old.len() as isize, new.len() as isize);
+        let max: isize = (old.len() + new.len()).min(limit) as isize;
+
+        // Furthest reaching x for every diagonal k = x - y, indexed by k + max + 1
+        let mut furthest: Vec<isize> = vec![0; 2 * max as usize + 3];
+        let mut trace: Vec<Vec<isize>> = Vec::new();
+
+        for d in 0..=max {
+            for k in (-d..=d).step_by(2) {
+                let index: usize = (k + max + 1) as usize;
+                let mut x: isize =
+                    match k == -d || (k != d && furthest[index - 1] < furthest[index + 1]) {
+                        true => furthest[index + 1],
+                        false => furthest[index - 1] + 1,
+                    };
+                let mut y: isize = x - k;
+
+                while x < n && y < m && old[x as usize] == new[y as usize] {
+                    x += 1;
+                    y += 1;
+                }
+                furthest[index] = x;
+
+                if x >= n && y >= m {
+                    trace.push(furthest[(max + 1 - d) as usize..=(max + 1 + d) as usize].to_vec());
+                    return Some(Self::backtrack(&trace, n, m));
+                }
+            }
+            trace.push(furthest[(max + 1 - d) as usize..=(max + 1 + d) as usize].to_vec());
+        }
+
+        None
+    }
+
+    /// Collects the hunks of the edit script from the end to the start.
+    fn backtrack(trace: &[Vec<isize>], n: isize, m: isize) -> Vec<Hunk> {
+        let mut hunks: Vec<Hunk> = Vec::new();
+        let mut current: Option<Hunk> = None;
+        let (mut x, mut y) = (n, m);
+
+        for d in (1..trace.len() as isize).rev() {
+            // The furthest reaching x of the previous step, for k in -(d - 1)..=(d - 1)
+            let previous = |k: isize| -> isize { trace[d as usize - 1][(k + d - 1) as usize] };
+
+            let k: isize = x - y;
+            let down: bool = k == -d || (k != d && previous(k - 1) < previous(k + 1));
+            let from_k: isize = if down { k + 1 } else { k - 1 };
+            let from_x: isize = previous(from_k);
+            let from_y: isize = from_x - from_k;
+            let (to_x, to_y) = if down {
+                (from_x, from_y + 1)
+            } else {
+                (from_x + 1, from_y)
+            };
+
+            // Equal bytes after the edit end the current hunk
+            if x > to_x {
+                hunks.extend(current.take());
+            }
+
+            let (old, new) = (
+                from_x as usize..to_x as usize,
+                from_y as usize..to_y as usize,
+            );
+            current = Some(match current {
+                Some(hunk) => Hunk {
+                    old: old.start..hunk.old.end,
+                    new: new.start..hunk.new.end,
+                },
+                None => Hunk { old, new },
+            });
+
+            (x, y) = (from_x, from_y);
+        }
+
+        hunks.extend(current);
+        hunks.reverse();
+        hunks
+    }
+
+    /// Compares the bytes at the same positions, and reports the difference
+    /// in length at the end.
+    fn in_place(old: &[u8], new: &[u8]) -> Vec<Hunk> {
+        let common: usize = old.len().min(new.len());
+        let mut hunks: Vec<Hunk> = Vec::new();
+        let mut index: usize = 0;
+
+        while index < common {
+            if old[index] == new[index] {
+                index += 1;
+                continue;
+            }
+
+            let start: usize = index;
+            while index < common && old[index] != new[index] {
+                index += 1;
+            }
+            hunks.push(Hunk {
+                old: start..index,
+                new: start..index,
+            });
+        }
+
+        if old.len() != new.len() {
+            match hunks.last_mut() {
+                Some(hunk) if hunk.old.end == common => {
+                    hunk.old.end = old.len();
+                    hunk.new.end = new.len();
+                }
+                _ => hunks.push(Hunk {
+                    old: common..old.len(),
+                    new: common..new.len(),
+                }),
+            }
+        }
+        hunks
+    }
+
+    /// Expands a range to complete rows. An empty range is expanded to the
+    /// row of its position, or to the last row at the end of the data.
+    fn rows(dump: &HexDump<'_>, range: &Range<usize>, len: usize) -> Range<usize> {
+        match range.is_empty() {
+            true => {
+                let position: usize = range.start.min(len.saturating_sub(1));
+                dump.row_span(position..(position + 1).min(len))
+            }
+            false => dump.row_span(range.clone()),
+        }
+    }
+
+    /// Renders the rows of a side of a block.
+    fn side(dump: HexDump<'_>, rows: Range<usize>) -> Vec<String> {
+        let dump: HexDump<'_> = dump.range(rows);
+        dump.rows()
+            .map(|row| {
+                let mut line: String = String::new();
+                let _ = dump.write_row(&mut line, row);
+                line
+            })
+            .collect()
+    }
+
+    fn write_block(&self, f: &mut Formatter<'_>, hunks: &[Hunk]) -> core::fmt::Result {
+        let mut old_dump: HexDump<'_> = self.layout.with_data(self.old);
+        let mut new_dump: HexDump<'_> = self.layout.with_data(self.new);
+
+        let old_rows: Range<usize> = union(
+            hunks
+                .iter()
+                .map(|hunk| Self::rows(&old_dump, &hunk.old, self.old.len())),
+        );
+        let new_rows: Range<usize> = union(
+            hunks
+                .iter()
+                .map(|hunk| Self::rows(&new_dump, &hunk.new, self.new.len())),
+        );
+
+        for hunk in hunks {
+            write!(
+                f,
+                "{} {:#x}..{:#x} -> {:#x}..{:#x}",
+                hunk.kind(),
+                hunk.old.start,
+                hunk.old.end,
+                hunk.new.start,
+                hunk.new.end
+            )?;
+            let labels: Vec<String> = self.labels(hunk);
+            if !labels.is_empty() {
+                write!(f, "  {}", labels.join(", "))?;
+            }
+            f.write_char('\n')?;
+
+            old_dump = old_dump.highlight(hunk.old.clone());
+            new_dump = new_dump.highlight(hunk.new.clone());
+        }
+
+        let column: usize = old_dump.row_len();
+        let old_lines: Vec<String> = Self::side(old_dump, old_rows);
+        let new_lines: Vec<String> = Self::side(new_dump, new_rows);
+
+        for index in 0..old_lines.len().max(new_lines.len()) {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+
+            let old_line: &str = old_lines.get(index).map_or("", String::as_str);
+            write!(
+                f,
+                "{}{:2$} |",
+                old_line,
+                "",
+                column.saturating_sub(visible_len(old_line))
+            )?;
+            if let Some(new_line) = new_lines.get(index) {
+                write!(f, " {}", new_line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the smallest range containing the non-empty ranges.
+fn union(ranges: impl Iterator<Item = Range<usize>>) -> Range<usize> {
+    ranges
+        .filter(|range| !range.is_empty())
+        .reduce(|a, b| a.start.min(b.start)..a.end.max(b.end))
+        .unwrap_or(0..0)
+}
+
+/// Returns the number of characters of a line without ANSI escape codes.
+fn visible_len(line: &str) -> usize {
+    let mut len: usize = 0;
+    let mut escape: bool = false;
+    for character in line.chars() {
+        match (escape, character) {
+            (false, '\x1b') => escape = true,
+            (false, _) => len += 1,
+            (true, 'm') => escape = false,
+            (true, _) => {}
+        }
+    }
+    len
+}
+
+impl Display for Diff<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let old_dump: HexDump<'_> = self.layout.with_data(self.old);
+        let new_dump: HexDump<'_> = self.layout.with_data(self.new);
+        let old_span = |hunk: &Hunk| Self::rows(&old_dump, &hunk.old, self.old.len());
+        let new_span = |hunk: &Hunk| Self::rows(&new_dump, &hunk.new, self.new.len());
+        let mut start: usize = 0;
+
+        // Hunks sharing rows on either side are rendered together
+        while start < self.hunks.len() {
+            let mut end: usize = start + 1;
+            let mut old_end: usize = old_span(&self.hunks[start]).end;
+            let mut new_end: usize = new_span(&self.hunks[start]).end;
+
+            while let Some(hunk) = self.hunks.get(end) {
+                let old_rows: Range<usize> = old_span(hunk);
+                let new_rows: Range<usize> = new_span(hunk);
+                if !(old_rows.start < old_end || new_rows.start < new_end) {
+                    break;
+                }
+                old_end = old_end.max(old_rows.end);
+                new_end = new_end.max(new_rows.end);
+                end += 1;
+            }
+
+            if start > 0 {
+                f.write_char('\n')?;
+            }
+            self.write_block(f, &self.hunks[start..end])?;
+            start = end;
+        }
+        Ok(())
+    }
+}
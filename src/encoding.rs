@@ -0,0 +1,331 @@
+//! Text encodings of binary data.
+//!
+//! The `encoding` module provides [`Hex`] and [`Base64`] for moving binary
+//! payloads through JSON, logs and test fixtures:
+//!
+//! - [`Hex`] - hexadecimal digits, whitespace between the digits is ignored
+//! - [`Base64`] - the standard and the URL-safe alphabets of RFC 4648, with
+//!   or without padding
+//!
+//! Text is decoded into a reader with
+//! [`ByteReader::from_hex`](crate::reader::ByteReader::from_hex) and
+//! [`ByteReader::from_base64`](crate::reader::ByteReader::from_base64), and
+//! the written data is encoded with
+//! [`ByteWriter::to_hex`](crate::writer::ByteWriter::to_hex) and
+//! [`ByteWriter::to_base64`](crate::writer::ByteWriter::to_base64).
+//!
+//! Invalid characters are rejected with an [`Error::Custom`] holding an
+//! [`InvalidCharacter`] error with the byte index and the value of the
+//! character. Text of an invalid length results in [`Error::NotValid`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{common::Endian, encoding::Base64, reader::ByteReader, writer::ByteWriter};
+//!
+//! let fixture = ByteReader::from_hex("
+//!     00 01 02 03
+//!     CA FE BA BE
+//! ").unwrap();
+//!
+//! let mut reader = fixture.reader();
+//! reader.set_endian(Endian::Big);
+//! assert_eq!(reader.read::<[u32; 2]>().unwrap(), [0x00010203, 0xCAFEBABE]);
+//!
+//! let mut buffer = [0u8; 16];
+//! let mut writer = ByteWriter::new(&mut buffer[..]);
+//! writer.write_bytes(b"foobar").unwrap();
+//!
+//! assert_eq!(writer.to_hex(), "666f6f626172");
+//! assert_eq!(writer.to_base64(Base64::Standard), "Zm9vYmFy");
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+use crate::error::{Error, Result};
+
+/// An error returned when a character is not valid for the encoding.
+///
+/// The error is wrapped into [`Error::Custom`] and can be recovered with
+/// `downcast_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{encoding::{Hex, InvalidCharacter}, error::Error};
+///
+/// match Hex::decode("0a 1g") {
+///     Err(Error::Custom(err)) => {
+///         let err: &InvalidCharacter = err.downcast_ref().unwrap();
+///         assert_eq!(err.index(), 4);
+///         assert_eq!(err.value(), b'g');
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCharacter {
+    index: usize,
+    value: u8,
+}
+
+impl InvalidCharacter {
+    /// Creates a new error for the character at `index`.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The byte index of the character in the text
+    /// - `value`: The invalid byte
+    pub fn new(index: usize, value: u8) -> Self {
+        Self { index, value }
+    }
+
+    /// Returns the byte index of the invalid character in the text.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the invalid byte.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl Display for InvalidCharacter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Invalid character 0x{:02X} at index {}",
+            self.value, self.index
+        )
+    }
+}
+
+impl std::error::Error for InvalidCharacter {}
+
+impl From<InvalidCharacter> for Error {
+    fn from(value: InvalidCharacter) -> Self {
+        Error::Custom(Box::new(value))
+    }
+}
+
+/// Hexadecimal encoding, two digits per byte.
+///
+/// Uppercase and lowercase digits are accepted on decode, and ASCII
+/// whitespace between the digits is ignored. Whitespace cannot split the two
+/// digits of a byte.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::encoding::Hex;
+///
+/// assert_eq!(Hex::encode(&[0x0A, 0xFF]), "0aff");
+/// assert_eq!(Hex::encode_upper(&[0x0A, 0xFF]), "0AFF");
+/// assert_eq!(Hex::decode("0a FF\n10").unwrap(), [0x0A, 0xFF, 0x10]);
+/// assert!(Hex::decode("0a F").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Hex;
+
+impl Hex {
+    const LOWER: &'static [u8; 16] = b"0123456789abcdef";
+    const UPPER: &'static [u8; 16] = b"0123456789ABCDEF";
+
+    /// Encodes bytes as lowercase hexadecimal digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The data to encode
+    pub fn encode(bytes: &[u8]) -> String {
+        Self::encode_with(bytes, Self::LOWER)
+    }
+
+    /// Encodes bytes as uppercase hexadecimal digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The data to encode
+    pub fn encode_upper(bytes: &[u8]) -> String {
+        Self::encode_with(bytes, Self::UPPER)
+    }
+
+    fn encode_with(bytes: &[u8], digits: &[u8; 16]) -> String {
+        let mut text: String = String::with_capacity(bytes.len() * 2);
+        for &byte in bytes {
+            text.push(digits[(byte >> 4) as usize] as char);
+            text.push(digits[(byte & 0x0F) as usize] as char);
+        }
+        text
+    }
+
+    /// Decodes hexadecimal digits.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The digits to decode, optionally separated by ASCII whitespace
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(data)` containing the decoded bytes
+    /// - [`Error::Custom`] holding an [`InvalidCharacter`] error if a character is not a digit
+    /// - [`Error::NotValid`] if the number of digits is odd, or whitespace
+    ///   splits the digits of a byte
+    pub fn decode(text: &str) -> Result<Vec<u8>> {
+        let mut bytes: Vec<u8> = Vec::with_capacity(text.len() / 2);
+        let mut high: Option<u8> = None;
+
+        for (index, byte) in text.bytes().enumerate() {
+            if byte.is_ascii_whitespace() {
+                match high {
+                    Some(_) => return Err(Error::NotValid),
+                    None => continue,
+                }
+            }
+
+            let digit: u8 = match (byte as char).to_digit(16) {
+                Some(digit) => digit as u8,
+                None => return Err(InvalidCharacter::new(index, byte).into()),
+            };
+
+            match high.take() {
+                Some(high) => bytes.push((high << 4) | digit),
+                None => high = Some(digit),
+            }
+        }
+
+        match high {
+            Some(_) => Err(Error::NotValid),
+            None => Ok(bytes),
+        }
+    }
+}
+
+/// Base64 encoding of RFC 4648.
+///
+/// Every 3 bytes are encoded as 4 characters. The padded variants append `=`
+/// until the length of the text is a multiple of 4, and require the padding
+/// on decode. The unpadded variants reject it. Non-zero unused bits of the
+/// last character are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::encoding::Base64;
+///
+/// assert_eq!(Base64::Standard.encode(&[0xFB, 0xFF]), "+/8=");
+/// assert_eq!(Base64::UrlSafeNoPad.encode(&[0xFB, 0xFF]), "-_8");
+/// assert_eq!(Base64::UrlSafeNoPad.decode("-_8").unwrap(), [0xFB, 0xFF]);
+/// assert!(Base64::Standard.decode("-_8=").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Base64 {
+    /// The standard alphabet with `+` and `/`, padded.
+    #[default]
+    Standard,
+    /// The standard alphabet with `+` and `/`, without padding.
+    StandardNoPad,
+    /// The URL and filename safe alphabet with `-` and `_`, padded.
+    UrlSafe,
+    /// The URL and filename safe alphabet with `-` and `_`, without padding.
+    UrlSafeNoPad,
+}
+
+impl Base64 {
+    const STANDARD: &'static [u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    const URL_SAFE: &'static [u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+    const PAD: u8 = b'=';
+
+    fn alphabet(self) -> &'static [u8; 64] {
+        match self {
+            Base64::Standard | Base64::StandardNoPad => Self::STANDARD,
+            Base64::UrlSafe | Base64::UrlSafeNoPad => Self::URL_SAFE,
+        }
+    }
+
+    fn is_padded(self) -> bool {
+        matches!(self, Base64::Standard | Base64::UrlSafe)
+    }
+
+    /// Encodes bytes as Base64 text.
+    ///
+    /// # Parameters
+    ///
+    /// - `bytes`: The data to encode
+    pub fn encode(self, bytes: &[u8]) -> String {
+        let alphabet: &[u8; 64] = self.alphabet();
+        let mut text: String = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+        for chunk in bytes.chunks(3) {
+            let group: u32 = chunk.iter().enumerate().fold(0, |group, (index, &byte)| {
+                group | ((byte as u32) << (16 - 8 * index))
+            });
+
+            for index in 0..=chunk.len() {
+                text.push(alphabet[((group >> (18 - 6 * index)) & 0x3F) as usize] as char);
+            }
+            if self.is_padded() {
+                (chunk.len()..3).for_each(|_| text.push(Self::PAD as char));
+            }
+        }
+
+        text
+    }
+
+    /// Decodes Base64 text.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The text to decode
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(data)` containing the decoded bytes
+    /// - [`Error::Custom`] holding an [`InvalidCharacter`] error if a character
+    ///   is not in the alphabet or the padding is not expected
+    /// - [`Error::NotValid`] if the length of the text or the unused bits
+    ///   of the last character are not valid
+    pub fn decode(self, text: &str) -> Result<Vec<u8>> {
+        let alphabet: &[u8; 64] = self.alphabet();
+        let text: &[u8] = text.as_bytes();
+
+        let mut size: usize = text.len();
+        if self.is_padded() {
+            if text.len() % 4 != 0 {
+                return Err(Error::NotValid);
+            }
+            while size > 0 && text.len() - size < 2 && text[size - 1] == Self::PAD {
+                size -= 1;
+            }
+        }
+        if size % 4 == 1 {
+            return Err(Error::NotValid);
+        }
+
+        let mut bytes: Vec<u8> = Vec::with_capacity(size / 4 * 3 + 2);
+        let mut bits: u32 = 0;
+        let mut count: u32 = 0;
+
+        for (index, &byte) in text[..size].iter().enumerate() {
+            let value: usize = alphabet
+                .iter()
+                .position(|&digit| digit == byte)
+                .ok_or(InvalidCharacter::new(index, byte))?;
+
+            bits = (bits << 6) | value as u32;
+            count += 6;
+            if count >= 8 {
+                count -= 8;
+                bytes.push((bits >> count) as u8);
+                bits &= (1 << count) - 1;
+            }
+        }
+
+        match bits {
+            0 => Ok(bytes),
+            _ => Err(Error::NotValid),
+        }
+    }
+}
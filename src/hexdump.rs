@@ -0,0 +1,441 @@
+//! Configurable hex dumps of binary data.
+//!
+//! The `hexdump` module provides the [`HexDump`] builder, created by
+//! [`ByteReader::hexdump`](crate::reader::ByteReader::hexdump),
+//! [`ByteWriter::hexdump`](crate::writer::ByteWriter::hexdump) or
+//! [`HexDump::new`]. It implements `Display`, so it can be printed or
+//! converted to a `String`.
+//!
+//! The default layout is the canonical `hexdump -C` format: 8-digit offsets,
+//! 16 bytes per row in groups of 8, and an ASCII gutter. The current position
+//! of a reader or writer is highlighted.
+//!
+//! # Highlighting
+//!
+//! Without color, a run of highlighted bytes is enclosed in square brackets,
+//! which replace the spaces around it, so the columns stay aligned. With
+//! [color()](HexDump::color), the highlighted bytes are shown in reverse video,
+//! and the bytes are colored by kind with ANSI escape codes: zero bytes are
+//! gray, printable ASCII characters are cyan, and other bytes are yellow.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::reader::ByteReader;
+//!
+//! let data = b"Hello, world!\n\x00\x01\x02";
+//! let mut reader = ByteReader::new(data);
+//! reader.skip(7).unwrap();
+//!
+//! assert_eq!(
+//!     reader.hexdump().to_string(),
+//!     "00000000  48 65 6c 6c 6f 2c 20[77] 6f 72 6c 64 21 0a 00 01  |Hello, world!...|\n\
+//!      00000010  02                                                |.|"
+//! );
+//!
+//! assert_eq!(
+//!     reader.hexdump().width(8).group(4).ascii(false).range(4..12).to_string(),
+//!     "00000000               6f 2c 20[77]\n\
+//!      00000008  6f 72 6c 64"
+//! );
+//! ```
+
+use core::fmt::{Display, Formatter, Write};
+use core::ops::{Bound, Range, RangeBounds};
+
+/// A builder of hex dumps.
+///
+/// See the [module-level documentation](self) for the format.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::hexdump::HexDump;
+///
+/// let data: Vec<u8> = (0u8..20).collect();
+/// let dump = HexDump::new(&data)
+///     .width(10)
+///     .group(0)
+///     .uppercase(true)
+///     .address(0x8000_0000)
+///     .highlight(9..11);
+///
+/// assert_eq!(
+///     dump.to_string(),
+///     "80000000  00 01 02 03 04 05 06 07 08[09] |..........|\n\
+///      8000000A [0A]0B 0C 0D 0E 0F 10 11 12 13  |..........|"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HexDump<'d> {
+    data: &'d [u8],
+    width: usize,
+    group: usize,
+    ascii: bool,
+    header: bool,
+    uppercase: bool,
+    color: bool,
+    address: u64,
+    range: Range<usize>,
+    highlights: Vec<Range<usize>>,
+}
+
+impl<'d> HexDump<'d> {
+    const RESET: &'static str = "\x1b[0m";
+
+    /// Creates a new `HexDump` of the data with the default layout.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The data to dump
+    pub fn new(data: &'d [u8]) -> Self {
+        Self {
+            data,
+            width: 16,
+            group: 8,
+            ascii: true,
+            header: false,
+            uppercase: false,
+            color: false,
+            address: 0,
+            range: 0..data.len(),
+            highlights: Vec::new(),
+        }
+    }
+
+    /// Sets the number of bytes per row, 16 by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The number of bytes per row, at least 1
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Sets the number of bytes per group, 8 by default.
+    ///
+    /// Groups are separated by an additional space.
+    ///
+    /// # Parameters
+    ///
+    /// - `group`: The number of bytes per group, `0` disables grouping
+    pub fn group(mut self, group: usize) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// Enables or disables the ASCII gutter, enabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `ascii`: `true` to show printable characters next to the bytes
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Enables or disables the header row with column offsets, disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `header`: `true` to show the header row
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Selects uppercase or lowercase hexadecimal digits, lowercase by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `uppercase`: `true` for uppercase digits
+    pub fn uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Enables or disables ANSI colors for terminals, disabled by default.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: `true` to emit ANSI escape codes
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the address of the first byte of the data, 0 by default.
+    ///
+    /// The printed offsets are absolute: the address plus the index of the
+    /// byte in the data. Useful for dumps of memory or of a chunk of a file.
+    ///
+    /// # Parameters
+    ///
+    /// - `address`: The address of the first byte
+    pub fn address(mut self, address: u64) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Selects the bytes to dump, all of them by default.
+    ///
+    /// Rows stay aligned to the width, and the bytes of the first and the
+    /// last row outside the range are left blank. The range is clamped to
+    /// the data.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The indices of the bytes to dump
+    pub fn range<R: RangeBounds<usize>>(mut self, range: R) -> Self {
+        self.range = Self::clamp(range, self.data.len());
+        self
+    }
+
+    /// Highlights a range of bytes.
+    ///
+    /// May be called several times, adjacent and overlapping ranges are
+    /// merged. The current position of a reader or writer is highlighted
+    /// by default, use [clear_highlights()](HexDump::clear_highlights) to remove it.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The indices of the bytes to highlight
+    pub fn highlight<R: RangeBounds<usize>>(mut self, range: R) -> Self {
+        self.highlights.push(Self::clamp(range, self.data.len()));
+        self
+    }
+
+    /// Removes all highlighted ranges, including the current position.
+    pub fn clear_highlights(mut self) -> Self {
+        self.highlights.clear();
+        self
+    }
+
+    fn clamp<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+        let start: usize = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end: usize = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+
+        start.min(len)..end.clamp(start.min(len), len)
+    }
+
+    fn is_highlighted(&self, index: usize) -> bool {
+        self.highlights.iter().any(|range| range.contains(&index))
+    }
+
+    fn offset_digits(&self) -> usize {
+        match self.address.saturating_add(self.data.len() as u64) > u32::MAX as u64 {
+            true => 16,
+            false => 8,
+        }
+    }
+
+    fn color_code(byte: u8) -> u8 {
+        match byte {
+            0x00 => 90,
+            0x20..=0x7E => 36,
+            _ => 33,
+        }
+    }
+
+    /// Writes the separator before a cell, or before the gutter if `column == width`.
+    fn write_separator(
+        &self,
+        f: &mut impl Write,
+        column: usize,
+        open: bool,
+        close: bool,
+    ) -> core::fmt::Result {
+        let grouped: bool =
+            self.group > 0 && column > 0 && column < self.width && column % self.group == 0;
+
+        f.write_str(match (close, open, grouped) {
+            (true, _, true) => "] ",
+            (true, _, false) => "]",
+            (false, true, true) => " [",
+            (false, true, false) => "[",
+            (false, false, true) => "  ",
+            (false, false, false) => " ",
+        })
+    }
+
+    /// Writes the row of bytes starting at `start`.
+    pub(crate) fn write_row(&self, f: &mut impl Write, start: usize) -> core::fmt::Result {
+        let digits: usize = self.offset_digits();
+        let address: u64 = self.address.wrapping_add(start as u64);
+        let marked = |index: usize| -> bool {
+            !self.color && self.range.contains(&index) && self.is_highlighted(index)
+        };
+
+        match self.uppercase {
+            true => write!(f, "{:01$X} ", address, digits)?,
+            false => write!(f, "{:01$x} ", address, digits)?,
+        }
+
+        // Without the gutter, the blank cells at the end of the row are omitted
+        let columns: usize = match self.ascii {
+            true => self.width,
+            false => self.width.min(self.range.end - start),
+        };
+
+        for column in 0..columns {
+            let index: usize = start + column;
+            let previous: bool = column > 0 && marked(index - 1);
+            self.write_separator(
+                f,
+                column,
+                marked(index) && !previous,
+                previous && !marked(index),
+            )?;
+
+            if !self.range.contains(&index) {
+                f.write_str("  ")?;
+                continue;
+            }
+
+            let byte: u8 = self.data[index];
+            if self.color {
+                let reverse: &str = if self.is_highlighted(index) { "7;" } else { "" };
+                write!(f, "\x1b[{}{}m", reverse, Self::color_code(byte))?;
+            }
+            match self.uppercase {
+                true => write!(f, "{:02X}", byte)?,
+                false => write!(f, "{:02x}", byte)?,
+            }
+            if self.color {
+                f.write_str(Self::RESET)?;
+            }
+        }
+
+        let last: bool = columns > 0 && marked(start + columns - 1);
+        if !self.ascii {
+            return match last {
+                true => f.write_str("]"),
+                false => Ok(()),
+            };
+        }
+
+        self.write_separator(f, self.width, false, last)?;
+        f.write_str(" |")?;
+        for index in (start..start + self.width).filter(|index| self.range.contains(index)) {
+            let byte: u8 = self.data[index];
+            let character: char = match byte {
+                0x20..=0x7E => byte as char,
+                _ => '.',
+            };
+
+            match self.color {
+                true => {
+                    let reverse: &str = if self.is_highlighted(index) { "7;" } else { "" };
+                    let code: u8 = Self::color_code(byte);
+                    write!(f, "\x1b[{}{}m{}{}", reverse, code, character, Self::RESET)?;
+                }
+                false => f.write_char(character)?,
+            }
+        }
+        f.write_str("|")
+    }
+
+    /// Returns the starts of the rows covering the selected range.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = usize> {
+        let first: usize = match self.range.is_empty() {
+            true => self.range.end,
+            false => self.row_span(self.range.clone()).start,
+        };
+        (first..self.range.end).step_by(self.width)
+    }
+
+    /// Expands a range of bytes to the complete rows covering it.
+    pub(crate) fn row_span(&self, range: Range<usize>) -> Range<usize> {
+        let start: usize = range.start - range.start % self.width;
+        let end: usize = range.end.div_ceil(self.width).saturating_mul(self.width);
+        start..end.min(self.data.len())
+    }
+
+    /// Creates a dump of other data with the same layout, without a
+    /// selected range and highlights.
+    pub(crate) fn with_data<'x>(&self, data: &'x [u8]) -> HexDump<'x> {
+        HexDump {
+            data,
+            range: 0..data.len(),
+            highlights: Vec::new(),
+            ..*self
+        }
+    }
+
+    /// Returns the number of visible characters of a complete row.
+    pub(crate) fn row_len(&self) -> usize {
+        let groups: usize = match self.group {
+            0 => 0,
+            group => (self.width - 1) / group,
+        };
+        let gutter: usize = match self.ascii {
+            true => self.width + 4,
+            false => 0,
+        };
+
+        self.offset_digits() + 1 + self.width * 3 + groups + gutter
+    }
+
+    fn write_header(&self, f: &mut impl Write) -> core::fmt::Result {
+        write!(f, "{:1$} ", "", self.offset_digits())?;
+        for column in 0..self.width {
+            self.write_separator(f, column, false, false)?;
+            match self.uppercase {
+                true => write!(f, "{:02X}", column % 256)?,
+                false => write!(f, "{:02x}", column % 256)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the dump, calling `annotate` with the selected bytes of every
+    /// row to append text after it.
+    pub(crate) fn write_annotated<W: Write>(
+        &self,
+        f: &mut W,
+        mut annotate: impl FnMut(&mut W, Range<usize>) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let mut first: bool = true;
+
+        if self.header {
+            self.write_header(f)?;
+            first = false;
+        }
+
+        for row in self.rows() {
+            if !first {
+                f.write_char('\n')?;
+            }
+            self.write_row(f, row)?;
+            annotate(
+                f,
+                row.max(self.range.start)..(row + self.width).min(self.range.end),
+            )?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for HexDump<'_> {
+    /// Creates a `HexDump` without data, to be used as a layout.
+    fn default() -> Self {
+        HexDump::new(&[])
+    }
+}
+
+impl Display for HexDump<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        self.write_annotated(f, |_, _| Ok(()))
+    }
+}
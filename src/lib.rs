@@ -91,6 +91,10 @@
 //! - Selection of Compile-time and Runtime handlers
 //! - Support for Little/Big/Native endian
 //!
+//! #### Optional features
+//! - `deflate` - Deflate, Zlib and Gzip codecs in the `compression` module
+//! - `serde` - serde `Serializer` and `Deserializer` in the `serde` module
+//!
 //! ## Getting Started
 //!
 //! ```toml
@@ -158,9 +162,19 @@
 
 ////////////////////////////////////////////////////////////////////////////////
 
+pub mod bitfield;
+pub mod checksum;
 pub mod common;
+pub mod compression;
+pub mod diff;
+pub mod encoding;
 pub mod error;
+pub mod hexdump;
 pub mod reader;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod tagged;
+pub mod types;
 pub mod writer;
 
 pub use reader::peekable;
@@ -0,0 +1,287 @@
+//! Checkpoints and rollback for speculative parsing.
+//!
+//! The `checkpoint` module provides tools for trying alternative decodings
+//! of the same bytes without cloning the [`ByteReader`] and comparing
+//! positions manually.
+//!
+//! # Core Concepts
+//!
+//! ## Checkpoint
+//!
+//! A [`Checkpoint`] is a guard that remembers the reader's position,
+//! endianness and user context at the moment it was created. When the guard is dropped,
+//! the reader is rolled back to the remembered state, unless the guard
+//! was [committed](Checkpoint::commit). The spans recorded since the checkpoint
+//! by a reader in [tracing](crate::reader::trace) mode are discarded as well.
+//!
+//! ## Alternatives
+//!
+//! The [`Alternatives`] trait is implemented for tuples of `Readable` types
+//! and allows [ByteReader::alt()] to try every type of the tuple in order,
+//! returning the first successfully read value.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::reader::ByteReader;
+//!
+//! let data = [0x01, 0x02, 0x03];
+//! let mut reader = ByteReader::new(&data);
+//!
+//! {
+//!     let mut checkpoint = reader.checkpoint();
+//!     let _: u16 = checkpoint.read().unwrap();
+//!     assert_eq!(checkpoint.position(), 2);
+//!     // Dropped without commit - position is restored
+//! }
+//! assert_eq!(reader.position(), 0);
+//!
+//! {
+//!     let mut checkpoint = reader.checkpoint();
+//!     let _: u16 = checkpoint.read().unwrap();
+//!     checkpoint.commit();
+//! }
+//! assert_eq!(reader.position(), 2);
+//! ```
+
+use core::ops::{Deref, DerefMut};
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::common::Endian;
+use crate::error::{Error, Result};
+use crate::reader::readable::Readable;
+use crate::reader::ByteReader;
+
+/// A guard that rolls the reader back to a saved state on drop.
+///
+/// Created by [ByteReader::checkpoint()] or [ReadStream::checkpoint()](crate::reader::ReadStream::checkpoint).
+/// While the guard is alive, it dereferences to the underlying [`ByteReader`],
+/// so all reading and navigation methods are available through it.
+///
+/// When the guard is dropped, the position, endianness and user context of
+/// the reader are restored to the values they had when the checkpoint was created. Call
+/// [commit()](Checkpoint::commit) to keep the changes instead.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the borrowed data by `ByteReader`
+/// - `'r`: The lifetime of the borrowed `ByteReader` instance
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::common::Endian;
+///
+/// let data = [0x00, 0x01, 0xFF];
+/// let mut reader = ByteReader::with_endian(&data, Endian::Little);
+///
+/// {
+///     let mut checkpoint = reader.checkpoint();
+///     checkpoint.set_endian(Endian::Big);
+///     let value: u16 = checkpoint.read().unwrap();
+///     assert_eq!(value, 0x0001);
+///
+///     // Next byte is not a valid bool - keep nothing
+///     if checkpoint.read::<bool>().is_ok() {
+///         checkpoint.commit();
+///     }
+/// }
+///
+/// assert_eq!(reader.position(), 0);
+/// assert_eq!(reader.endian(), Endian::Little);
+/// ```
+pub struct Checkpoint<'a, 'r>
+where
+    'a: 'r,
+{
+    reader: &'r mut ByteReader<'a>,
+    pos: usize,
+    endian: Endian,
+    context: Option<Arc<dyn Any + Send + Sync>>,
+    trace: Option<(usize, usize)>,
+    committed: bool,
+}
+
+impl<'a, 'r> Checkpoint<'a, 'r> {
+    /// Creates a new checkpoint at the current state of the reader.
+    pub(crate) fn new(reader: &'r mut ByteReader<'a>) -> Self {
+        let pos: usize = reader.pos;
+        let endian: Endian = reader.endian;
+        let context: Option<Arc<dyn Any + Send + Sync>> = reader.context.clone();
+        let trace: Option<(usize, usize)> = reader.trace.as_mut().map(|tracer| tracer.mark());
+
+        Self {
+            reader,
+            pos,
+            endian,
+            context,
+            trace,
+            committed: false,
+        }
+    }
+
+    /// Returns the position saved by this checkpoint.
+    ///
+    /// # Returns
+    ///
+    /// The position the reader will be restored to on rollback.
+    pub fn start(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes consumed since the checkpoint was created.
+    ///
+    /// If the reader was moved backward past the saved position, returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
+    /// reader.skip(1).unwrap();
+    ///
+    /// let mut checkpoint = reader.checkpoint();
+    /// checkpoint.skip(3).unwrap();
+    /// assert_eq!(checkpoint.start(), 1);
+    /// assert_eq!(checkpoint.consumed(), 3);
+    /// ```
+    pub fn consumed(&self) -> usize {
+        self.reader.pos.saturating_sub(self.pos)
+    }
+
+    /// Keeps all changes made to the reader since the checkpoint was created.
+    ///
+    /// Consumes the guard without rolling back the reader.
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+
+    /// Restores the reader to the state saved by the checkpoint.
+    ///
+    /// Equivalent to dropping the guard, but makes the intent explicit.
+    pub fn rollback(self) {
+        drop(self)
+    }
+}
+
+impl<'a> Deref for Checkpoint<'a, '_> {
+    type Target = ByteReader<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        self.reader
+    }
+}
+
+impl DerefMut for Checkpoint<'_, '_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.reader
+    }
+}
+
+impl Drop for Checkpoint<'_, '_> {
+    fn drop(&mut self) {
+        if !self.committed {
+            self.reader.pos = self.pos;
+            self.reader.endian = self.endian;
+            self.reader.context = self.context.take();
+            if let (Some(tracer), Some(mark)) = (self.reader.trace.as_mut(), self.trace) {
+                tracer.rollback(mark);
+            }
+        }
+    }
+}
+
+/// A set of `Readable` types that can be tried in order.
+///
+/// This trait is implemented for tuples of up to 13 elements, where every
+/// element implements [`Readable`] and can be converted into the result type `T`.
+/// It is used by [ByteReader::alt()] and [ReadStream::alt()](crate::reader::ReadStream::alt).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{
+///     reader::{ByteReader, ReadStream},
+///     readable::Readable,
+///     error::{Error, Result}
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Message {
+///     Ping,
+///     Data(u16),
+/// }
+///
+/// struct Ping;
+///
+/// impl<'a> Readable<'a> for Ping {
+///     fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+///         match s.read::<u8>()? {
+///             0x00 => Ok(Ping),
+///             _ => Err(Error::NotValid),
+///         }
+///     }
+/// }
+///
+/// impl From<Ping> for Message {
+///     fn from(_: Ping) -> Self {
+///         Message::Ping
+///     }
+/// }
+///
+/// impl From<u16> for Message {
+///     fn from(value: u16) -> Self {
+///         Message::Data(value)
+///     }
+/// }
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x00]);
+/// let message: Message = reader.alt::<_, (Ping, u16)>().unwrap();
+/// assert_eq!(message, Message::Data(1));
+/// ```
+pub trait Alternatives<'a, T> {
+    /// Tries to read every alternative in order and returns the first success.
+    ///
+    /// The reader is rolled back after every failed alternative.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of the first alternative that was read successfully
+    /// - The error of the last alternative if all of them failed
+    fn read_first(reader: &mut ByteReader<'a>) -> Result<T>;
+}
+
+macro_rules! impl_alternatives {
+    ($($Types:tt)+) => {
+        impl<'a, T, $($Types : Readable<'a> + Into<T>),+> Alternatives<'a, T> for ($($Types ,)+) {
+            fn read_first(reader: &mut ByteReader<'a>) -> Result<T> {
+                let result: Result<T> = Err(Error::NotValid);
+                $(
+                    let result: Result<T> = match result {
+                        Ok(value) => Ok(value),
+                        Err(_) => reader.try_read::<$Types>().map(Into::into),
+                    };
+                )+
+                result
+            }
+        }
+    };
+}
+
+// Tuple implementations from 1 to 13 elements
+impl_alternatives!(T0);
+impl_alternatives!(T0 T1);
+impl_alternatives!(T0 T1 T2);
+impl_alternatives!(T0 T1 T2 T3);
+impl_alternatives!(T0 T1 T2 T3 T4);
+impl_alternatives!(T0 T1 T2 T3 T4 T5);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7 T8);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+impl_alternatives!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
@@ -0,0 +1,474 @@
+//! Parser combinators for declarative reading of ad-hoc formats.
+//!
+//! The `combinator` module provides a small set of building blocks that
+//! operate on a [`ReadStream`] and can be nested into each other. They allow
+//! describing repetitive or delimited structures without writing manual loops.
+//!
+//! # Core Concepts
+//!
+//! ## Parser
+//!
+//! A [`Parser`] is anything that can produce a value from a [`ReadStream`].
+//! The trait is implemented for every closure of the form
+//! `FnMut(&mut ReadStream<'a, '_>) -> Result<T>`, and every combinator of
+//! this module returns a new `Parser`.
+//!
+//! Parsers are run with [ByteReader::parse()](crate::reader::ByteReader::parse)
+//! or [ReadStream::parse()].
+//!
+//! ## Combinators
+//!
+//! - [`read`] - Reads a [`Readable`] type
+//! - [`tag`] - Matches an exact byte sequence
+//! - [`many0`], [`many1`] - Repeats a parser while it succeeds
+//! - [`count`] - Repeats a parser exactly `n` times
+//! - [`repeat_until`] - Repeats a parser until a predicate is satisfied
+//! - [`separated`] - Reads a list of items divided by separators
+//! - [`terminated_by`] - Reads items until a sentinel byte sequence
+//! - [`map`], [`verify`] - Transforms or validates the result of a parser
+//!
+//! # Error Handling
+//!
+//! Combinators report errors of the inner parsers as is. A mismatch found by
+//! the combinator itself ([`tag`], [`verify`]) is reported as [`Error::NotValid`].
+//! Combinators that stop on the first failed item ([`many0`], [`many1`])
+//! roll the reader back to the end of the last successfully read item.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::reader::ByteReader;
+//! use bytecraft::reader::combinator::{count, many0, read, tag, terminated_by};
+//!
+//! let data = b"LIST\x02\x00\x01\x00\x02\x00abc\x00";
+//! let mut reader = ByteReader::new(data);
+//!
+//! reader.parse(tag(b"LIST")).unwrap();
+//! let n: u16 = reader.read().unwrap();
+//! let items: Vec<u16> = reader.parse(count(read::<u16>(), n as usize)).unwrap();
+//! let name: Vec<u8> = reader.parse(terminated_by(read::<u8>(), b"\x00")).unwrap();
+//!
+//! assert_eq!(items, vec![1, 2]);
+//! assert_eq!(name, b"abc");
+//! assert!(reader.is_eof());
+//!
+//! // Nothing left to repeat
+//! let rest: Vec<u8> = reader.parse(many0(read::<u8>())).unwrap();
+//! assert!(rest.is_empty());
+//! ```
+
+use crate::error::{Error, Result};
+use crate::reader::readable::Readable;
+use crate::reader::ReadStream;
+
+/// A trait for values that can parse data from a [`ReadStream`].
+///
+/// `Parser` is the common interface of all combinators in this module.
+/// It is implemented for every closure taking `&mut ReadStream`, so custom
+/// parsers can be written inline.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{
+///     reader::{ByteReader, ReadStream},
+///     reader::combinator::many1,
+///     error::Result
+/// };
+///
+/// // A custom parser reading a length-prefixed slice
+/// let chunk = |s: &mut ReadStream<'_, '_>| -> Result<u16> {
+///     let len: u8 = s.read()?;
+///     s.skip(len as usize)?;
+///     Ok(len as u16)
+/// };
+///
+/// let mut reader = ByteReader::new(&[0x01, 0xAA, 0x02, 0xBB, 0xCC]);
+/// let lengths: Vec<u16> = reader.parse(many1(chunk)).unwrap();
+/// assert_eq!(lengths, vec![1, 2]);
+/// ```
+pub trait Parser<'a, T> {
+    /// Parses a value from the stream.
+    ///
+    /// # Parameters
+    ///
+    /// - `s`: The stream to read data from
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the parsed value
+    /// - An error if the data cannot be parsed
+    fn parse(&mut self, s: &mut ReadStream<'a, '_>) -> Result<T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&mut ReadStream<'a, '_>) -> Result<T>,
+{
+    fn parse(&mut self, s: &mut ReadStream<'a, '_>) -> Result<T> {
+        self(s)
+    }
+}
+
+/// Runs a parser and rolls the reader back if it fails.
+fn attempt<'a, T, P: Parser<'a, T>>(s: &mut ReadStream<'a, '_>, parser: &mut P) -> Result<T> {
+    let mut checkpoint = s.checkpoint();
+    let value: T = parser.parse(&mut ReadStream {
+        reader: &mut checkpoint,
+    })?;
+    checkpoint.commit();
+    Ok(value)
+}
+
+/// Creates a parser reading a value of type `T`.
+///
+/// Equivalent to calling [ReadStream::read()] inside a closure.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::read;
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x00]);
+/// let value: u16 = reader.parse(read::<u16>()).unwrap();
+/// assert_eq!(value, 1);
+/// ```
+pub fn read<'a, T: Readable<'a>>() -> impl Parser<'a, T> {
+    |s: &mut ReadStream<'a, '_>| s.read::<T>()
+}
+
+/// Creates a parser matching the exact byte sequence `bytes`.
+///
+/// # Returns
+///
+/// The parser returns the matched slice of the underlying data, or
+/// - [`Error::NotValid`] if the data does not match
+/// - [`Error::InsufficientData`] if there is not enough data
+///
+/// The reader position is unchanged on error.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::tag;
+///
+/// let mut reader = ByteReader::new(b"\x89PNG\r\n");
+/// assert!(reader.parse(tag(b"GIF")).is_err());
+/// assert_eq!(reader.position(), 0);
+///
+/// assert_eq!(reader.parse(tag(b"\x89PNG")).unwrap(), b"\x89PNG");
+/// assert_eq!(reader.position(), 4);
+/// ```
+pub fn tag<'a, 't>(bytes: &'t [u8]) -> impl Parser<'a, &'a [u8]> + 't {
+    move |s: &mut ReadStream<'a, '_>| {
+        let data: &[u8] = s.peek_exact(bytes.len())?;
+        if data != bytes {
+            return Err(Error::NotValid);
+        }
+        s.read_exact(bytes.len())
+    }
+}
+
+/// Creates a parser repeating `parser` while it succeeds.
+///
+/// Reading stops at the end of data or at the first failed item. The reader
+/// is rolled back to the end of the last successfully read item. Reading also
+/// stops if an item is read without consuming any bytes, to prevent an endless loop.
+///
+/// # Returns
+///
+/// The parser returns all read items, possibly none. It never fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{many0, read};
+///
+/// // 0x02 is not a valid bool
+/// let mut reader = ByteReader::new(&[0x01, 0x00, 0x02]);
+/// let flags: Vec<bool> = reader.parse(many0(read::<bool>())).unwrap();
+/// assert_eq!(flags, vec![true, false]);
+/// assert_eq!(reader.position(), 2);
+/// ```
+pub fn many0<'a, T, P: Parser<'a, T>>(mut parser: P) -> impl Parser<'a, Vec<T>> {
+    move |s: &mut ReadStream<'a, '_>| {
+        let mut result: Vec<T> = Vec::new();
+
+        while !s.is_eof() {
+            let rest: usize = s.rest_len();
+            match attempt(s, &mut parser) {
+                Ok(value) => result.push(value),
+                Err(_) => break,
+            }
+            if s.rest_len() == rest {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Creates a parser repeating `parser` while it succeeds, requiring at least one item.
+///
+/// Works like [`many0`], but the first item is mandatory.
+///
+/// # Returns
+///
+/// The parser returns all read items, or the error of the first item if
+/// it cannot be read.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{many1, tag};
+///
+/// let mut reader = ByteReader::new(b"abab!");
+/// let items: Vec<&[u8]> = reader.parse(many1(tag(b"ab"))).unwrap();
+/// assert_eq!(items.len(), 2);
+///
+/// assert!(reader.parse(many1(tag(b"ab"))).is_err());
+/// ```
+pub fn many1<'a, T, P: Parser<'a, T>>(mut parser: P) -> impl Parser<'a, Vec<T>> {
+    move |s: &mut ReadStream<'a, '_>| {
+        let first: T = attempt(s, &mut parser)?;
+        let mut result: Vec<T> = many0(|s: &mut ReadStream<'a, '_>| parser.parse(s)).parse(s)?;
+        result.insert(0, first);
+        Ok(result)
+    }
+}
+
+/// Creates a parser repeating `parser` exactly `n` times.
+///
+/// # Returns
+///
+/// The parser returns `n` items, or the error of the first failed item.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{count, read};
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x02, 0x03]);
+/// let items: Vec<u8> = reader.parse(count(read::<u8>(), 2)).unwrap();
+/// assert_eq!(items, vec![1, 2]);
+///
+/// assert!(reader.parse(count(read::<u8>(), 2)).is_err());
+/// ```
+pub fn count<'a, T, P: Parser<'a, T>>(mut parser: P, n: usize) -> impl Parser<'a, Vec<T>> {
+    move |s: &mut ReadStream<'a, '_>| {
+        let mut result: Vec<T> = Vec::with_capacity(n.min(s.rest_len()));
+
+        for _ in 0..n {
+            result.push(parser.parse(s)?);
+        }
+
+        Ok(result)
+    }
+}
+
+/// Creates a parser repeating `parser` until `pred` returns `true` for an item.
+///
+/// The item satisfying the predicate is included in the result.
+///
+/// # Returns
+///
+/// The parser returns all read items, the error of the first failed item,
+/// or [`Error::NotValid`] if an item that does not satisfy the predicate
+/// consumed no bytes, since the repetition would never end.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{read, repeat_until};
+///
+/// // Records of (is_last, value)
+/// let mut reader = ByteReader::new(&[0x00, 0x0A, 0x01, 0x0B, 0x00, 0x0C]);
+/// let records: Vec<(bool, u8)> = reader
+///     .parse(repeat_until(read::<(bool, u8)>(), |record| record.0))
+///     .unwrap();
+/// assert_eq!(records, vec![(false, 0x0A), (true, 0x0B)]);
+/// ```
+pub fn repeat_until<'a, T, P, F>(mut parser: P, mut pred: F) -> impl Parser<'a, Vec<T>>
+where
+    P: Parser<'a, T>,
+    F: FnMut(&T) -> bool,
+{
+    move |s: &mut ReadStream<'a, '_>| {
+        let mut result: Vec<T> = Vec::new();
+
+        loop {
+            let rest: usize = s.rest_len();
+            let value: T = parser.parse(s)?;
+            let stop: bool = pred(&value);
+            result.push(value);
+
+            if stop {
+                return Ok(result);
+            }
+            if s.rest_len() == rest {
+                return Err(Error::NotValid);
+            }
+        }
+    }
+}
+
+/// Creates a parser reading items divided by separators.
+///
+/// Reads `item (separator item)*`. The list may be empty: if the first item
+/// cannot be read, the reader is rolled back and an empty list is returned.
+/// Every separator must be followed by an item. Reading stops after a
+/// separator and an item that consumed no bytes.
+///
+/// # Returns
+///
+/// The parser returns all read items, or the error of an item that is
+/// missing after a separator.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{read, separated, tag};
+///
+/// let mut reader = ByteReader::new(&[0x01, b',', 0x02, b',', 0x03, b';']);
+/// let items: Vec<u8> = reader.parse(separated(read::<u8>(), tag(b","))).unwrap();
+/// assert_eq!(items, vec![1, 2, 3]);
+/// assert_eq!(reader.position(), 5);
+/// ```
+pub fn separated<'a, T, S, P, D>(mut item: P, mut separator: D) -> impl Parser<'a, Vec<T>>
+where
+    P: Parser<'a, T>,
+    D: Parser<'a, S>,
+{
+    move |s: &mut ReadStream<'a, '_>| {
+        let mut result: Vec<T> = Vec::new();
+
+        match attempt(s, &mut item) {
+            Ok(value) => result.push(value),
+            Err(_) => return Ok(result),
+        }
+
+        loop {
+            let rest: usize = s.rest_len();
+            if attempt(s, &mut separator).is_err() {
+                break;
+            }
+            result.push(item.parse(s)?);
+            if s.rest_len() == rest {
+                break;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Creates a parser reading items until the `sentinel` byte sequence.
+///
+/// Before every item the stream is checked for the sentinel. When it is
+/// found, it is consumed and reading stops. The sentinel is not part of the result.
+///
+/// # Returns
+///
+/// The parser returns all items before the sentinel, or the error of the
+/// first failed item (for example, [`Error::InsufficientData`] if the data
+/// ends before the sentinel). If an item consumes no bytes, the sentinel
+/// can never be reached and [`Error::NotValid`] is returned.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{read, terminated_by};
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x00, 0x02, 0x00, 0xFF, 0xFF, 0x03]);
+/// let items: Vec<u16> = reader
+///     .parse(terminated_by(read::<u16>(), &[0xFF, 0xFF]))
+///     .unwrap();
+/// assert_eq!(items.len(), 2);
+/// assert_eq!(reader.position(), 6);
+/// ```
+pub fn terminated_by<'a, 't, T, P: Parser<'a, T> + 't>(
+    mut parser: P,
+    sentinel: &'t [u8],
+) -> impl Parser<'a, Vec<T>> + 't {
+    move |s: &mut ReadStream<'a, '_>| {
+        let mut result: Vec<T> = Vec::new();
+
+        loop {
+            if s.rest_bytes().starts_with(sentinel) {
+                s.skip(sentinel.len())?;
+                return Ok(result);
+            }
+
+            let rest: usize = s.rest_len();
+            result.push(parser.parse(s)?);
+            if s.rest_len() == rest {
+                return Err(Error::NotValid);
+            }
+        }
+    }
+}
+
+/// Creates a parser transforming the result of `parser` with `f`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{map, read};
+///
+/// let mut reader = ByteReader::new(&[0x02]);
+/// let value: u32 = reader.parse(map(read::<u8>(), |v| v as u32 * 100)).unwrap();
+/// assert_eq!(value, 200);
+/// ```
+pub fn map<'a, T, U, P, F>(mut parser: P, mut f: F) -> impl Parser<'a, U>
+where
+    P: Parser<'a, T>,
+    F: FnMut(T) -> U,
+{
+    move |s: &mut ReadStream<'a, '_>| parser.parse(s).map(&mut f)
+}
+
+/// Creates a parser validating the result of `parser` with `pred`.
+///
+/// # Returns
+///
+/// The parser returns the value if `pred` returns `true` for it, otherwise
+/// [`Error::NotValid`]. The reader position is unchanged on error.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+/// use bytecraft::reader::combinator::{read, verify};
+///
+/// let mut reader = ByteReader::new(&[0x05, 0xFF]);
+/// let small = || verify(read::<u8>(), |v| *v < 0x10);
+///
+/// assert_eq!(reader.parse(small()).unwrap(), 0x05);
+/// assert!(reader.parse(small()).is_err());
+/// assert_eq!(reader.position(), 1);
+/// ```
+pub fn verify<'a, T, P, F>(mut parser: P, mut pred: F) -> impl Parser<'a, T>
+where
+    P: Parser<'a, T>,
+    F: FnMut(&T) -> bool,
+{
+    move |s: &mut ReadStream<'a, '_>| {
+        attempt(s, &mut |s: &mut ReadStream<'a, '_>| {
+            let value: T = parser.parse(s)?;
+            match pred(&value) {
+                true => Ok(value),
+                false => Err(Error::NotValid),
+            }
+        })
+    }
+}
@@ -19,6 +19,8 @@
 //!   changing the reader's position
 //! - [`ReadStream`] - Provides read-write access for consuming data and
 //!   advancing the reader's position
+//! - [`OwnedReader`] - Owns data created while reading, like decompressed
+//!   chunks, and lends it as a `ByteReader`
 //!
 //! ## Navigation and Positioning
 //!
@@ -27,6 +29,13 @@
 //! - **Absolute positioning**: [set_position()](ByteReader::set_position), [seek()](ByteReader::seek), [reset()](ByteReader::reset)
 //! - **Relative movement**: [skip()](ByteReader::skip), [rewind()](ByteReader::rewind), [seek()](ByteReader::seek)
 //! - **Preview without movement**: [peek()](ByteReader::peek)
+//! - **Searching**: [find()](ByteReader::find), [rfind()](ByteReader::rfind), [skip_until()](ByteReader::skip_until), [read_until()](ByteReader::read_until), [split()](ByteReader::split)
+//! - **Speculative reading**: [try_read()](ByteReader::try_read), [checkpoint()](ByteReader::checkpoint), [alt()](ByteReader::alt)
+//! - **Declarative parsing**: [parse()](ByteReader::parse) with the [combinator] module
+//! - **Record iteration**: [iter()](ByteReader::iter), [iter_n()](ByteReader::iter_n)
+//! - **User context**: [with_context()](ByteReader::with_context), [context()](ByteReader::context), [set_context()](ByteReader::set_context)
+//! - **Compressed chunks**: [decompress()](ByteReader::decompress) with the [compression](crate::compression) module
+//! - **Parse tracing**: [start_trace()](ByteReader::start_trace), [read_field()](ByteReader::read_field), [take_trace()](ByteReader::take_trace) with the [trace] module
 //!
 //! # Examples
 //!
@@ -147,15 +156,33 @@
 //! assert_eq!(reader.position(), 4);
 //! ```
 
+pub mod checkpoint;
+pub mod combinator;
+pub mod owned;
 pub mod peekable;
 pub mod readable;
+pub mod records;
+pub mod search;
+pub mod trace;
 
+use std::any::{type_name, Any};
 use std::io::Read;
+use std::sync::Arc;
 
+use crate::checksum::{Checksum, ChecksumMismatch};
 use crate::common::{Endian, SeekFrom};
+use crate::compression::{Codec, DEFAULT_LIMIT};
+use crate::encoding::{Base64, Hex};
 use crate::error::{Error, Result};
+use crate::hexdump::HexDump;
+use checkpoint::{Alternatives, Checkpoint};
+use combinator::Parser;
+use owned::OwnedReader;
 use peekable::Peekable;
-use readable::Readable;
+use readable::{Readable, ReadableWith};
+use records::Records;
+use search::Split;
+use trace::{ParseMap, Tracer};
 
 /// A versatile binary data reader for parsing structured binary formats.
 ///
@@ -197,11 +224,12 @@ use readable::Readable;
 ///
 /// See the [module-level documentation](self) for comprehensive examples.
 ///
-#[derive(Hash)]
 pub struct ByteReader<'a> {
     data: &'a [u8],
     pos: usize,
     endian: Endian,
+    context: Option<Arc<dyn Any + Send + Sync>>,
+    trace: Option<Box<Tracer>>,
 }
 
 impl<'a> ByteReader<'a> {
@@ -234,6 +262,8 @@ impl<'a> ByteReader<'a> {
             data,
             pos: 0,
             endian: Endian::Native,
+            context: None,
+            trace: None,
         }
     }
 
@@ -264,9 +294,176 @@ impl<'a> ByteReader<'a> {
             data,
             pos: 0,
             endian,
+            context: None,
+            trace: None,
         }
     }
 
+    /// Creates a new `ByteReader` with an attached user context.
+    ///
+    /// The context is available to every nested [`Readable`] implementation
+    /// through [ReadStream::context()]. It is useful for version-dependent
+    /// formats, where earlier header fields (format version, pointer size,
+    /// string table) control how later structures are decoded.
+    ///
+    /// The context is shared between clones of the reader. Use types with
+    /// interior mutability if nested implementations need to update it.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The context type
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The source data to read from
+    /// - `context`: The user context
+    ///
+    /// # Returns
+    ///
+    /// A new `ByteReader` instance with [Endian::Native] parameter and the attached context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// struct Header {
+    ///     version: u8,
+    /// }
+    ///
+    /// let data = [0x01, 0x02];
+    /// let reader = ByteReader::with_context(&data, Header { version: 2 });
+    ///
+    /// assert_eq!(reader.context::<Header>().unwrap().version, 2);
+    /// assert!(reader.context::<u32>().is_none());
+    /// ```
+    pub fn with_context<C: Any + Send + Sync>(data: &'a [u8], context: C) -> Self {
+        Self {
+            data,
+            pos: 0,
+            endian: Endian::Native,
+            context: Some(Arc::new(context)),
+            trace: None,
+        }
+    }
+
+    /// Decodes hexadecimal text into an [`OwnedReader`].
+    ///
+    /// `ByteReader` borrows its data, so the decoded bytes are owned by the
+    /// returned [`OwnedReader`], which creates readers with [`Endian::Native`].
+    /// ASCII whitespace between the digits is ignored, which allows writing
+    /// test fixtures as readable dumps. See [`Hex`] for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The hexadecimal digits
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decoded bytes
+    /// - An error if the text is not valid hexadecimal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let fixture = ByteReader::from_hex("2a00 0100\n ff").unwrap();
+    /// let mut reader = fixture.reader();
+    ///
+    /// assert_eq!(reader.read::<u16>().unwrap(), 42); // Assuming little endian
+    /// assert_eq!(reader.rest_bytes(), [0x01, 0x00, 0xFF]);
+    /// ```
+    pub fn from_hex(text: &str) -> Result<OwnedReader> {
+        Ok(OwnedReader::new(Hex::decode(text)?))
+    }
+
+    /// Decodes Base64 text into an [`OwnedReader`].
+    ///
+    /// `ByteReader` borrows its data, so the decoded bytes are owned by the
+    /// returned [`OwnedReader`], which creates readers with [`Endian::Native`].
+    ///
+    /// # Parameters
+    ///
+    /// - `text`: The Base64 text
+    /// - `base64`: The alphabet and padding of the text, see [`Base64`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decoded bytes
+    /// - An error if the text is not valid Base64
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{encoding::Base64, reader::ByteReader};
+    ///
+    /// let payload = ByteReader::from_base64("AQID-_8", Base64::UrlSafeNoPad).unwrap();
+    /// assert_eq!(payload.as_slice(), [0x01, 0x02, 0x03, 0xFB, 0xFF]);
+    /// ```
+    pub fn from_base64(text: &str, base64: Base64) -> Result<OwnedReader> {
+        Ok(OwnedReader::new(base64.decode(text)?))
+    }
+
+    /// Returns a reference to the attached user context of type `C`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The expected context type
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&context)` if a context of type `C` is attached
+    /// - `None` if there is no context or it has a different type
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let reader = ByteReader::with_context(&[0u8; 4], 64u8);
+    /// assert_eq!(reader.context::<u8>(), Some(&64));
+    /// assert_eq!(reader.context::<u16>(), None);
+    /// ```
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.context.as_deref()?.downcast_ref::<C>()
+    }
+
+    /// Attaches a user context, replacing the previous one.
+    ///
+    /// Allows setting the context after the values it depends on were read,
+    /// for example after parsing a file header.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The context type
+    ///
+    /// # Parameters
+    ///
+    /// - `context`: The new user context
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    /// let mut reader = ByteReader::new(&data);
+    ///
+    /// let pointer_size: u8 = reader.read().unwrap();
+    /// reader.set_context(pointer_size);
+    ///
+    /// assert_eq!(reader.context::<u8>(), Some(&8));
+    /// ```
+    pub fn set_context<C: Any + Send + Sync>(&mut self, context: C) {
+        self.context = Some(Arc::new(context));
+    }
+
+    /// Removes the attached user context.
+    pub fn clear_context(&mut self) {
+        self.context = None;
+    }
+
     /// Returns the current endianness setting.
     ///
     /// This setting affects how multi-byte values (u16, u32, f32, etc.) are
@@ -744,6 +941,36 @@ impl<'a> ByteReader<'a> {
         self.data
     }
 
+    /// Creates a [`HexDump`] of the whole data with the current position highlighted.
+    ///
+    /// The dump uses the canonical `hexdump -C` layout by default and can be
+    /// configured with the builder methods of [`HexDump`].
+    ///
+    /// # Returns
+    ///
+    /// A [`HexDump`] borrowing the data of the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"\x7fELF\x02\x01\x01");
+    /// reader.skip(4).unwrap();
+    ///
+    /// assert_eq!(
+    ///     reader.hexdump().to_string(),
+    ///     "00000000  7f 45 4c 46[02]01 01                              |.ELF...|"
+    /// );
+    /// assert_eq!(
+    ///     reader.hexdump().ascii(false).uppercase(true).range(..4).to_string(),
+    ///     "00000000  7F 45 4C 46"
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> HexDump<'a> {
+        HexDump::new(self.data).highlight(self.pos..self.pos + 1)
+    }
+
     /// Returns the total length of the underlying data.
     ///
     /// Provides the size of the entire data stream in bytes, regardless of
@@ -959,188 +1186,1066 @@ impl<'a> ByteReader<'a> {
     /// assert_eq!(value, 0x04030201);
     /// ```
     pub fn read<R: Readable<'a>>(&mut self) -> Result<R> {
-        R::read(ReadStream { reader: self })
+        self.traced(type_name::<R>(), None, |reader| {
+            R::read(ReadStream { reader })
+        })
     }
 
-    /// Peeks exactly `size` bytes without advancing position.
+    /// Reads a value of type `R` using external arguments and advances position.
     ///
-    /// Returns a reference to `size` bytes starting from the current position.
+    /// Works like [read()](ByteReader::read) for types that cannot be decoded
+    /// in isolation, such as an array whose length came from a previous field.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: A type that implements [`ReadableWith`]
+    /// - `A`: The type of arguments
     ///
     /// # Parameters
     ///
-    /// - `size`: The number of bytes to peek
+    /// - `args`: The arguments passed to the [`ReadableWith`] implementation
     ///
     /// # Returns
     ///
-    /// - `Ok(slice)` containing the requested bytes
-    /// - [`Error::InsufficientData`] if not enough bytes are available
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read
     ///
     /// # Examples
     ///
     /// ```rust
     /// use bytecraft::reader::ByteReader;
+    /// use bytecraft::common::Endian;
     ///
-    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
-    /// let bytes = reader.peek_bytes(3).unwrap();
-    /// assert_eq!(bytes, &[1, 2, 3]);
-    /// assert_eq!(reader.position(), 0);
+    /// let data = [0x00, 0x02, 0x00, 0x01, 0x00, 0x02, 0xFF];
+    /// let mut reader = ByteReader::with_endian(&data, Endian::Big);
+    ///
+    /// let count: u16 = reader.read().unwrap();
+    /// let values: Vec<u16> = reader.read_with(count as usize).unwrap();
+    /// assert_eq!(values, vec![1, 2]);
+    ///
+    /// let rest: &[u8] = reader.read_with(1).unwrap();
+    /// assert_eq!(rest, &[0xFF]);
     /// ```
-    pub fn peek_bytes(&mut self, size: usize) -> Result<&'a [u8]> {
-        self.peek_exact(size)
+    pub fn read_with<R: ReadableWith<'a, A>, A>(&mut self, args: A) -> Result<R> {
+        self.traced(type_name::<R>(), None, |reader| {
+            R::read_with(ReadStream { reader }, args)
+        })
     }
 
-    /// Reads exactly `size` bytes and advances position.
+    /// Starts recording a [`ParseMap`] of the following reads.
     ///
-    /// Returns a reference to `size` bytes starting from the current position
-    /// and advances the reader's position by `size` bytes.
+    /// Every call of [read()](ByteReader::read) and
+    /// [read_with()](ByteReader::read_with), including the nested calls made
+    /// by [`Readable`] implementations, records a span of the consumed bytes.
+    /// A previously recorded map is discarded. Clones of the reader do not
+    /// trace, so values previewed with [PeekStream::preview()] are not recorded.
+    ///
+    /// See the [trace] module for the recorded data and the export formats.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[0x01, 0x02, 0x03]);
+    /// reader.start_trace();
+    ///
+    /// let _: (u8, u16) = reader.read().unwrap();
+    /// let map = reader.take_trace().unwrap();
+    ///
+    /// assert_eq!(map.to_string(), "(u8, u16) [0..3]\n  u8 [0..1]\n  u16 [1..3]");
+    /// assert!(!reader.is_tracing());
+    /// ```
+    pub fn start_trace(&mut self) {
+        self.trace = Some(Box::default());
+    }
+
+    /// Stops tracing and returns the recorded [`ParseMap`].
+    ///
+    /// Reads still in progress, when called from a nested [`Readable`]
+    /// implementation, are closed at the current position.
+    ///
+    /// # Returns
+    ///
+    /// The recorded map, or `None` if tracing was not started.
+    pub fn take_trace(&mut self) -> Option<ParseMap> {
+        self.trace.take().map(|tracer| tracer.finish(self.pos))
+    }
+
+    /// Returns `true` if the reader records a [`ParseMap`].
+    pub fn is_tracing(&self) -> bool {
+        self.trace.is_some()
+    }
+
+    /// Reads a value of type `R` as a named field and advances position.
+    ///
+    /// Works like [read()](ByteReader::read), and records the field name in
+    /// the span of the read when tracing is enabled.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: A type that implements [`Readable`]
     ///
     /// # Parameters
     ///
-    /// - `size`: The number of bytes to read
+    /// - `name`: The field name
     ///
     /// # Returns
     ///
-    /// - `Ok(slice)` containing the requested bytes
-    /// - [`Error::InsufficientData`] if not enough bytes are available
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read
     ///
     /// # Examples
     ///
     /// ```rust
     /// use bytecraft::reader::ByteReader;
     ///
-    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
-    /// let bytes = reader.read_bytes(3).unwrap();
-    /// assert_eq!(bytes, &[1, 2, 3]);
-    /// assert_eq!(reader.position(), 3);
+    /// let mut reader = ByteReader::new(&[0x2A, 0x00]);
+    /// reader.start_trace();
+    ///
+    /// let answer: u16 = reader.read_field("answer").unwrap();
+    /// assert_eq!(answer, 42);
+    ///
+    /// let map = reader.take_trace().unwrap();
+    /// assert_eq!(map.spans()[0].field(), Some("answer"));
+    /// assert_eq!(map.spans()[0].range(), 0..2);
     /// ```
-    pub fn read_bytes(&mut self, size: usize) -> Result<&'a [u8]> {
-        self.read_exact(size)
+    pub fn read_field<R: Readable<'a>>(&mut self, name: &'static str) -> Result<R> {
+        self.traced(type_name::<R>(), Some(name), |reader| {
+            R::read(ReadStream { reader })
+        })
     }
 
-    /// Reads exactly `size` bytes into a new `Vec<u8>` and advances position.
+    /// Runs a closure as a named field.
     ///
-    /// Similar to [`read_bytes()`](ByteReader::read_bytes), but returns an owned `Vec<u8>` instead of
-    /// a slice reference. Useful when you need owned data or when working with
-    /// APIs that require owned collections.
+    /// Records the bytes consumed by the closure as a single span when tracing
+    /// is enabled, with the reads made by the closure nested in it. Useful for
+    /// fields read without [`Readable`], like raw bytes and strings.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `T`: The type of the value returned by the closure
     ///
     /// # Parameters
     ///
-    /// - `size`: The number of bytes to read
+    /// - `name`: The field name
+    /// - `f`: The closure reading the field
     ///
     /// # Returns
     ///
-    /// - `Ok(vec)` containing the requested bytes
-    /// - [`Error::InsufficientData`] if not enough bytes are available
+    /// - `Ok(value)` containing the result of the closure
+    /// - An error returned by the closure
     ///
     /// # Examples
     ///
     /// ```rust
     /// use bytecraft::reader::ByteReader;
     ///
-    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
-    /// let bytes = reader.read_vec(3).unwrap();
-    /// assert_eq!(bytes, vec![1, 2, 3]);
-    /// assert_eq!(reader.position(), 3);
+    /// let mut reader = ByteReader::new(b"\x05hello");
+    /// reader.start_trace();
+    ///
+    /// let name: &str = reader
+    ///     .field("name", |s| {
+    ///         let length: u8 = s.read()?;
+    ///         s.read_ascii(length as usize)
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(name, "hello");
+    ///
+    /// let map = reader.take_trace().unwrap();
+    /// assert_eq!(map.to_string(), "name: &str [0..6]\n  u8 [0..1]");
     /// ```
-    pub fn read_vec(&mut self, size: usize) -> Result<Vec<u8>> {
-        self.read_exact(size).map(Vec::from)
+    pub fn field<T>(
+        &mut self,
+        name: &'static str,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<T> {
+        self.traced(type_name::<T>(), Some(name), |reader| {
+            f(&mut ReadStream { reader })
+        })
+    }
+
+    /// Runs a read, recording its span when tracing is enabled.
+    fn traced<T>(
+        &mut self,
+        type_name: &'static str,
+        field: Option<&'static str>,
+        f: impl FnOnce(&mut Self) -> Result<T>,
+    ) -> Result<T> {
+        let Some(tracer) = self.trace.as_mut() else {
+            return f(self);
+        };
+
+        tracer.open(self.pos, type_name, field);
+        let result: Result<T> = f(self);
+        if let Some(tracer) = self.trace.as_mut() {
+            tracer.close(self.pos, result.is_err());
+        }
+        result
     }
 
-    /// Peeks ASCII string as `&str` of specified length and advances position.
+    /// Reads a value of type `R`, restoring the reader state on error.
     ///
-    /// Peeks `size` bytes and attempts to interpret them as an ASCII string slice.
-    /// Validates that all bytes are valid ASCII characters (0-127) and UTF-8 sequence.
+    /// Works like [read()](ByteReader::read), but if reading fails, the position
+    /// and endianness of the reader are restored to the values they had
+    /// before the call. This allows trying alternative decodings of the same bytes.
     ///
-    /// # Parameters
+    /// # Type Parameters
     ///
-    /// - `size`: The number of bytes to read as ASCII
+    /// - `R`: A type that implements [`Readable`]
     ///
     /// # Returns
     ///
-    /// - `Ok(string)` containing the ASCII text
-    /// - [`Error::NotValidAscii`] if non-ASCII bytes are found
-    /// - [`Error::NotValidUTF8`] if bytes are not valid UTF-8
-    /// - [`Error::InsufficientData`] if not enough bytes are available
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read, with the reader state unchanged
     ///
     /// # Examples
     ///
     /// ```rust
     /// use bytecraft::reader::ByteReader;
     ///
-    /// let mut reader = ByteReader::new(b"Hello World");
-    /// let ascii: &str = reader.peek_ascii(5).unwrap();
-    /// assert_eq!(ascii, "Hello");
+    /// let mut reader = ByteReader::new(&[0x01, 0x02, 0x03]);
+    /// reader.skip(1).unwrap();
     ///
-    /// // Non-ASCII data returns error
-    /// let mut reader = ByteReader::new(&[0xC0, 0x80][..]); // Invalid UTF-8
-    /// assert!(reader.read_ascii(2).is_err());
+    /// assert!(reader.try_read::<u32>().is_err()); // Not enough data
+    /// assert_eq!(reader.position(), 1); // Position restored
+    ///
+    /// let value: u16 = reader.try_read().unwrap();
+    /// assert_eq!(value, 0x0302);
     /// ```
-    pub fn peek_ascii(&self, size: usize) -> Result<&'a str> {
-        match self
-            .peek_exact(size)
-            .map(|bytes| std::str::from_utf8(bytes))?
-        {
-            Ok(data) if data.is_ascii() => Ok(data),
-            Ok(_) => Err(Error::NotValidAscii),
-            Err(err) => Err(Error::NotValidUTF8(err)),
-        }
+    pub fn try_read<R: Readable<'a>>(&mut self) -> Result<R> {
+        let mut checkpoint: Checkpoint<'a, '_> = self.checkpoint();
+        let value: R = checkpoint.read()?;
+        checkpoint.commit();
+        Ok(value)
     }
 
-    /// Reads ASCII string as `&str` of specified length and advances position.
-    ///
-    /// Reads `size` bytes and attempts to interpret them as an ASCII string slice.
-    /// Validates that all bytes are valid ASCII characters (0-127) and UTF-8 sequence.
-    ///
-    /// # Parameters
+    /// Creates a [`Checkpoint`] guard at the current reader state.
     ///
-    /// - `size`: The number of bytes to read as ASCII
+    /// The guard dereferences to this reader and restores its position and
+    /// endianness when dropped, unless [Checkpoint::commit()] is called.
     ///
     /// # Returns
     ///
-    /// - `Ok(string)` containing the ASCII text
-    /// - [`Error::NotValidAscii`] if non-ASCII bytes are found
-    /// - [`Error::NotValidUTF8`] if bytes are not valid UTF-8
-    /// - [`Error::InsufficientData`] if not enough bytes are available
+    /// A new [`Checkpoint`] borrowing this reader.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use bytecraft::reader::ByteReader;
     ///
-    /// let mut reader = ByteReader::new(b"Hello World");
-    /// let ascii: String = reader.read_ascii(5).unwrap().into();
-    /// assert_eq!(ascii, "Hello");
+    /// let mut reader = ByteReader::new(&[0x01, 0x02, 0x03, 0x04]);
     ///
-    /// // Non-ASCII data returns error
-    /// let mut reader = ByteReader::new(&[0xC0, 0x80][..]); // Invalid UTF-8
-    /// assert!(reader.read_ascii(2).is_err());
+    /// {
+    ///     let mut checkpoint = reader.checkpoint();
+    ///     let magic: u16 = checkpoint.read().unwrap();
+    ///     if magic == 0x0201 {
+    ///         checkpoint.commit();
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(reader.position(), 2);
     /// ```
-    pub fn read_ascii(&mut self, size: usize) -> Result<&'a str> {
-        match self
-            .read_exact(size)
-            .map(|bytes| std::str::from_utf8(bytes))?
-        {
-            Ok(data) if data.is_ascii() => Ok(data),
-            Ok(_) => Err(Error::NotValidAscii),
-            Err(err) => Err(Error::NotValidUTF8(err)),
-        }
+    pub fn checkpoint(&mut self) -> Checkpoint<'a, '_> {
+        Checkpoint::new(self)
     }
 
-    /// Checks if the requested size is within data bounds.
+    /// Tries to read several types in order and returns the first success.
     ///
-    /// Internal helper method that validates whether reading `size` bytes from
-    /// the current position would exceed the data boundaries.
+    /// Every type of the tuple `A` is read with [try_read()](ByteReader::try_read),
+    /// so the reader is rolled back after every failed alternative. The first
+    /// successfully read value is converted into `T`.
     ///
-    /// # Parameters
+    /// # Type Parameters
     ///
-    /// - `size`: The number of bytes to check
+    /// - `T`: The result type
+    /// - `A`: A tuple of [`Readable`] types convertible into `T`, see [`Alternatives`]
     ///
     /// # Returns
     ///
-    /// - `Ok(())` if the read would be within bounds
-    /// - [`Error::InsufficientData`] if the read would exceed bounds
-    #[inline]
-    fn check_bounds(&self, size: usize) -> Result<()> {
+    /// - `Ok(value)` of the first alternative that was read successfully
+    /// - The error of the last alternative if all of them failed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[0x02, 0x00]);
+    ///
+    /// // 0x02 is not a valid bool, so u16 is read instead
+    /// let value: u64 = reader.alt::<_, (bool, u16)>().unwrap();
+    /// assert_eq!(value, 2);
+    /// ```
+    pub fn alt<T, A: Alternatives<'a, T>>(&mut self) -> Result<T> {
+        A::read_first(self)
+    }
+
+    /// Runs a [`Parser`] and advances position.
+    ///
+    /// Allows reading data with the combinators of the [combinator] module.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `T`: The type of the parsed value
+    /// - `P`: A type that implements [`Parser`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `T`
+    /// - An error if the value cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    /// use bytecraft::reader::combinator::{read, separated, tag};
+    ///
+    /// let mut reader = ByteReader::new(b"a:b:c");
+    /// let items: Vec<u8> = reader.parse(separated(read::<u8>(), tag(b":"))).unwrap();
+    /// assert_eq!(items, b"abc");
+    /// ```
+    pub fn parse<T, P: Parser<'a, T>>(&mut self, mut parser: P) -> Result<T> {
+        parser.parse(&mut ReadStream { reader: self })
+    }
+
+    /// Runs a closure and computes a checksum over the bytes it consumed.
+    ///
+    /// The checksum covers the data between the position before and after the
+    /// closure. See the [checksum](crate::checksum) module for the built-in algorithms.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The checksum algorithm, see [`Checksum`]
+    /// - `T`: The type of the value returned by the closure
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The closure reading the data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, checksum))` containing the result of the closure and the checksum
+    /// - [`Error::NotValid`] if the closure moved the position backwards
+    /// - An error returned by the closure
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{checksum::Adler32, reader::ByteReader};
+    ///
+    /// let mut reader = ByteReader::new(b"Wikipedia");
+    /// let (text, adler) = reader.digest::<Adler32, _>(|s| s.read_ascii(9)).unwrap();
+    ///
+    /// assert_eq!(text, "Wikipedia");
+    /// assert_eq!(adler, 0x11E60398);
+    /// ```
+    pub fn digest<C: Checksum, T>(
+        &mut self,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<(T, C::Output)> {
+        let start: usize = self.pos;
+        let value: T = f(&mut ReadStream { reader: self })?;
+        let bytes: &[u8] = self.data.get(start..self.pos).ok_or(Error::NotValid)?;
+
+        Ok((value, C::checksum(bytes)))
+    }
+
+    /// Runs a closure, then reads a trailing checksum and compares it with
+    /// the checksum of the bytes the closure consumed.
+    ///
+    /// The trailing checksum is read as `C::Output` with the endianness of
+    /// the reader. The position is not restored on error.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The checksum algorithm, see [`Checksum`]
+    /// - `T`: The type of the value returned by the closure
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The closure reading the data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the result of the closure
+    /// - [`Error::Custom`] holding a [`ChecksumMismatch`] error if the checksums differ
+    /// - [`Error::NotValid`] if the closure moved the position backwards
+    /// - An error returned by the closure or when reading the checksum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{checksum::Crc16Modbus, common::Endian, reader::ByteReader};
+    ///
+    /// // Modbus RTU frame: read holding registers request and CRC
+    /// let frame = [0x01, 0x03, 0x00, 0x00, 0x00, 0x0A, 0xC5, 0xCD];
+    /// let mut reader = ByteReader::with_endian(&frame, Endian::Little);
+    ///
+    /// let (address, function) = reader
+    ///     .verify::<Crc16Modbus, _>(|s| {
+    ///         let header: (u8, u8) = s.read()?;
+    ///         s.skip(4)?;
+    ///         Ok(header)
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!((address, function), (0x01, 0x03));
+    /// ```
+    pub fn verify<C: Checksum, T>(
+        &mut self,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<T>
+    where
+        C::Output: Readable<'a>,
+    {
+        let (value, actual) = self.digest::<C, T>(f)?;
+        let expected: C::Output = self.read()?;
+
+        match expected == actual {
+            true => Ok(value),
+            false => Err(ChecksumMismatch::new(expected.into(), actual.into()).into()),
+        }
+    }
+
+    /// Decompresses a chunk of `size` bytes and advances position.
+    ///
+    /// The decompressed data is limited to [`DEFAULT_LIMIT`] bytes. See the
+    /// [compression](crate::compression) module for the built-in codecs.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The compression codec, see [`Codec`]
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The size of the compressed chunk
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decompressed data,
+    ///   with the endianness and the context of this reader
+    /// - [`Error::InsufficientData`] if there are fewer than `size` bytes remaining
+    /// - An error returned by the codec, the position is not changed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, compression::Rle, reader::ByteReader};
+    ///
+    /// // Chunk size, then two big endian u16 values packed with PackBits
+    /// let data = [0x04, 0xFE, 0x00, 0x00, 0x2A];
+    /// let mut reader = ByteReader::with_endian(&data, Endian::Big);
+    ///
+    /// let size: u8 = reader.read().unwrap();
+    /// let chunk = reader.decompress::<Rle>(size as usize).unwrap();
+    /// let values: [u16; 2] = chunk.reader().read().unwrap();
+    ///
+    /// assert_eq!(values, [0x0000, 0x002A]);
+    /// assert!(reader.is_eof());
+    /// ```
+    pub fn decompress<C: Codec>(&mut self, size: usize) -> Result<OwnedReader> {
+        self.decompress_with_limit::<C>(size, DEFAULT_LIMIT)
+    }
+
+    /// Decompresses a chunk of `size` bytes with a custom size limit and advances position.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The compression codec, see [`Codec`]
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The size of the compressed chunk
+    /// - `limit`: The maximum size of the decompressed data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decompressed data
+    /// - [`Error::Custom`] holding a [`LimitExceeded`](crate::compression::LimitExceeded)
+    ///   error if the decompressed data is larger than `limit`
+    /// - [`Error::InsufficientData`] if there are fewer than `size` bytes remaining
+    /// - An error returned by the codec, the position is not changed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{compression::Lz4Block, reader::ByteReader};
+    ///
+    /// // 1 literal byte, then a match of 19 bytes at offset 1
+    /// let block = [0x1F, 0x00, 0x01, 0x00, 0x00, 0x50, 0x00, 0x00, 0x00, 0x00, 0x00];
+    ///
+    /// let mut reader = ByteReader::new(&block);
+    /// assert!(reader.decompress_with_limit::<Lz4Block>(11, 16).is_err());
+    /// assert_eq!(reader.position(), 0);
+    ///
+    /// let chunk = reader.decompress_with_limit::<Lz4Block>(11, 25).unwrap();
+    /// assert_eq!(chunk.as_slice(), [0u8; 25]);
+    /// ```
+    pub fn decompress_with_limit<C: Codec>(
+        &mut self,
+        size: usize,
+        limit: usize,
+    ) -> Result<OwnedReader> {
+        let data: Vec<u8> = C::decompress(self.peek_exact(size)?, limit)?;
+        self.pos += size;
+        Ok(OwnedReader::inherit(data, self))
+    }
+
+    /// Returns an iterator reading values of type `T` until the end of data.
+    ///
+    /// Every item is a `Result<T>`. By default the iteration stops after the
+    /// first error, leaving the reader at the start of the failed record. Use
+    /// [Records::skip_on_error()] to skip broken records instead.
+    ///
+    /// # Returns
+    ///
+    /// A [`Records`] iterator borrowing the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    /// use bytecraft::common::Endian;
+    ///
+    /// let data = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+    /// let mut reader = ByteReader::with_endian(&data, Endian::Big);
+    ///
+    /// let values: Vec<u16> = reader.iter::<u16>().map(|v| v.unwrap()).collect();
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// assert!(reader.is_eof());
+    /// ```
+    pub fn iter<T: Readable<'a>>(&mut self) -> Records<'a, '_, T> {
+        Records::new(self, None)
+    }
+
+    /// Returns an iterator reading exactly `count` values of type `T`.
+    ///
+    /// Unlike [iter()](ByteReader::iter), the iteration does not stop at the
+    /// end of data: a missing record is returned as an error.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: The number of records to read
+    ///
+    /// # Returns
+    ///
+    /// A [`Records`] iterator borrowing the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [0x02, 0x0A, 0x0B, 0xFF];
+    /// let mut reader = ByteReader::new(&data);
+    ///
+    /// let count: u8 = reader.read().unwrap();
+    /// let items: Vec<u8> = reader
+    ///     .iter_n::<u8>(count as usize)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(items, vec![0x0A, 0x0B]);
+    /// assert_eq!(reader.position(), 3);
+    ///
+    /// assert!(reader.iter_n::<u16>(1).next().unwrap().is_err());
+    /// ```
+    pub fn iter_n<T: Readable<'a>>(&mut self, count: usize) -> Records<'a, '_, T> {
+        Records::new(self, Some(count))
+    }
+
+    /// Peeks exactly `size` bytes without advancing position.
+    ///
+    /// Returns a reference to `size` bytes starting from the current position.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to peek
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slice)` containing the requested bytes
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
+    /// let bytes = reader.peek_bytes(3).unwrap();
+    /// assert_eq!(bytes, &[1, 2, 3]);
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn peek_bytes(&mut self, size: usize) -> Result<&'a [u8]> {
+        self.peek_exact(size)
+    }
+
+    /// Reads exactly `size` bytes and advances position.
+    ///
+    /// Returns a reference to `size` bytes starting from the current position
+    /// and advances the reader's position by `size` bytes.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to read
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slice)` containing the requested bytes
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
+    /// let bytes = reader.read_bytes(3).unwrap();
+    /// assert_eq!(bytes, &[1, 2, 3]);
+    /// assert_eq!(reader.position(), 3);
+    /// ```
+    pub fn read_bytes(&mut self, size: usize) -> Result<&'a [u8]> {
+        self.read_exact(size)
+    }
+
+    /// Reads exactly `size` bytes into a new `Vec<u8>` and advances position.
+    ///
+    /// Similar to [`read_bytes()`](ByteReader::read_bytes), but returns an owned `Vec<u8>` instead of
+    /// a slice reference. Useful when you need owned data or when working with
+    /// APIs that require owned collections.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to read
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(vec)` containing the requested bytes
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[1, 2, 3, 4, 5]);
+    /// let bytes = reader.read_vec(3).unwrap();
+    /// assert_eq!(bytes, vec![1, 2, 3]);
+    /// assert_eq!(reader.position(), 3);
+    /// ```
+    pub fn read_vec(&mut self, size: usize) -> Result<Vec<u8>> {
+        self.read_exact(size).map(Vec::from)
+    }
+
+    /// Reads an unsigned integer of `size` bytes and advances position.
+    ///
+    /// Reads integers of any width from 1 to 8 bytes, such as 24-bit or 48-bit
+    /// values, using the current endianness of the reader.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes of the integer, from 1 to 8
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the integer zero-extended to `u64`
+    /// - [`Error::NotValid`] if `size` is 0 or greater than 8
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, reader::ByteReader};
+    ///
+    /// let mut reader = ByteReader::with_endian(&[0x01, 0x02, 0x03], Endian::Big);
+    /// assert_eq!(reader.read_uint_n(3).unwrap(), 0x010203);
+    /// assert!(reader.is_eof());
+    /// ```
+    pub fn read_uint_n(&mut self, size: usize) -> Result<u64> {
+        if size == 0 || size > size_of::<u64>() {
+            return Err(Error::NotValid);
+        }
+
+        let bytes: &[u8] = self.read_exact(size)?;
+        let fold = |value: u64, byte: &u8| (value << 8) | *byte as u64;
+
+        match self.endian {
+            Endian::Big => Ok(bytes.iter().fold(0, fold)),
+            Endian::Little => Ok(bytes.iter().rev().fold(0, fold)),
+            #[cfg(target_endian = "big")]
+            Endian::Native => Ok(bytes.iter().fold(0, fold)),
+            #[cfg(target_endian = "little")]
+            Endian::Native => Ok(bytes.iter().rev().fold(0, fold)),
+        }
+    }
+
+    /// Reads a signed integer of `size` bytes and advances position.
+    ///
+    /// Works like [`read_uint_n()`](ByteReader::read_uint_n), and extends the
+    /// sign of the most significant bit of the integer.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes of the integer, from 1 to 8
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the integer sign-extended to `i64`
+    /// - [`Error::NotValid`] if `size` is 0 or greater than 8
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, reader::ByteReader};
+    ///
+    /// let mut reader = ByteReader::with_endian(&[0xFE, 0xFF, 0xFF, 0x01, 0x00, 0x00], Endian::Little);
+    /// assert_eq!(reader.read_int_n(3).unwrap(), -2);
+    /// assert_eq!(reader.read_int_n(3).unwrap(), 1);
+    /// ```
+    pub fn read_int_n(&mut self, size: usize) -> Result<i64> {
+        let value: u64 = self.read_uint_n(size)?;
+        let shift: usize = 64 - size * 8;
+
+        Ok(((value << shift) as i64) >> shift)
+    }
+
+    /// Peeks ASCII string as `&str` of specified length and advances position.
+    ///
+    /// Peeks `size` bytes and attempts to interpret them as an ASCII string slice.
+    /// Validates that all bytes are valid ASCII characters (0-127) and UTF-8 sequence.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to read as ASCII
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(string)` containing the ASCII text
+    /// - [`Error::NotValidAscii`] if non-ASCII bytes are found
+    /// - [`Error::NotValidUTF8`] if bytes are not valid UTF-8
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"Hello World");
+    /// let ascii: &str = reader.peek_ascii(5).unwrap();
+    /// assert_eq!(ascii, "Hello");
+    ///
+    /// // Non-ASCII data returns error
+    /// let mut reader = ByteReader::new(&[0xC0, 0x80][..]); // Invalid UTF-8
+    /// assert!(reader.read_ascii(2).is_err());
+    /// ```
+    pub fn peek_ascii(&self, size: usize) -> Result<&'a str> {
+        match self
+            .peek_exact(size)
+            .map(|bytes| std::str::from_utf8(bytes))?
+        {
+            Ok(data) if data.is_ascii() => Ok(data),
+            Ok(_) => Err(Error::NotValidAscii),
+            Err(err) => Err(Error::NotValidUTF8(err)),
+        }
+    }
+
+    /// Reads ASCII string as `&str` of specified length and advances position.
+    ///
+    /// Reads `size` bytes and attempts to interpret them as an ASCII string slice.
+    /// Validates that all bytes are valid ASCII characters (0-127) and UTF-8 sequence.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to read as ASCII
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(string)` containing the ASCII text
+    /// - [`Error::NotValidAscii`] if non-ASCII bytes are found
+    /// - [`Error::NotValidUTF8`] if bytes are not valid UTF-8
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"Hello World");
+    /// let ascii: String = reader.read_ascii(5).unwrap().into();
+    /// assert_eq!(ascii, "Hello");
+    ///
+    /// // Non-ASCII data returns error
+    /// let mut reader = ByteReader::new(&[0xC0, 0x80][..]); // Invalid UTF-8
+    /// assert!(reader.read_ascii(2).is_err());
+    /// ```
+    pub fn read_ascii(&mut self, size: usize) -> Result<&'a str> {
+        match self
+            .read_exact(size)
+            .map(|bytes| std::str::from_utf8(bytes))?
+        {
+            Ok(data) if data.is_ascii() => Ok(data),
+            Ok(_) => Err(Error::NotValidAscii),
+            Err(err) => Err(Error::NotValidUTF8(err)),
+        }
+    }
+
+    /// Finds the first occurrence of `pattern` in the remaining data.
+    ///
+    /// Searches from the current position to the end of the data without
+    /// changing the reader's position.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The byte sequence to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the first occurrence
+    /// - `None` if the pattern is not found
+    ///
+    /// An empty pattern is found at the current position.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"abcabc");
+    /// assert_eq!(reader.find(b"bc"), Some(1));
+    ///
+    /// reader.skip(2).unwrap();
+    /// assert_eq!(reader.find(b"bc"), Some(4));
+    /// assert_eq!(reader.find(b"cb"), None);
+    /// ```
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        search::find_bytes(self.rest_bytes(), pattern).map(|idx| self.pos + idx)
+    }
+
+    /// Finds the last occurrence of `pattern` in the remaining data.
+    ///
+    /// Searches from the end of the data back to the current position without
+    /// changing the reader's position.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The byte sequence to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the last occurrence
+    /// - `None` if the pattern is not found
+    ///
+    /// An empty pattern is found at the end of the data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let reader = ByteReader::new(b"abcabc");
+    /// assert_eq!(reader.rfind(b"ab"), Some(3));
+    /// assert_eq!(reader.rfind(b"x"), None);
+    /// ```
+    pub fn rfind(&self, pattern: &[u8]) -> Option<usize> {
+        search::rfind_bytes(self.rest_bytes(), pattern).map(|idx| self.pos + idx)
+    }
+
+    /// Finds the first occurrence of `byte` in the remaining data.
+    ///
+    /// # Parameters
+    ///
+    /// - `byte`: The byte to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the first occurrence
+    /// - `None` if the byte is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// // MPEG-TS sync byte
+    /// let reader = ByteReader::new(&[0x00, 0x00, 0x47, 0x40]);
+    /// assert_eq!(reader.find_byte(0x47), Some(2));
+    /// ```
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        self.rest_bytes()
+            .iter()
+            .position(|&b| b == byte)
+            .map(|idx| self.pos + idx)
+    }
+
+    /// Finds the first occurrence of a value at an aligned position.
+    ///
+    /// Reads a value of type `T` at every position of the remaining data that
+    /// is a multiple of `alignment`, and compares it with `value`. The current
+    /// endianness is used for reading. The reader's position is not changed.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to search for
+    /// - `alignment`: The alignment of candidate positions (must be a power of 2)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(position))` - absolute position of the first occurrence
+    /// - `Ok(None)` if the value is not found
+    /// - [`Error::NotValid`] if alignment is not a power of 2
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{reader::ByteReader, common::Endian};
+    ///
+    /// let data = [0x00, 0xEF, 0xBE, 0x00, 0xEF, 0xBE, 0x00, 0x00];
+    /// let reader = ByteReader::with_endian(&data, Endian::Little);
+    ///
+    /// assert_eq!(reader.find_aligned(&0xBEEFu16, 1).unwrap(), Some(1));
+    /// assert_eq!(reader.find_aligned(&0xBEEFu16, 2).unwrap(), Some(4));
+    /// assert_eq!(reader.find_aligned(&0xBEEFu16, 8).unwrap(), None);
+    /// ```
+    pub fn find_aligned<T>(&self, value: &T, alignment: usize) -> Result<Option<usize>>
+    where
+        T: Readable<'a> + PartialEq,
+    {
+        if !alignment.is_power_of_two() {
+            return Err(Error::NotValid);
+        }
+
+        let mut reader: ByteReader<'a> = self.clone();
+        let pos: usize = (self.pos + (alignment - 1)) & !(alignment - 1);
+        reader.pos = pos.min(self.len());
+
+        while !reader.is_eof() {
+            let pos: usize = reader.position();
+            if let Ok(candidate) = reader.read::<T>() {
+                if candidate == *value {
+                    return Ok(Some(pos));
+                }
+            }
+            reader.pos = pos;
+            reader.skip_force(alignment);
+        }
+
+        Ok(None)
+    }
+
+    /// Skips forward to the first occurrence of `pattern`.
+    ///
+    /// Moves the position to the start of the pattern, so it can be read next.
+    /// If the pattern is not found, the position remains unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `pattern`: The byte sequence to search for
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(count)` with the number of skipped bytes
+    /// - [`Error::NotValid`] if the pattern is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"garbage\xFF\xD8data");
+    /// assert_eq!(reader.skip_until(b"\xFF\xD8").unwrap(), 7);
+    /// assert_eq!(reader.rest_bytes(), b"\xFF\xD8data");
+    ///
+    /// assert!(reader.skip_until(b"EOI").is_err());
+    /// assert_eq!(reader.position(), 7);
+    /// ```
+    pub fn skip_until(&mut self, pattern: &[u8]) -> Result<usize> {
+        let pos: usize = self.find(pattern).ok_or(Error::NotValid)?;
+        let count: usize = pos - self.pos;
+        self.pos = pos;
+        Ok(count)
+    }
+
+    /// Reads data up to the first occurrence of `delimiter` and advances position.
+    ///
+    /// Returns a reference to the bytes between the current position and the
+    /// delimiter. The delimiter itself is consumed but not included in the result.
+    /// If the delimiter is not found, the position remains unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `delimiter`: The byte sequence ending the read data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slice)` containing the bytes before the delimiter
+    /// - [`Error::NotValid`] if the delimiter is not found
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"key=value;next");
+    /// assert_eq!(reader.read_until(b"=").unwrap(), b"key");
+    /// assert_eq!(reader.read_until(b";").unwrap(), b"value");
+    /// assert_eq!(reader.rest_bytes(), b"next");
+    ///
+    /// assert!(reader.read_until(b";").is_err());
+    /// ```
+    pub fn read_until(&mut self, delimiter: &[u8]) -> Result<&'a [u8]> {
+        let pos: usize = self.find(delimiter).ok_or(Error::NotValid)?;
+        let result: &'a [u8] = self.read_exact(pos - self.pos)?;
+        self.pos += delimiter.len();
+        Ok(result)
+    }
+
+    /// Returns an iterator over the remaining data divided by `delimiter`.
+    ///
+    /// Every piece borrows the underlying data. The iterator advances the
+    /// reader past every returned piece and its delimiter, so the reader is
+    /// at the end of data when the iterator is exhausted.
+    ///
+    /// See [`Split`] for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `delimiter`: The byte sequence dividing the pieces
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(b"a|bb|ccc");
+    /// let mut pieces = reader.split(b"|");
+    ///
+    /// assert_eq!(pieces.next(), Some(&b"a"[..]));
+    /// assert_eq!(pieces.next(), Some(&b"bb"[..]));
+    /// assert_eq!(pieces.next(), Some(&b"ccc"[..]));
+    /// assert_eq!(pieces.next(), None);
+    /// ```
+    pub fn split<'d>(&mut self, delimiter: &'d [u8]) -> Split<'a, '_, 'd> {
+        Split::new(self, delimiter)
+    }
+
+    /// Checks if the requested size is within data bounds.
+    ///
+    /// Internal helper method that validates whether reading `size` bytes from
+    /// the current position would exceed the data boundaries.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes to check
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the read would be within bounds
+    /// - [`Error::InsufficientData`] if the read would exceed bounds
+    #[inline]
+    fn check_bounds(&self, size: usize) -> Result<()> {
         match self.pos.checked_add(size) {
             Some(pos) if pos <= self.len() => Ok(()),
             _ => Err(Error::InsufficientData {
@@ -1197,6 +2302,13 @@ impl<'a, 'r> PeekStream<'a, 'r> {
         self.reader.endian()
     }
 
+    /// Returns a reference to the user context of type `C` attached to the underlying reader.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::context`] method.
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.reader.context::<C>()
+    }
+
     /// Returns a slice containing all remaining unread bytes.
     ///
     /// # Returns
@@ -1256,6 +2368,51 @@ impl<'a, 'r> PeekStream<'a, 'r> {
         self.reader.peek::<P>()
     }
 
+    /// Reads a value of type `R` from a copy of the underlying reader.
+    ///
+    /// Allows implementing [`Peekable`] for composite types: the value is read
+    /// with its [`Readable`] implementation, but the position of the
+    /// underlying reader is not changed.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: A type that implements [`Readable`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{
+    ///     reader::{ByteReader, PeekStream},
+    ///     peekable::Peekable,
+    ///     error::Result
+    /// };
+    ///
+    /// struct Header {
+    ///     id: u16,
+    ///     flags: [u8; 2],
+    /// }
+    ///
+    /// impl<'a> Peekable<'a> for Header {
+    ///     fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+    ///         let (id, flags) = s.preview::<(u16, [u8; 2])>()?;
+    ///         Ok(Header { id, flags })
+    ///     }
+    /// }
+    ///
+    /// let reader = ByteReader::new(&[0x01, 0x00, 0xAA, 0xBB]);
+    /// let header: Header = reader.peek().unwrap();
+    /// assert_eq!(header.flags, [0xAA, 0xBB]);
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn preview<R: Readable<'a>>(&self) -> Result<R> {
+        self.reader.clone().read::<R>()
+    }
+
     /// Peeks ASCII string as `&str` of specified length and advances position.
     ///
     /// Delegates to the underlying reader's [`ByteReader::peek_ascii`] method.
@@ -1328,6 +2485,48 @@ impl<'a, 'r> ReadStream<'a, 'r> {
         self.reader.set_endian(endian);
     }
 
+    /// Returns a reference to the user context of type `C` attached to the underlying reader.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::context`] method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{
+    ///     reader::{ByteReader, ReadStream},
+    ///     readable::Readable,
+    ///     error::{Error, Result}
+    /// };
+    ///
+    /// struct Version(u8);
+    ///
+    /// struct Offset(u64);
+    ///
+    /// impl<'a> Readable<'a> for Offset {
+    ///     fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+    ///         match s.context::<Version>().ok_or(Error::NotValid)?.0 {
+    ///             1 => Ok(Offset(s.read::<u32>()? as u64)),
+    ///             _ => Ok(Offset(s.read::<u64>()?)),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let data = [0u8; 8];
+    /// let mut reader = ByteReader::with_context(&data, Version(1));
+    /// let _: Offset = reader.read().unwrap();
+    /// assert_eq!(reader.position(), 4);
+    /// ```
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.reader.context::<C>()
+    }
+
+    /// Attaches a user context to the underlying reader, replacing the previous one.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::set_context`] method.
+    pub fn set_context<C: Any + Send + Sync>(&mut self, context: C) {
+        self.reader.set_context(context);
+    }
+
     /// Returns a slice containing all remaining unread bytes.
     ///
     /// # Returns
@@ -1477,6 +2676,194 @@ impl<'a, 'r> ReadStream<'a, 'r> {
         self.reader.read::<R>()
     }
 
+    /// Reads a value of type `R` using external arguments and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::read_with`] method.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: A type that implements [`ReadableWith`]
+    /// - `A`: The type of arguments
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read
+    pub fn read_with<R: ReadableWith<'a, A>, A>(&mut self, args: A) -> Result<R> {
+        self.reader.read_with::<R, A>(args)
+    }
+
+    /// Returns `true` if the reader records a [`ParseMap`].
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::is_tracing`] method.
+    pub fn is_tracing(&self) -> bool {
+        self.reader.is_tracing()
+    }
+
+    /// Reads a value of type `R` as a named field and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::read_field`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read
+    pub fn read_field<R: Readable<'a>>(&mut self, name: &'static str) -> Result<R> {
+        self.reader.read_field::<R>(name)
+    }
+
+    /// Runs a closure as a named field.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::field`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the result of the closure
+    /// - An error returned by the closure
+    pub fn field<T>(
+        &mut self,
+        name: &'static str,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<T> {
+        self.reader.field::<T>(name, f)
+    }
+
+    /// Reads a value of type `R`, restoring the reader state on error.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::try_read`] method.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `R`: A type that implements [`Readable`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `R`
+    /// - An error if the value cannot be read, with the reader state unchanged
+    pub fn try_read<R: Readable<'a>>(&mut self) -> Result<R> {
+        self.reader.try_read::<R>()
+    }
+
+    /// Creates a [`Checkpoint`] guard at the current reader state.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::checkpoint`] method.
+    ///
+    /// # Returns
+    ///
+    /// A new [`Checkpoint`] borrowing the underlying reader.
+    pub fn checkpoint(&mut self) -> Checkpoint<'a, '_> {
+        self.reader.checkpoint()
+    }
+
+    /// Tries to read several types in order and returns the first success.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::alt`] method.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `T`: The result type
+    /// - `A`: A tuple of [`Readable`] types convertible into `T`, see [`Alternatives`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of the first alternative that was read successfully
+    /// - The error of the last alternative if all of them failed
+    pub fn alt<T, A: Alternatives<'a, T>>(&mut self) -> Result<T> {
+        self.reader.alt::<T, A>()
+    }
+
+    /// Runs a [`Parser`] and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::parse`] method.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `T`: The type of the parsed value
+    /// - `P`: A type that implements [`Parser`]
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` of type `T`
+    /// - An error if the value cannot be parsed
+    pub fn parse<T, P: Parser<'a, T>>(&mut self, parser: P) -> Result<T> {
+        self.reader.parse::<T, P>(parser)
+    }
+
+    /// Runs a closure and computes a checksum over the bytes it consumed.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::digest`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, checksum))` containing the result of the closure and the checksum
+    /// - An error returned by the closure
+    pub fn digest<C: Checksum, T>(
+        &mut self,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<(T, C::Output)> {
+        self.reader.digest::<C, T>(f)
+    }
+
+    /// Runs a closure and verifies the trailing checksum of the bytes it consumed.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::verify`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the result of the closure
+    /// - An error if the checksums differ or the data cannot be read
+    pub fn verify<C: Checksum, T>(
+        &mut self,
+        f: impl FnOnce(&mut ReadStream<'a, '_>) -> Result<T>,
+    ) -> Result<T>
+    where
+        C::Output: Readable<'a>,
+    {
+        self.reader.verify::<C, T>(f)
+    }
+
+    /// Decompresses a chunk of `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::decompress`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decompressed data
+    /// - An error if the chunk cannot be read or decompressed
+    pub fn decompress<C: Codec>(&mut self, size: usize) -> Result<OwnedReader> {
+        self.reader.decompress::<C>(size)
+    }
+
+    /// Decompresses a chunk of `size` bytes with a custom size limit and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::decompress_with_limit`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(owned)` containing an [`OwnedReader`] over the decompressed data
+    /// - An error if the chunk cannot be read or decompressed
+    pub fn decompress_with_limit<C: Codec>(
+        &mut self,
+        size: usize,
+        limit: usize,
+    ) -> Result<OwnedReader> {
+        self.reader.decompress_with_limit::<C>(size, limit)
+    }
+
+    /// Returns an iterator reading values of type `T` until the end of data.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::iter`] method.
+    pub fn iter<T: Readable<'a>>(&mut self) -> Records<'a, '_, T> {
+        self.reader.iter()
+    }
+
+    /// Returns an iterator reading exactly `count` values of type `T`.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::iter_n`] method.
+    pub fn iter_n<T: Readable<'a>>(&mut self, count: usize) -> Records<'a, '_, T> {
+        self.reader.iter_n(count)
+    }
+
     /// Peeks ASCII string as `&str` of specified length and advances position.
     ///
     /// Delegates to the underlying reader's [`ByteReader::peek_ascii`] method.
@@ -1524,6 +2911,90 @@ impl<'a, 'r> ReadStream<'a, 'r> {
     pub fn read_vec(&mut self, size: usize) -> Result<Vec<u8>> {
         self.reader.read_vec(size)
     }
+
+    /// Reads an unsigned integer of `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::read_uint_n`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the integer zero-extended to `u64`
+    /// - An error if the value cannot be read
+    pub fn read_uint_n(&mut self, size: usize) -> Result<u64> {
+        self.reader.read_uint_n(size)
+    }
+
+    /// Reads a signed integer of `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::read_int_n`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the integer sign-extended to `i64`
+    /// - An error if the value cannot be read
+    pub fn read_int_n(&mut self, size: usize) -> Result<i64> {
+        self.reader.read_int_n(size)
+    }
+
+    /// Finds the first occurrence of `pattern` in the remaining data.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::find`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the first occurrence
+    /// - `None` if the pattern is not found
+    pub fn find(&self, pattern: &[u8]) -> Option<usize> {
+        self.reader.find(pattern)
+    }
+
+    /// Finds the last occurrence of `pattern` in the remaining data.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::rfind`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the last occurrence
+    /// - `None` if the pattern is not found
+    pub fn rfind(&self, pattern: &[u8]) -> Option<usize> {
+        self.reader.rfind(pattern)
+    }
+
+    /// Finds the first occurrence of `byte` in the remaining data.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::find_byte`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(position)` - absolute position of the first occurrence
+    /// - `None` if the byte is not found
+    pub fn find_byte(&self, byte: u8) -> Option<usize> {
+        self.reader.find_byte(byte)
+    }
+
+    /// Skips forward to the first occurrence of `pattern`.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::skip_until`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(count)` with the number of skipped bytes
+    /// - [`Error::NotValid`] if the pattern is not found
+    pub fn skip_until(&mut self, pattern: &[u8]) -> Result<usize> {
+        self.reader.skip_until(pattern)
+    }
+
+    /// Reads data up to the first occurrence of `delimiter` and advances position.
+    ///
+    /// Delegates to the underlying reader's [`ByteReader::read_until`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(slice)` containing the bytes before the delimiter
+    /// - [`Error::NotValid`] if the delimiter is not found
+    pub fn read_until(&mut self, delimiter: &[u8]) -> Result<&'a [u8]> {
+        self.reader.read_until(delimiter)
+    }
 }
 
 impl Clone for ByteReader<'_> {
@@ -1532,6 +3003,8 @@ impl Clone for ByteReader<'_> {
             data: self.data,
             pos: self.pos,
             endian: self.endian,
+            context: self.context.clone(),
+            trace: None,
         }
     }
 }
@@ -1542,6 +3015,14 @@ impl PartialEq for ByteReader<'_> {
     }
 }
 
+impl core::hash::Hash for ByteReader<'_> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+        self.pos.hash(state);
+        self.endian.hash(state);
+    }
+}
+
 impl<'a> From<&'a [u8]> for ByteReader<'a> {
     fn from(value: &'a [u8]) -> Self {
         Self::new(value)
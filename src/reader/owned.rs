@@ -0,0 +1,179 @@
+//! Readers over owned data.
+//!
+//! The `owned` module provides the [`OwnedReader`], which owns a buffer
+//! produced while reading, like decompressed data, and lends it as a
+//! [`ByteReader`] with the endianness and the context of the reader it came
+//! from.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::common::Endian;
+//! use bytecraft::reader::owned::OwnedReader;
+//!
+//! let owned = OwnedReader::with_endian(vec![0x00, 0x2A], Endian::Big);
+//! let mut reader = owned.reader();
+//!
+//! assert_eq!(reader.read::<u16>().unwrap(), 42);
+//! assert_eq!(owned.into_inner(), vec![0x00, 0x2A]);
+//! ```
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::common::Endian;
+use crate::reader::ByteReader;
+
+/// An owned buffer that can be read with a [`ByteReader`].
+///
+/// `ByteReader` borrows its data, so data created while reading cannot be
+/// returned as a `ByteReader`. `OwnedReader` keeps the data together with the
+/// endianness and the user context, and creates readers over it with
+/// [reader()](OwnedReader::reader).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::{owned::OwnedReader, ByteReader};
+///
+/// let owned = OwnedReader::with_context(vec![1, 2, 3], 7u8);
+/// let mut reader: ByteReader = owned.reader();
+///
+/// assert_eq!(reader.read_bytes(3).unwrap(), [1, 2, 3]);
+/// assert_eq!(reader.context::<u8>(), Some(&7));
+/// ```
+#[derive(Clone)]
+pub struct OwnedReader {
+    data: Vec<u8>,
+    endian: Endian,
+    context: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl OwnedReader {
+    /// Creates a new `OwnedReader` with [Endian::Native] parameter.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The data to own
+    pub fn new(data: Vec<u8>) -> Self {
+        Self::with_endian(data, Endian::Native)
+    }
+
+    /// Creates a new `OwnedReader` with specified endianness.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The data to own
+    /// - `endian`: The byte order of the created readers
+    pub fn with_endian(data: Vec<u8>, endian: Endian) -> Self {
+        Self {
+            data,
+            endian,
+            context: None,
+        }
+    }
+
+    /// Creates a new `OwnedReader` with an attached user context.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The context type
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The data to own
+    /// - `context`: The user context of the created readers
+    pub fn with_context<C: Any + Send + Sync>(data: Vec<u8>, context: C) -> Self {
+        Self {
+            data,
+            endian: Endian::Native,
+            context: Some(Arc::new(context)),
+        }
+    }
+
+    /// Creates an `OwnedReader` sharing the endianness and the context of a reader.
+    pub(crate) fn inherit(data: Vec<u8>, reader: &ByteReader<'_>) -> Self {
+        Self {
+            data,
+            endian: reader.endian,
+            context: reader.context.clone(),
+        }
+    }
+
+    /// Creates a new `ByteReader` over the owned data.
+    ///
+    /// # Returns
+    ///
+    /// A reader positioned at byte 0 with the endianness and the context of
+    /// this `OwnedReader`.
+    pub fn reader(&self) -> ByteReader<'_> {
+        ByteReader {
+            data: &self.data,
+            pos: 0,
+            endian: self.endian,
+            context: self.context.clone(),
+            trace: None,
+        }
+    }
+
+    /// Returns the endianness of the created readers.
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// Sets the endianness of the created readers.
+    ///
+    /// # Parameters
+    ///
+    /// - `endian`: The new byte order
+    pub fn set_endian(&mut self, endian: Endian) {
+        self.endian = endian;
+    }
+
+    /// Returns the owned data as a slice.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Consumes the `OwnedReader` and returns the owned data.
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the length of the owned data in bytes.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the owned data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl PartialEq for OwnedReader {
+    fn eq(&self, other: &Self) -> bool {
+        self.data.eq(&other.data)
+    }
+}
+
+impl core::hash::Hash for OwnedReader {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.data.hash(state);
+    }
+}
+
+impl From<Vec<u8>> for OwnedReader {
+    fn from(value: Vec<u8>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl core::fmt::Debug for OwnedReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OwnedReader")
+            .field("data", &self.data)
+            .field("endian", &self.endian)
+            .finish()
+    }
+}
@@ -9,13 +9,13 @@
 //!
 //! ## Peekable Trait
 //!
-//! The [`Peekable`] trait is the counterpart to [`Readable`][super::Readable], allowing types
+//! The [`Peekable`] trait is the counterpart to [`Readable`], allowing types
 //! to be inspected from a [`PeekStream`] without advancing the reader's position.
 //! This enables non-destructive examination of data.
 //!
 //! ## Difference from Readable
 //!
-//! While [`Readable`][super::Readable] consumes data and advances the stream position, [`Peekable`]
+//! While [`Readable`] consumes data and advances the stream position, [`Peekable`]
 //! leaves the position unchanged:
 //!
 //! ```rust
@@ -40,7 +40,9 @@
 //! # Built-in Implementations
 //!
 //! ByteCraft provides `Peekable` implementations for the same standard types
-//! that implement `Readable`, except tupples and arrays.
+//! that implement `Readable`. Composite types, such as tuples, arrays, `Vec`
+//! and `String`, are peeked through their `Readable` implementation with
+//! [PeekStream::preview()](crate::reader::PeekStream::preview).
 //!
 //! ## Primitive Types
 //!
@@ -192,14 +194,30 @@
 //! assert_eq!(preview, actual);
 //! ```
 
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use core::ops::Range;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::CString;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::rc::Rc;
+use std::sync::Arc;
+
 use crate::common::Endian;
 use crate::error::{Error, Result};
+use crate::reader::readable::Readable;
 use crate::reader::PeekStream;
 
 /// A trait for types that can be peeked at from a binary stream.
 ///
 /// The `Peekable` trait defines how a type should be inspected from a
-/// sequence of bytes without consuming the data. Unlike [`Readable`][super::Readable], peeking
+/// sequence of bytes without consuming the data. Unlike [`Readable`], peeking
 /// does not advance the stream position, allowing the same data to be read
 /// again later.
 ///
@@ -216,7 +234,8 @@ use crate::reader::PeekStream;
 ///
 /// `Peekable` implementations can not compose other `Peekable` types, because
 /// the stream position does not change during a call.
-/// Use another ByteReader to peek complex types.
+/// Use [PeekStream::preview()](crate::reader::PeekStream::preview) or another
+/// ByteReader to peek complex types.
 ///
 /// # Examples
 ///
@@ -279,7 +298,7 @@ use crate::reader::PeekStream;
 ///
 /// - [`crate::reader::ByteReader::peek()`] - The primary method for peeking at `Peekable` types
 /// - [`crate::reader::PeekStream`] - The stream type provided to implementations
-/// - [`Readable`][super::Readable] - The consuming counterpart to this trait
+/// - [`Readable`] - The consuming counterpart to this trait
 pub trait Peekable<'a>: Sized {
     /// Peeks at a value of this type from the provided stream.
     ///
@@ -365,3 +384,225 @@ impl<'a> Peekable<'a> for bool {
         }
     }
 }
+
+impl<'a> Peekable<'a> for () {
+    /// Peeks nothing.
+    fn peek<'r>(_: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl<'a, T: ?Sized> Peekable<'a> for PhantomData<T> {
+    /// Peeks nothing.
+    fn peek<'r>(_: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(PhantomData)
+    }
+}
+
+macro_rules! impl_non_zero {
+    ($Type:ty, $Inner:ty) => {
+        impl<'a> Peekable<'a> for $Type {
+            /// Peeks the underlying number and checks that it is not zero.
+            ///
+            /// Returns error [Error::NotValid] if the value is zero.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                <$Type>::new(s.peek::<$Inner>()?).ok_or(Error::NotValid)
+            }
+        }
+    };
+}
+
+impl_non_zero!(NonZeroU8, u8);
+impl_non_zero!(NonZeroU16, u16);
+impl_non_zero!(NonZeroU32, u32);
+impl_non_zero!(NonZeroU64, u64);
+impl_non_zero!(NonZeroU128, u128);
+impl_non_zero!(NonZeroUsize, usize);
+impl_non_zero!(NonZeroI8, i8);
+impl_non_zero!(NonZeroI16, i16);
+impl_non_zero!(NonZeroI32, i32);
+impl_non_zero!(NonZeroI64, i64);
+impl_non_zero!(NonZeroI128, i128);
+impl_non_zero!(NonZeroIsize, isize);
+
+impl<'a, T: Peekable<'a>> Peekable<'a> for Wrapping<T> {
+    /// Peeks the underlying value with `T::peek()`.
+    fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(Wrapping(s.peek()?))
+    }
+}
+
+/// Implements `Peekable` for composite types through their `Readable` implementation.
+macro_rules! impl_preview {
+    ($(#[$meta:meta])* [$($Generics:tt)*] $Type:ty) => {
+        impl<'a, $($Generics)*> Peekable<'a> for $Type {
+            $(#[$meta])*
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+    };
+}
+
+impl_preview!(
+    /// Peeks a UTF-8 encoded character.
+    []
+    char
+);
+impl_preview!(
+    /// Peeks an optional value with a tag byte, see [`Readable`] implementation.
+    [T: Readable<'a>]
+    Option<T>
+);
+impl_preview!(
+    /// Peeks the value with `T::read()` and moves it to the heap.
+    [T: Readable<'a>]
+    Box<T>
+);
+impl_preview!(
+    /// Peeks the value with `T::read()` and wraps it into [`Rc`].
+    [T: Readable<'a>]
+    Rc<T>
+);
+impl_preview!(
+    /// Peeks the value with `T::read()` and wraps it into [`Arc`].
+    [T: Readable<'a>]
+    Arc<T>
+);
+impl_preview!(
+    /// Peeks a length-prefixed byte slice without copying.
+    []
+    Cow<'a, [u8]>
+);
+impl_preview!(
+    /// Peeks a length-prefixed UTF-8 string without copying.
+    []
+    Cow<'a, str>
+);
+impl_preview!(
+    /// Peeks a length-prefixed UTF-8 string without copying.
+    []
+    &'a str
+);
+impl_preview!(
+    /// Peeks the `start` and the `end` bound of the range.
+    [T: Readable<'a>]
+    Range<T>
+);
+impl_preview!(
+    /// Peeks the 4 octets of the address.
+    []
+    Ipv4Addr
+);
+impl_preview!(
+    /// Peeks the 16 octets of the address.
+    []
+    Ipv6Addr
+);
+impl_preview!(
+    /// Peeks a version byte and the address.
+    []
+    IpAddr
+);
+impl_preview!(
+    /// Peeks the address and the port in network byte order.
+    []
+    SocketAddrV4
+);
+impl_preview!(
+    /// Peeks the address and the port in network byte order.
+    []
+    SocketAddrV6
+);
+impl_preview!(
+    /// Peeks a version byte and the socket address.
+    []
+    SocketAddr
+);
+impl_preview!(
+    /// Peeks the seconds and the nanoseconds of the duration.
+    []
+    Duration
+);
+impl_preview!(
+    /// Peeks an array of `N` elements read with `T::read()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let reader = ByteReader::new(&[1u8, 2, 3, 4]);
+    /// let array: [u16; 2] = reader.peek().unwrap();
+    /// assert_eq!(array, [0x0201, 0x0403]);
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    [T: Readable<'a>, const N: usize]
+    [T; N]
+);
+impl_preview!(
+    /// Peeks a vector from a length-prefixed binary format.
+    [T: Readable<'a>]
+    Vec<T>
+);
+impl_preview!(
+    /// Peeks a string from a length-prefixed UTF-8 binary format.
+    []
+    String
+);
+impl_preview!(
+    /// Peeks a C string from a null-terminated binary format.
+    []
+    CString
+);
+impl_preview!(
+    /// Peeks a double-ended queue from a length-prefixed binary format.
+    [T: Readable<'a>]
+    VecDeque<T>
+);
+impl_preview!(
+    /// Peeks a map from a length-prefixed binary format, see [`Readable`] implementation.
+    [K: Readable<'a> + Eq + Hash, V: Readable<'a>, S: BuildHasher + Default]
+    HashMap<K, V, S>
+);
+impl_preview!(
+    /// Peeks an ordered map from a length-prefixed binary format.
+    [K: Readable<'a> + Ord, V: Readable<'a>]
+    BTreeMap<K, V>
+);
+impl_preview!(
+    /// Peeks a set from a length-prefixed binary format.
+    [T: Readable<'a> + Eq + Hash, S: BuildHasher + Default]
+    HashSet<T, S>
+);
+impl_preview!(
+    /// Peeks an ordered set from a length-prefixed binary format.
+    [T: Readable<'a> + Ord]
+    BTreeSet<T>
+);
+
+macro_rules! impl_tupple {
+    ($($Types:tt)+) => {
+        impl<'a, $($Types : Readable<'a>),+> Peekable<'a> for ($($Types ,)+) {
+            /// Peeks the tuple elements in order.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+    };
+}
+
+// Tuple implementations from 1 to 13 elements
+impl_tupple!(T0);
+impl_tupple!(T0 T1);
+impl_tupple!(T0 T1 T2);
+impl_tupple!(T0 T1 T2 T3);
+impl_tupple!(T0 T1 T2 T3 T4);
+impl_tupple!(T0 T1 T2 T3 T4 T5);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7 T8);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+impl_tupple!(T0 T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
@@ -13,6 +13,12 @@
 //! Any type that implements `Readable` can be read from a [`ReadStream`] using
 //! the [ByteReader::read()](crate::reader::ByteReader::read) method.
 //!
+//! ## ReadableWith Trait
+//!
+//! The [`ReadableWith`] trait is used for types that need external arguments,
+//! such as a length read from a previous field. They are read with the
+//! [ByteReader::read_with()](crate::reader::ByteReader::read_with) method.
+//!
 //! ## Composition and Nesting
 //!
 //! `Readable` implementations can compose other `Readable` types, enabling
@@ -102,6 +108,40 @@
 //! assert_eq!(tuple, (1, 0x0302u16)); // Assuming little endian
 //! ```
 //!
+//! ## Maps and Sets
+//!
+//! `HashMap`, `BTreeMap`, `HashSet`, `BTreeSet` and `VecDeque` use a `u32`
+//! length prefix like `Vec<T>`. Map entries are stored as a key followed by
+//! its value, and duplicate keys are rejected with [`Error::NotValid`]:
+//!
+//! ```rust
+//! use std::collections::BTreeMap;
+//! use bytecraft::reader::ByteReader;
+//!
+//! let data = [1u8, 0, 0, 0, b'k', 7];
+//! let mut reader = ByteReader::new(&data[..]);
+//!
+//! let map: BTreeMap<char, u8> = reader.read().unwrap();
+//! assert_eq!(map[&'k'], 7);
+//! ```
+//!
+//! ## Network Addresses
+//!
+//! `std::net` addresses always use network byte order, independent of the
+//! endianness of the reader. `IpAddr` and `SocketAddr` start with a version
+//! byte, `4` or `6`, and socket addresses end with a `u16` port:
+//!
+//! ```rust
+//! use std::net::{Ipv4Addr, SocketAddrV4};
+//! use bytecraft::reader::ByteReader;
+//!
+//! let data = [192u8, 168, 0, 1, 0x1F, 0x90];
+//! let mut reader = ByteReader::new(&data[..]);
+//!
+//! let addr: SocketAddrV4 = reader.read().unwrap();
+//! assert_eq!(addr, SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 8080));
+//! ```
+//!
 //! # Safety Considerations
 //!
 //! - Memory safety is maintained through proper bounds checking
@@ -169,8 +209,21 @@
 //! }
 //! ```
 
+use core::hash::{BuildHasher, Hash};
+use core::marker::PhantomData;
 use core::mem::MaybeUninit;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use core::ops::Range;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::ffi::CString;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::common::Endian;
 use crate::error::{Error, Result};
@@ -631,3 +684,588 @@ impl<'a> Readable<'a> for CString {
         CString::from_vec_with_nul(result).map_err(|err| Error::Custom(Box::new(err)))
     }
 }
+
+/// A trait for types that need external arguments to be read from a binary stream.
+///
+/// Some fields cannot be decoded in isolation: an array whose length came from
+/// a previous field, or a string whose encoding depends on a flag. The
+/// `ReadableWith` trait works like [`Readable`], but receives additional
+/// arguments of type `Args` from the caller through
+/// [ByteReader::read_with()](crate::reader::ByteReader::read_with).
+///
+/// Every `Readable` type implements `ReadableWith<'a, ()>`.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the borrowed data by `ByteReader`
+/// - `Args`: The type of arguments required for reading
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{
+///     reader::{ByteReader, ReadStream},
+///     readable::{Readable, ReadableWith},
+///     error::{Error, Result}
+/// };
+///
+/// enum Encoding {
+///     Ascii,
+///     Utf8,
+/// }
+///
+/// struct Name(String);
+///
+/// impl<'a> ReadableWith<'a, Encoding> for Name {
+///     fn read_with<'r>(mut s: ReadStream<'a, 'r>, args: Encoding) -> Result<Self> {
+///         let len: u8 = s.read()?;
+///         match args {
+///             Encoding::Ascii => Ok(Name(s.read_ascii(len as usize)?.to_string())),
+///             Encoding::Utf8 => {
+///                 let bytes: &[u8] = s.read_with(len as usize)?;
+///                 let name: &str = std::str::from_utf8(bytes).map_err(Error::NotValidUTF8)?;
+///                 Ok(Name(name.to_string()))
+///             }
+///         }
+///     }
+/// }
+///
+/// struct Entry {
+///     names: Vec<Name>,
+/// }
+///
+/// impl<'a> Readable<'a> for Entry {
+///     fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+///         let is_utf8: bool = s.read()?;
+///         let count: u8 = s.read()?;
+///
+///         let mut names: Vec<Name> = Vec::new();
+///         for _ in 0..count {
+///             let encoding: Encoding = if is_utf8 { Encoding::Utf8 } else { Encoding::Ascii };
+///             names.push(s.read_with(encoding)?);
+///         }
+///
+///         Ok(Entry { names })
+///     }
+/// }
+///
+/// let data = [0x01, 0x01, 0x02, 0xC2, 0xB5];
+/// let mut reader = ByteReader::new(&data);
+/// let entry: Entry = reader.read().unwrap();
+/// assert_eq!(entry.names[0].0, "µ");
+/// ```
+pub trait ReadableWith<'a, Args>: Sized {
+    /// Reads a value of this type from the provided stream using `args`.
+    ///
+    /// This method is called by [ByteReader::read_with()](super::ByteReader::read_with)
+    /// to deserialize values.
+    ///
+    /// # Parameters
+    ///
+    /// - `stream`: A [`ReadStream`] providing access to the binary data
+    /// - `args`: The arguments required for reading
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the deserialized value
+    /// - An error if deserialization fails or data is invalid
+    fn read_with<'r>(s: ReadStream<'a, 'r>, args: Args) -> Result<Self>;
+}
+
+impl<'a, T: Readable<'a>> ReadableWith<'a, ()> for T {
+    /// Reads the value with its [`Readable`] implementation.
+    fn read_with<'r>(mut s: ReadStream<'a, 'r>, _: ()) -> Result<Self> {
+        s.read::<T>()
+    }
+}
+
+impl<'a, T: Readable<'a>> ReadableWith<'a, usize> for Vec<T> {
+    /// Reads a vector of exactly `count` elements without a length prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [0x02, 0x0A, 0x0B];
+    /// let mut reader = ByteReader::new(&data);
+    ///
+    /// let count: u8 = reader.read().unwrap();
+    /// let items: Vec<u8> = reader.read_with(count as usize).unwrap();
+    /// assert_eq!(items, vec![0x0A, 0x0B]);
+    /// ```
+    fn read_with<'r>(mut s: ReadStream<'a, 'r>, count: usize) -> Result<Self> {
+        let mut result: Vec<T> = Vec::with_capacity(count.min(s.rest_len()));
+
+        for _ in 0..count {
+            let val: T = s.read()?;
+            result.push(val);
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a> ReadableWith<'a, usize> for &'a [u8] {
+    /// Reads a slice of exactly `len` bytes without a length prefix.
+    ///
+    /// The returned slice borrows the underlying data, so no bytes are copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [0x03, b'a', b'b', b'c'];
+    /// let mut reader = ByteReader::new(&data);
+    ///
+    /// let len: u8 = reader.read().unwrap();
+    /// let bytes: &[u8] = reader.read_with(len as usize).unwrap();
+    /// assert_eq!(bytes, b"abc");
+    /// ```
+    fn read_with<'r>(mut s: ReadStream<'a, 'r>, len: usize) -> Result<Self> {
+        s.read_exact(len)
+    }
+}
+
+impl<'a> Readable<'a> for char {
+    /// Reads a UTF-8 encoded character.
+    ///
+    /// # Process
+    /// 1. Determines the encoded length (1 to 4 bytes) from the first byte
+    /// 2. Reads the bytes and validates them as UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new("a🦀".as_bytes());
+    /// assert_eq!(reader.read::<char>().unwrap(), 'a');
+    /// assert_eq!(reader.read::<char>().unwrap(), '🦀');
+    /// assert!(reader.is_eof());
+    /// ```
+    ///
+    /// # Error Propagation
+    ///
+    /// Invalid sequences result in [`Error::NotValidUTF8`].
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: usize = match s.peek::<u8>()? {
+            0xC0..=0xDF => 2,
+            0xE0..=0xEF => 3,
+            0xF0..=0xF7 => 4,
+            _ => 1,
+        };
+
+        let bytes: &[u8] = s.read_exact(size)?;
+        let string: &str = core::str::from_utf8(bytes).map_err(Error::NotValidUTF8)?;
+        string.chars().next().ok_or(Error::NotValid)
+    }
+}
+
+impl<'a> Readable<'a> for () {
+    /// Reads nothing. The stream position is not changed.
+    fn read<'r>(_: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(())
+    }
+}
+
+impl<'a, T: ?Sized> Readable<'a> for PhantomData<T> {
+    /// Reads nothing. The stream position is not changed.
+    fn read<'r>(_: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(PhantomData)
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for Option<T> {
+    /// Reads an optional value with a tag byte.
+    ///
+    /// # Process
+    /// 1. Reads a `u8` tag from the stream
+    /// 2. Returns `None` for tag `0`
+    /// 3. Reads the value with `T::read()` for tag `1`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [0x00, 0x01, 0x2A];
+    /// let mut reader = ByteReader::new(&data);
+    ///
+    /// assert_eq!(reader.read::<Option<u8>>().unwrap(), None);
+    /// assert_eq!(reader.read::<Option<u8>>().unwrap(), Some(42));
+    /// ```
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if the tag is not `0` or `1`.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        match s.read::<u8>()? {
+            0 => Ok(None),
+            1 => Ok(Some(s.read()?)),
+            _ => Err(Error::NotValid),
+        }
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for Box<T> {
+    /// Reads the value with `T::read()` and moves it to the heap.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Box::new(s.read()?))
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for Rc<T> {
+    /// Reads the value with `T::read()` and wraps it into [`Rc`].
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Rc::new(s.read()?))
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for Arc<T> {
+    /// Reads the value with `T::read()` and wraps it into [`Arc`].
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Arc::new(s.read()?))
+    }
+}
+
+macro_rules! impl_non_zero {
+    ($Type:ty, $Inner:ty) => {
+        impl<'a> Readable<'a> for $Type {
+            /// Reads the underlying number and checks that it is not zero.
+            ///
+            /// Returns error [Error::NotValid] if the value is zero.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                <$Type>::new(s.read::<$Inner>()?).ok_or(Error::NotValid)
+            }
+        }
+    };
+}
+
+impl_non_zero!(NonZeroU8, u8);
+impl_non_zero!(NonZeroU16, u16);
+impl_non_zero!(NonZeroU32, u32);
+impl_non_zero!(NonZeroU64, u64);
+impl_non_zero!(NonZeroU128, u128);
+impl_non_zero!(NonZeroUsize, usize);
+impl_non_zero!(NonZeroI8, i8);
+impl_non_zero!(NonZeroI16, i16);
+impl_non_zero!(NonZeroI32, i32);
+impl_non_zero!(NonZeroI64, i64);
+impl_non_zero!(NonZeroI128, i128);
+impl_non_zero!(NonZeroIsize, isize);
+
+impl<'a, T: Readable<'a>> Readable<'a> for Wrapping<T> {
+    /// Reads the underlying value with `T::read()`.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Wrapping(s.read()?))
+    }
+}
+
+impl<'a> Readable<'a> for Cow<'a, [u8]> {
+    /// Reads a length-prefixed byte slice without copying.
+    ///
+    /// # Process
+    /// 1. Reads a `u32` length prefix from the stream
+    /// 2. Borrows the specified number of bytes from the underlying data
+    ///
+    /// The format is the same as for `Vec<u8>`, but the result is always
+    /// [`Cow::Borrowed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [2u8, 0, 0, 0, 0xAA, 0xBB];
+    /// let mut reader = ByteReader::new(&data[..]);
+    /// let bytes: Cow<[u8]> = reader.read().unwrap();
+    /// assert!(matches!(bytes, Cow::Borrowed(&[0xAA, 0xBB])));
+    /// ```
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+        Ok(Cow::Borrowed(s.read_exact(size as usize)?))
+    }
+}
+
+impl<'a> Readable<'a> for Cow<'a, str> {
+    /// Reads a length-prefixed UTF-8 string without copying.
+    ///
+    /// The format is the same as for `String`, but the result is always
+    /// [`Cow::Borrowed`].
+    ///
+    /// # Error Propagation
+    ///
+    /// UTF-8 validation errors are wrapped in [`Error::NotValidUTF8`].
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+        let bytes: &'a [u8] = s.read_exact(size as usize)?;
+        Ok(Cow::Borrowed(
+            core::str::from_utf8(bytes).map_err(Error::NotValidUTF8)?,
+        ))
+    }
+}
+
+impl<'a> Readable<'a> for &'a str {
+    /// Reads a length-prefixed UTF-8 string without copying.
+    ///
+    /// The format is the same as for `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let data = [2u8, 0, 0, 0, b'h', b'i'];
+    /// let mut reader = ByteReader::new(&data[..]);
+    ///
+    /// let text: &str = reader.read().unwrap();
+    /// assert_eq!(text, "hi");
+    /// ```
+    ///
+    /// # Error Propagation
+    ///
+    /// UTF-8 validation errors are wrapped in [`Error::NotValidUTF8`].
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+        let bytes: &'a [u8] = s.read_exact(size as usize)?;
+        core::str::from_utf8(bytes).map_err(Error::NotValidUTF8)
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for Range<T> {
+    /// Reads the `start` and then the `end` bound of the range.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let (start, end) = s.read::<(T, T)>()?;
+        Ok(start..end)
+    }
+}
+
+impl<'a> Readable<'a> for Ipv4Addr {
+    /// Reads the 4 octets of the address.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Ipv4Addr::from(s.read::<[u8; 4]>()?))
+    }
+}
+
+impl<'a> Readable<'a> for Ipv6Addr {
+    /// Reads the 16 octets of the address.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Ipv6Addr::from(s.read::<[u8; 16]>()?))
+    }
+}
+
+impl<'a> Readable<'a> for IpAddr {
+    /// Reads a version byte, `4` or `6`, and then the address.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] for other version bytes.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        match s.read::<u8>()? {
+            4 => Ok(IpAddr::V4(s.read()?)),
+            6 => Ok(IpAddr::V6(s.read()?)),
+            _ => Err(Error::NotValid),
+        }
+    }
+}
+
+impl<'a> Readable<'a> for SocketAddrV4 {
+    /// Reads the address and then the port in network byte order.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let (ip, port) = s.read::<(Ipv4Addr, [u8; 2])>()?;
+        Ok(SocketAddrV4::new(ip, u16::from_be_bytes(port)))
+    }
+}
+
+impl<'a> Readable<'a> for SocketAddrV6 {
+    /// Reads the address and then the port in network byte order.
+    ///
+    /// The flow information and the scope identifier are not stored and are set to `0`.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let (ip, port) = s.read::<(Ipv6Addr, [u8; 2])>()?;
+        Ok(SocketAddrV6::new(ip, u16::from_be_bytes(port), 0, 0))
+    }
+}
+
+impl<'a> Readable<'a> for SocketAddr {
+    /// Reads a version byte, `4` or `6`, and then the socket address.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] for other version bytes.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        match s.read::<u8>()? {
+            4 => Ok(SocketAddr::V4(s.read()?)),
+            6 => Ok(SocketAddr::V6(s.read()?)),
+            _ => Err(Error::NotValid),
+        }
+    }
+}
+
+impl<'a> Readable<'a> for Duration {
+    /// Reads the seconds as `u64` and then the nanoseconds as `u32`.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if the nanoseconds are not less than `1_000_000_000`.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let (secs, nanos) = s.read::<(u64, u32)>()?;
+
+        match nanos < 1_000_000_000 {
+            true => Ok(Duration::new(secs, nanos)),
+            false => Err(Error::NotValid),
+        }
+    }
+}
+
+impl<'a, T: Readable<'a>> Readable<'a> for VecDeque<T> {
+    /// Reads a double-ended queue from a length-prefixed binary format.
+    ///
+    /// The format is the same as for `Vec<T>`.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+
+        let mut result: VecDeque<T> = VecDeque::with_capacity((size as usize).min(s.rest_len()));
+
+        for _ in 0..size {
+            result.push_back(s.read()?);
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a, K, V, S> Readable<'a> for HashMap<K, V, S>
+where
+    K: Readable<'a> + Eq + Hash,
+    V: Readable<'a>,
+    S: BuildHasher + Default,
+{
+    /// Reads a map from a length-prefixed binary format.
+    ///
+    /// # Process
+    /// 1. Reads a `u32` entry count from the stream
+    /// 2. Reads each entry as a key followed by its value
+    /// 3. Inserts the entries into the map
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::HashMap;
+    /// use bytecraft::{error::Error, reader::ByteReader};
+    ///
+    /// let data = [
+    ///     2u8, 0, 0, 0, // Count: 2 entries
+    ///     1, 10,        // 1 => 10
+    ///     2, 20,        // 2 => 20
+    /// ];
+    /// let mut reader = ByteReader::new(&data[..]);
+    /// let map: HashMap<u8, u8> = reader.read().unwrap();
+    /// assert_eq!(map[&1], 10);
+    /// assert_eq!(map[&2], 20);
+    ///
+    /// // Duplicate keys are rejected
+    /// let data = [2u8, 0, 0, 0, 1, 10, 1, 20];
+    /// let mut reader = ByteReader::new(&data[..]);
+    /// assert!(matches!(reader.read::<HashMap<u8, u8>>(), Err(Error::NotValid)));
+    /// ```
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if a key appears more than once.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+
+        let mut result: HashMap<K, V, S> =
+            HashMap::with_capacity_and_hasher((size as usize).min(s.rest_len()), S::default());
+
+        for _ in 0..size {
+            let (key, value) = s.read::<(K, V)>()?;
+
+            if result.insert(key, value).is_some() {
+                return Err(Error::NotValid);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a, K: Readable<'a> + Ord, V: Readable<'a>> Readable<'a> for BTreeMap<K, V> {
+    /// Reads an ordered map from a length-prefixed binary format.
+    ///
+    /// The format is the same as for `HashMap<K, V>`.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if a key appears more than once.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+
+        let mut result: BTreeMap<K, V> = BTreeMap::new();
+
+        for _ in 0..size {
+            let (key, value) = s.read::<(K, V)>()?;
+
+            if result.insert(key, value).is_some() {
+                return Err(Error::NotValid);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a, T, S> Readable<'a> for HashSet<T, S>
+where
+    T: Readable<'a> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    /// Reads a set from a length-prefixed binary format.
+    ///
+    /// The format is the same as for `Vec<T>`.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if a value appears more than once.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+
+        let mut result: HashSet<T, S> =
+            HashSet::with_capacity_and_hasher((size as usize).min(s.rest_len()), S::default());
+
+        for _ in 0..size {
+            if !result.insert(s.read()?) {
+                return Err(Error::NotValid);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl<'a, T: Readable<'a> + Ord> Readable<'a> for BTreeSet<T> {
+    /// Reads an ordered set from a length-prefixed binary format.
+    ///
+    /// The format is the same as for `Vec<T>`.
+    ///
+    /// # Error Propagation
+    ///
+    /// Returns [`Error::NotValid`] if a value appears more than once.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let size: u32 = s.read()?;
+
+        let mut result: BTreeSet<T> = BTreeSet::new();
+
+        for _ in 0..size {
+            if !result.insert(s.read()?) {
+                return Err(Error::NotValid);
+            }
+        }
+
+        Ok(result)
+    }
+}
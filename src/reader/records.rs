@@ -0,0 +1,246 @@
+//! Iterators over repeated records.
+//!
+//! The `records` module provides the [`Records`] iterator, which reads values
+//! of the same `Readable` type one after another. It replaces manual
+//! `while !reader.is_eof()` loops and allows consuming log and capture files
+//! with standard iterator adapters.
+//!
+//! # Error Policies
+//!
+//! - **Stop on error** (default): the error is returned as the last item of
+//!   the iterator, and the reader stays at the start of the failed record
+//! - **Skip on error**: the failed record is skipped with a user-defined
+//!   resynchronization function, and iteration continues
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::reader::ByteReader;
+//! use bytecraft::error::Result;
+//!
+//! let data = [0x01, 0x00, 0x02, 0x00, 0x03, 0x00];
+//! let mut reader = ByteReader::new(&data);
+//!
+//! let sum: u16 = reader
+//!     .iter::<u16>()
+//!     .collect::<Result<Vec<u16>>>()
+//!     .unwrap()
+//!     .iter()
+//!     .sum();
+//!
+//! assert_eq!(sum, 6);
+//! assert!(reader.is_eof());
+//! ```
+
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::error::Result;
+use crate::reader::readable::Readable;
+use crate::reader::ByteReader;
+
+/// The default resynchronization function type of [`Records`].
+pub type Resync<'a> = fn(&mut ByteReader<'a>) -> Result<()>;
+
+/// An iterator reading values of type `T` one after another.
+///
+/// Created by [ByteReader::iter()] and [ByteReader::iter_n()]. Every item is
+/// a `Result<T>`. The iterator ends when the end of data is reached, when the
+/// requested number of records was read, or after the first error (unless
+/// [skip_on_error()](Records::skip_on_error) is used).
+///
+/// Without a record count, the iterator also ends after a record that
+/// consumed no bytes, since reading it again would never reach the end of data.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the borrowed data by `ByteReader`
+/// - `'r`: The lifetime of the borrowed `ByteReader` instance
+/// - `T`: The record type that implements [`Readable`]
+/// - `F`: The resynchronization function type
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+///
+/// // The third record has an invalid bool value
+/// let data = [0x01, 0x00, 0x05, 0x01];
+/// let mut reader = ByteReader::new(&data);
+///
+/// let mut records = reader.iter::<bool>();
+/// assert_eq!(records.next().unwrap().unwrap(), true);
+/// assert_eq!(records.next().unwrap().unwrap(), false);
+/// assert!(records.next().unwrap().is_err());
+/// assert!(records.next().is_none());
+///
+/// // The reader stays at the failed record
+/// assert_eq!(reader.position(), 2);
+/// ```
+pub struct Records<'a, 'r, T, F = Resync<'a>>
+where
+    'a: 'r,
+{
+    reader: &'r mut ByteReader<'a>,
+    remaining: Option<usize>,
+    resync: Option<F>,
+    done: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, 'r, T> Records<'a, 'r, T> {
+    /// Creates a new iterator reading at most `count` records, or until the end of data.
+    pub(crate) fn new(reader: &'r mut ByteReader<'a>, count: Option<usize>) -> Self {
+        Self {
+            reader,
+            remaining: count,
+            resync: None,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'r, T, F> Records<'a, 'r, T, F> {
+    /// Ends the iteration on the first error.
+    ///
+    /// The error is returned as the last item, and the reader stays at the
+    /// start of the failed record. This is the default policy.
+    pub fn stop_on_error(self) -> Records<'a, 'r, T, F> {
+        Records {
+            resync: None,
+            ..self
+        }
+    }
+
+    /// Skips failed records instead of returning errors.
+    ///
+    /// When a record cannot be read, the reader is rolled back to the start of
+    /// the record and `resync` is called to move it to the next record. The
+    /// resynchronization function must move the reader forward, for example
+    /// by skipping to the next sync marker.
+    ///
+    /// If `resync` returns an error, or does not move the reader past the
+    /// start of the failed record, that error (or the record's error) is
+    /// returned as the last item.
+    ///
+    /// When used with [ByteReader::iter_n()], every skipped record is counted.
+    ///
+    /// # Parameters
+    ///
+    /// - `resync`: A function moving the reader to the next record
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// // Packets of [0x47, value], with some garbage between them
+    /// let data = [0x47, 0x01, 0xAA, 0xBB, 0x47, 0x02, 0x47, 0x03];
+    ///
+    /// # use bytecraft::{readable::Readable, reader::ReadStream, error::{Error, Result}};
+    /// struct Packet(u8);
+    ///
+    /// impl<'a> Readable<'a> for Packet {
+    ///     fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+    ///         match s.read::<[u8; 2]>()? {
+    ///             [0x47, value] => Ok(Packet(value)),
+    ///             _ => Err(Error::NotValid),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut reader = ByteReader::new(&data);
+    /// let values: Vec<u8> = reader
+    ///     .iter::<Packet>()
+    ///     .skip_on_error(|r| {
+    ///         r.skip(1)?;
+    ///         r.skip_until(&[0x47]).map(|_| ())
+    ///     })
+    ///     .map(|packet| packet.unwrap().0)
+    ///     .collect();
+    ///
+    /// assert_eq!(values, vec![1, 2, 3]);
+    /// ```
+    pub fn skip_on_error<G>(self, resync: G) -> Records<'a, 'r, T, G>
+    where
+        G: FnMut(&mut ByteReader<'a>) -> Result<()>,
+    {
+        Records {
+            reader: self.reader,
+            remaining: self.remaining,
+            resync: Some(resync),
+            done: self.done,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, F> Iterator for Records<'a, '_, T, F>
+where
+    T: Readable<'a>,
+    F: FnMut(&mut ByteReader<'a>) -> Result<()>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done || self.remaining == Some(0) {
+                return None;
+            }
+
+            if self.remaining.is_none() && self.reader.is_eof() {
+                self.done = true;
+                return None;
+            }
+
+            if let Some(remaining) = self.remaining.as_mut() {
+                *remaining -= 1;
+            }
+
+            let start: usize = self.reader.position();
+            let err = match self.reader.try_read::<T>() {
+                Ok(value) => {
+                    // A record consuming no bytes would be read forever
+                    if self.remaining.is_none() && self.reader.position() == start {
+                        self.done = true;
+                    }
+                    return Some(Ok(value));
+                }
+                Err(err) => err,
+            };
+
+            let resync: &mut F = match self.resync.as_mut() {
+                Some(resync) => resync,
+                None => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            if let Err(err) = resync(self.reader) {
+                self.done = true;
+                return Some(Err(err));
+            }
+
+            if self.reader.position() <= start {
+                self.done = true;
+                return Some(Err(err));
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.done, self.remaining) {
+            (true, _) => (0, Some(0)),
+            (false, remaining) => (0, remaining),
+        }
+    }
+}
+
+impl<'a, T, F> FusedIterator for Records<'a, '_, T, F>
+where
+    T: Readable<'a>,
+    F: FnMut(&mut ByteReader<'a>) -> Result<()>,
+{
+}
@@ -0,0 +1,160 @@
+//! Searching and scanning utilities for byte buffers.
+//!
+//! The `search` module contains the algorithms behind the search methods of
+//! [`ByteReader`], such as [find()](ByteReader::find), [rfind()](ByteReader::rfind)
+//! and [read_until()](ByteReader::read_until), and the [`Split`] iterator
+//! returned by [split()](ByteReader::split).
+//!
+//! Multi-byte patterns are searched with the Boyer-Moore-Horspool algorithm,
+//! which skips up to the pattern length on every mismatch and does not
+//! allocate, so it stays fast on large inputs.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::reader::ByteReader;
+//!
+//! // JPEG start of image marker somewhere in the data
+//! let data = [0x00, 0x00, 0xFF, 0xD8, 0xFF, 0xE0];
+//! let mut reader = ByteReader::new(&data);
+//!
+//! assert_eq!(reader.find(&[0xFF, 0xD8]), Some(2));
+//! reader.skip_until(&[0xFF, 0xD8]).unwrap();
+//! assert_eq!(reader.position(), 2);
+//! ```
+
+use crate::reader::ByteReader;
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+///
+/// An empty `needle` is found at index 0.
+pub(crate) fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let n: usize = needle.len();
+
+    match n {
+        0 => return Some(0),
+        1 => return haystack.iter().position(|&b| b == needle[0]),
+        _ if n > haystack.len() => return None,
+        _ => {}
+    }
+
+    // Shift for every byte value when it is the last byte of the window
+    let mut table: [usize; 256] = [n; 256];
+    for (i, &b) in needle[..n - 1].iter().enumerate() {
+        table[b as usize] = n - 1 - i;
+    }
+
+    let mut i: usize = 0;
+    while i + n <= haystack.len() {
+        let last: u8 = haystack[i + n - 1];
+        if last == needle[n - 1] && haystack[i..i + n - 1] == needle[..n - 1] {
+            return Some(i);
+        }
+        i += table[last as usize];
+    }
+
+    None
+}
+
+/// Returns the index of the last occurrence of `needle` in `haystack`.
+///
+/// An empty `needle` is found at index `haystack.len()`.
+pub(crate) fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let n: usize = needle.len();
+
+    match n {
+        0 => return Some(haystack.len()),
+        1 => return haystack.iter().rposition(|&b| b == needle[0]),
+        _ if n > haystack.len() => return None,
+        _ => {}
+    }
+
+    // Shift for every byte value when it is the first byte of the window
+    let mut table: [usize; 256] = [n; 256];
+    for (i, &b) in needle.iter().enumerate().skip(1).rev() {
+        table[b as usize] = i;
+    }
+
+    let mut i: usize = haystack.len() - n;
+    loop {
+        let first: u8 = haystack[i];
+        if first == needle[0] && haystack[i + 1..i + n] == needle[1..] {
+            return Some(i);
+        }
+
+        let shift: usize = table[first as usize];
+        if i < shift {
+            return None;
+        }
+        i -= shift;
+    }
+}
+
+/// An iterator over the pieces of the remaining reader data divided by a delimiter.
+///
+/// Created by [ByteReader::split()]. Every call to `next()` returns the data
+/// up to the next delimiter and advances the reader past the delimiter. The
+/// last piece is the data after the last delimiter. A trailing delimiter does
+/// not produce an empty final piece.
+///
+/// The pieces borrow the underlying data, not the reader, so no bytes are copied.
+///
+/// # Type Parameters
+///
+/// - `'a`: The lifetime of the borrowed data by `ByteReader`
+/// - `'r`: The lifetime of the borrowed `ByteReader` instance
+/// - `'d`: The lifetime of the delimiter
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::reader::ByteReader;
+///
+/// let mut reader = ByteReader::new(b"one\r\ntwo\r\n\r\nthree\r\n");
+/// let lines: Vec<&[u8]> = reader.split(b"\r\n").collect();
+///
+/// assert_eq!(lines, vec![&b"one"[..], b"two", b"", b"three"]);
+/// assert!(reader.is_eof());
+/// ```
+pub struct Split<'a, 'r, 'd>
+where
+    'a: 'r,
+{
+    reader: &'r mut ByteReader<'a>,
+    delimiter: &'d [u8],
+}
+
+impl<'a, 'r, 'd> Split<'a, 'r, 'd> {
+    /// Creates a new iterator over the remaining data of `reader`.
+    pub(crate) fn new(reader: &'r mut ByteReader<'a>, delimiter: &'d [u8]) -> Self {
+        Self { reader, delimiter }
+    }
+}
+
+impl<'a> Iterator for Split<'a, '_, '_> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.reader.is_eof() {
+            return None;
+        }
+
+        let rest: &'a [u8] = self.reader.rest_bytes();
+
+        let found: Option<usize> = match self.delimiter.is_empty() {
+            true => None,
+            false => find_bytes(rest, self.delimiter),
+        };
+
+        match found {
+            Some(idx) => {
+                self.reader.pos += idx + self.delimiter.len();
+                Some(&rest[..idx])
+            }
+            None => {
+                self.reader.pos += rest.len();
+                Some(rest)
+            }
+        }
+    }
+}
@@ -0,0 +1,494 @@
+//! Parse maps recorded while reading.
+//!
+//! The `trace` module provides the [`ParseMap`], a tree of the [`Span`]s
+//! consumed by every nested [`Readable`](crate::readable::Readable) call,
+//! recorded by a [`ByteReader`](crate::reader::ByteReader) in tracing mode.
+//! Tracing is opt-in: it is started with
+//! [ByteReader::start_trace()](crate::reader::ByteReader::start_trace) and
+//! the map is collected with
+//! [ByteReader::take_trace()](crate::reader::ByteReader::take_trace).
+//!
+//! Every span records the bytes between the position before and after the
+//! read, the name of the read type and an optional field name given with
+//! [read_field()](crate::reader::ByteReader::read_field) or
+//! [field()](crate::reader::ByteReader::field).
+//! Reads that failed are kept and marked as errors, while the reads rolled
+//! back by a [`Checkpoint`](crate::reader::checkpoint::Checkpoint), like the
+//! rejected alternatives of [alt()](crate::reader::ByteReader::alt), are discarded.
+//!
+//! A parse map can be exported as an indented tree with `Display`, as an
+//! annotated hex dump with [annotate()](ParseMap::annotate), and as JSON with
+//! [to_json()](ParseMap::to_json).
+//!
+//! Field names control the granularity of the labels: a span is described
+//! as a whole, unless one of its nested reads has a field name. For example,
+//! the elements of a `[u8; 4]` field are recorded, but the field is labeled
+//! as a single `[u8; 4]`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{
+//!     common::Endian,
+//!     error::Result,
+//!     reader::{ByteReader, ReadStream},
+//!     readable::Readable,
+//! };
+//!
+//! struct Header {
+//!     magic: [u8; 2],
+//!     length: u16,
+//! }
+//!
+//! impl<'a> Readable<'a> for Header {
+//!     fn read<'r>(mut stream: ReadStream<'a, 'r>) -> Result<Self> {
+//!         Ok(Header {
+//!             magic: stream.read_field("magic")?,
+//!             length: stream.read_field("length")?,
+//!         })
+//!     }
+//! }
+//!
+//! let data = [b'B', b'C', 0x00, 0x02, 0xCA, 0xFE];
+//! let mut reader = ByteReader::with_endian(&data, Endian::Big);
+//!
+//! reader.start_trace();
+//! let header: Header = reader.read_field("header").unwrap();
+//! let payload: &[u8] = reader.field("payload", |s| s.read_exact(header.length as usize)).unwrap();
+//! let map = reader.take_trace().unwrap();
+//!
+//! assert_eq!(payload, [0xCA, 0xFE]);
+//!
+//! assert_eq!(map.spans()[0].field(), Some("header"));
+//! assert_eq!(map.label(3).unwrap(), "header.length: u16");
+//!
+//! assert_eq!(
+//!     map.annotate(&reader.hexdump().clear_highlights().ascii(false)),
+//!     "00000000  42 43 00 02 ca fe  header.magic: [u8; 2], header.length: u16, payload: &[u8]"
+//! );
+//! ```
+
+use core::fmt::{Display, Formatter, Write};
+use core::ops::Range;
+
+use crate::hexdump::HexDump;
+
+/// A range of bytes consumed by a single read.
+///
+/// Spans are created by a [`ByteReader`](crate::reader::ByteReader) in tracing mode, see the
+/// [module-level documentation](self).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    start: usize,
+    end: usize,
+    type_name: &'static str,
+    field: Option<&'static str>,
+    error: bool,
+    children: Vec<Span>,
+}
+
+impl Span {
+    /// Returns the position before the read.
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the position after the read.
+    ///
+    /// If the read moved the position backwards, the span is empty and
+    /// `end` equals `start`.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Returns the indices of the consumed bytes.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the number of consumed bytes.
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if no bytes were consumed.
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the name of the read type, as given by [`core::any::type_name`].
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// Returns the field name given to the read, if any.
+    pub fn field(&self) -> Option<&'static str> {
+        self.field
+    }
+
+    /// Returns `true` if the read failed.
+    pub fn is_error(&self) -> bool {
+        self.error
+    }
+
+    /// Returns the spans of the nested reads, in the order they were made.
+    pub fn children(&self) -> &[Span] {
+        &self.children
+    }
+
+    fn has_named_children(&self) -> bool {
+        self.children
+            .iter()
+            .any(|child| child.field.is_some() || child.has_named_children())
+    }
+
+    fn write_tree(&self, f: &mut Formatter<'_>, depth: usize) -> core::fmt::Result {
+        write!(f, "{:1$}", "", depth * 2)?;
+        if let Some(field) = self.field {
+            write!(f, "{}: ", field)?;
+        }
+        write!(f, "{} [{}..{}]", self.type_name, self.start, self.end)?;
+        if self.error {
+            f.write_str(" (error)")?;
+        }
+
+        for child in &self.children {
+            f.write_char('\n')?;
+            child.write_tree(f, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    fn write_json(&self, json: &mut String) {
+        let _ = write!(
+            json,
+            "{{\"start\":{},\"end\":{},\"type\":",
+            self.start, self.end
+        );
+        write_json_string(json, self.type_name);
+        json.push_str(",\"field\":");
+        match self.field {
+            Some(field) => write_json_string(json, field),
+            None => json.push_str("null"),
+        }
+        let _ = write!(json, ",\"error\":{},\"children\":[", self.error);
+        for (index, child) in self.children.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            child.write_json(json);
+        }
+        json.push_str("]}");
+    }
+}
+
+fn write_json_string(json: &mut String, text: &str) {
+    json.push('"');
+    for character in text.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            '\u{0}'..='\u{1F}' => {
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            _ => json.push(character),
+        }
+    }
+    json.push('"');
+}
+
+/// Describes the innermost span of a chain with the field names leading to it.
+fn describe(chain: &[&Span]) -> String {
+    let mut label: String = String::new();
+    for field in chain.iter().filter_map(|span| span.field) {
+        if !label.is_empty() {
+            label.push('.');
+        }
+        label.push_str(field);
+    }
+
+    if let Some(span) = chain.last() {
+        if !label.is_empty() {
+            label.push_str(": ");
+        }
+        label.push_str(span.type_name);
+        if span.error {
+            label.push_str(" (error)");
+        }
+    }
+    label
+}
+
+/// A tree of the spans recorded by a [`ByteReader`](crate::reader::ByteReader) in tracing mode.
+///
+/// The `Display` implementation writes the tree with two spaces of
+/// indentation per level, one span per line.
+///
+/// See the [module-level documentation](self) for examples.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ParseMap {
+    spans: Vec<Span>,
+}
+
+impl ParseMap {
+    /// Returns the spans of the top-level reads, in the order they were made.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// Returns `true` if no reads were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns the chain of spans containing a byte, from the outermost to
+    /// the innermost.
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The index of the byte
+    ///
+    /// # Returns
+    ///
+    /// The spans containing the byte, empty if no read consumed it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[1, 2, 3]);
+    /// reader.start_trace();
+    /// let _: (u8, [u8; 2]) = reader.read_field("pair").unwrap();
+    /// let map = reader.take_trace().unwrap();
+    ///
+    /// let path: Vec<&str> = map.path(2).iter().map(|span| span.type_name()).collect();
+    /// assert_eq!(path, ["(u8, [u8; 2])", "[u8; 2]", "u8"]);
+    /// assert_eq!(map.label(2).unwrap(), "pair: (u8, [u8; 2])");
+    /// assert!(map.path(3).is_empty());
+    /// ```
+    pub fn path(&self, offset: usize) -> Vec<&Span> {
+        let mut path: Vec<&Span> = Vec::new();
+        let mut spans: &[Span] = &self.spans;
+
+        // The last matching span wins, as it is the one that was not overwritten
+        while let Some(span) = spans
+            .iter()
+            .rev()
+            .find(|span| span.range().contains(&offset))
+        {
+            path.push(span);
+            spans = &span.children;
+        }
+        path
+    }
+
+    /// Describes the span containing a byte.
+    ///
+    /// The [path()](ParseMap::path) to the byte is followed down to the first
+    /// span without field names in its nested reads. The label is made of the
+    /// field names on the way joined with dots, followed by the name of the
+    /// type of that span.
+    ///
+    /// # Parameters
+    ///
+    /// - `offset`: The index of the byte
+    ///
+    /// # Returns
+    ///
+    /// The label of the span, or `None` if no read consumed the byte.
+    pub fn label(&self, offset: usize) -> Option<String> {
+        self.atom(offset).map(|(_, label)| label)
+    }
+
+    /// Returns the range and the label of the span described at a byte.
+    fn atom(&self, offset: usize) -> Option<(Range<usize>, String)> {
+        let mut path: Vec<&Span> = self.path(offset);
+        if let Some(atom) = path.iter().position(|span| !span.has_named_children()) {
+            path.truncate(atom + 1);
+        }
+
+        path.last().map(|span| (span.range(), describe(&path)))
+    }
+
+    /// Returns the distinct labels of the bytes in a range, in byte order.
+    pub(crate) fn labels(&self, range: Range<usize>) -> Vec<String> {
+        let mut labels: Vec<String> = Vec::new();
+        let mut offset: usize = range.start;
+
+        while offset < range.end {
+            match self.atom(offset) {
+                Some((span, label)) => {
+                    if !labels.contains(&label) {
+                        labels.push(label);
+                    }
+                    offset = span.end.max(offset + 1);
+                }
+                None => offset += 1,
+            }
+        }
+        labels
+    }
+
+    /// Calls `f` with the path to every span described as a whole, in the
+    /// order the reads were made.
+    fn atoms<'m>(spans: &'m [Span], path: &mut Vec<&'m Span>, f: &mut impl FnMut(&[&'m Span])) {
+        for span in spans {
+            path.push(span);
+            match span.has_named_children() {
+                true => Self::atoms(&span.children, path, f),
+                false => f(path),
+            }
+            path.pop();
+        }
+    }
+
+    /// Renders a hex dump with the spans described after the rows they
+    /// start in.
+    ///
+    /// The layout, the range and the highlighted bytes are taken from the
+    /// dump. The labels are separated with commas and follow the rules of
+    /// [label()](ParseMap::label).
+    ///
+    /// # Parameters
+    ///
+    /// - `dump`: The hex dump of the traced data
+    ///
+    /// # Returns
+    ///
+    /// The annotated dump, without a trailing newline.
+    pub fn annotate(&self, dump: &HexDump<'_>) -> String {
+        let mut labels: Vec<(usize, String)> = Vec::new();
+        Self::atoms(&self.spans, &mut Vec::new(), &mut |path| {
+            if let Some(span) = path.last() {
+                labels.push((span.start, describe(path)));
+            }
+        });
+        labels.sort_by_key(|(start, _)| *start);
+
+        let mut text: String = String::new();
+        let _ = dump.write_annotated(&mut text, |text, row| {
+            let mut first: bool = true;
+            for (_, label) in labels.iter().filter(|(start, _)| row.contains(start)) {
+                text.push_str(if first { "  " } else { ", " });
+                text.push_str(label);
+                first = false;
+            }
+            Ok(())
+        });
+        text
+    }
+
+    /// Exports the spans as a JSON array.
+    ///
+    /// Every span is an object with the `start`, `end`, `type`, `field`,
+    /// `error` and `children` keys. `field` is `null` for reads without a
+    /// field name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[0x2A]);
+    /// reader.start_trace();
+    /// let _: u8 = reader.read_field("answer").unwrap();
+    /// let _ = reader.read::<u8>();
+    ///
+    /// assert_eq!(
+    ///     reader.take_trace().unwrap().to_json(),
+    ///     "[{\"start\":0,\"end\":1,\"type\":\"u8\",\"field\":\"answer\",\"error\":false,\"children\":[]},\
+    ///       {\"start\":1,\"end\":1,\"type\":\"u8\",\"field\":null,\"error\":true,\"children\":[]}]"
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let mut json: String = String::from("[");
+        for (index, span) in self.spans.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            span.write_json(&mut json);
+        }
+        json.push(']');
+        json
+    }
+}
+
+impl Display for ParseMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (index, span) in self.spans.iter().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            span.write_tree(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// The recording state of a reader in tracing mode.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Tracer {
+    /// Spans of the reads in progress, the innermost last
+    open: Vec<Span>,
+    /// Completed top-level spans
+    spans: Vec<Span>,
+}
+
+impl Tracer {
+    /// Opens a span at the position before a read.
+    pub(crate) fn open(
+        &mut self,
+        start: usize,
+        type_name: &'static str,
+        field: Option<&'static str>,
+    ) {
+        self.open.push(Span {
+            start,
+            end: start,
+            type_name,
+            field,
+            error: false,
+            children: Vec::new(),
+        });
+    }
+
+    /// Closes the innermost span at the position after a read.
+    pub(crate) fn close(&mut self, end: usize, error: bool) {
+        if let Some(mut span) = self.open.pop() {
+            span.end = end.max(span.start);
+            span.error = error;
+            self.level().push(span);
+        }
+    }
+
+    fn level(&mut self) -> &mut Vec<Span> {
+        match self.open.last_mut() {
+            Some(parent) => &mut parent.children,
+            None => &mut self.spans,
+        }
+    }
+
+    /// Returns the nesting depth and the number of spans at that depth.
+    pub(crate) fn mark(&mut self) -> (usize, usize) {
+        (self.open.len(), self.level().len())
+    }
+
+    /// Discards the spans recorded since `mark` at the same depth.
+    pub(crate) fn rollback(&mut self, (depth, count): (usize, usize)) {
+        if self.open.len() == depth {
+            self.level().truncate(count);
+        }
+    }
+
+    /// Closes the reads in progress at `end` and returns the parse map.
+    pub(crate) fn finish(mut self, end: usize) -> ParseMap {
+        while !self.open.is_empty() {
+            self.close(end, false);
+        }
+        ParseMap { spans: self.spans }
+    }
+}
@@ -0,0 +1,247 @@
+//! Serde integration for ByteCraft readers and writers.
+//!
+//! The `serde` module is available with the `serde` feature. It provides a
+//! [`Serializer`] writing to a [`ByteWriter`] and a zero-copy [`Deserializer`]
+//! reading from a [`ByteReader`], so types deriving `serde::Serialize` and
+//! `serde::Deserialize` can be written and read without separate
+//! [`Writable`](crate::writable::Writable) and [`Readable`](crate::readable::Readable)
+//! implementations.
+//!
+//! # Format
+//!
+//! The format is not self-describing and follows the conventions of the
+//! built-in `Readable`/`Writable` implementations:
+//!
+//! - **Numbers**: Fixed size, using the endianness of the reader or writer
+//! - **bool**: One byte, `0` or `1`
+//! - **char**: UTF-8 encoded, 1 to 4 bytes
+//! - **Strings and bytes**: `u32` length prefix followed by the data
+//! - **Sequences and maps**: `u32` element count followed by the elements
+//! - **Option**: One byte tag (`0` for `None`, `1` for `Some`), then the value
+//! - **Structs and tuples**: Fields in order, without prefix
+//! - **Enums**: `u32` variant index, then the variant data
+//! - **Unit types**: Nothing
+//!
+//! Because the format is not self-describing, `deserialize_any` and
+//! `deserialize_ignored_any` are not supported, so untagged and internally
+//! tagged enums cannot be read.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::serde::{from_slice, to_vec};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Record<'a> {
+//!     id: u16,
+//!     name: &'a str,
+//!     tags: Vec<u8>,
+//! }
+//!
+//! let record = Record { id: 1, name: "abc", tags: vec![7] };
+//!
+//! let bytes: Vec<u8> = to_vec(&record).unwrap();
+//! let decoded: Record = from_slice(&bytes).unwrap();
+//!
+//! assert_eq!(decoded, record);
+//! ```
+
+mod de;
+mod ser;
+
+pub use de::Deserializer;
+pub use ser::Serializer;
+
+use core::fmt::Display;
+
+use ::serde::{Deserialize, Serialize};
+
+use crate::common::Endian;
+use crate::error::{Error, Result};
+use crate::reader::ByteReader;
+use crate::writer::ByteWriter;
+use ser::SizeCounter;
+
+impl ::serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string().into())
+    }
+}
+
+impl ::serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string().into())
+    }
+}
+
+/// Serializes a value into the writer and advances its position.
+///
+/// Multi-byte values are written with the endianness of the writer.
+///
+/// # Parameters
+///
+/// - `writer`: The writer to serialize into
+/// - `value`: The value to serialize
+///
+/// # Returns
+///
+/// - `Ok(())` if the value was successfully written
+/// - [`Error::InsufficientData`] if the buffer is too small
+/// - [`Error::NotValid`] if a length does not fit in `u32` or is unknown
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{common::Endian, serde::to_writer, writer::ByteWriter};
+///
+/// let mut buffer = [0u8; 8];
+/// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+///
+/// to_writer(&mut writer, &(1u16, "ab")).unwrap();
+/// assert_eq!(buffer, [0x00, 0x01, 0x00, 0x00, 0x00, 0x02, b'a', b'b']);
+/// ```
+pub fn to_writer<T, S>(writer: &mut ByteWriter<T>, value: &S) -> Result<()>
+where
+    T: AsRef<[u8]> + AsMut<[u8]>,
+    S: Serialize + ?Sized,
+{
+    value.serialize(&mut Serializer::new(writer))
+}
+
+/// Serializes a value into a new vector with [Endian::Native] byte order.
+///
+/// # Parameters
+///
+/// - `value`: The value to serialize
+///
+/// # Returns
+///
+/// - `Ok(bytes)` containing the serialized value
+/// - An error if the value cannot be serialized
+pub fn to_vec<S: Serialize + ?Sized>(value: &S) -> Result<Vec<u8>> {
+    to_vec_with_endian(value, Endian::Native)
+}
+
+/// Serializes a value into a new vector with the specified byte order.
+///
+/// # Parameters
+///
+/// - `value`: The value to serialize
+/// - `endian`: The byte order to use for multi-byte values
+///
+/// # Returns
+///
+/// - `Ok(bytes)` containing the serialized value
+/// - An error if the value cannot be serialized
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{common::Endian, serde::to_vec_with_endian};
+///
+/// let bytes: Vec<u8> = to_vec_with_endian(&[1u16, 2u16], Endian::Little).unwrap();
+/// assert_eq!(bytes, [0x01, 0x00, 0x02, 0x00]);
+/// ```
+pub fn to_vec_with_endian<S: Serialize + ?Sized>(value: &S, endian: Endian) -> Result<Vec<u8>> {
+    // ByteWriter does not grow, so the size is computed before writing
+    let mut counter: SizeCounter = SizeCounter::default();
+    value.serialize(&mut counter)?;
+
+    let mut writer: ByteWriter<Vec<u8>> =
+        ByteWriter::with_endian(vec![0u8; counter.size()], endian);
+    to_writer(&mut writer, value)?;
+
+    let len: usize = writer.position();
+    let mut result: Vec<u8> = writer.into_inner();
+    result.truncate(len);
+    Ok(result)
+}
+
+/// Deserializes a value from the reader and advances its position.
+///
+/// Multi-byte values are read with the endianness of the reader. Strings and
+/// byte slices can borrow the underlying data. If deserialization fails, the
+/// reader position is not changed.
+///
+/// # Parameters
+///
+/// - `reader`: The reader to deserialize from
+///
+/// # Returns
+///
+/// - `Ok(value)` containing the deserialized value
+/// - An error if the data is invalid or insufficient
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{reader::ByteReader, serde::from_reader};
+///
+/// let data = [0x01, 0x02, 0x00, 0x00, 0x00, b'h', b'i'];
+/// let mut reader = ByteReader::new(&data);
+///
+/// let (flag, text): (bool, &str) = from_reader(&mut reader).unwrap();
+/// assert_eq!((flag, text), (true, "hi"));
+/// assert!(reader.is_eof());
+/// ```
+pub fn from_reader<'a, D: Deserialize<'a>>(reader: &mut ByteReader<'a>) -> Result<D> {
+    let mut deserializer: Deserializer<'a> = Deserializer::new(reader.clone());
+    let value: D = D::deserialize(&mut deserializer)?;
+    // Only the position is copied back, the clone has no tracer
+    reader.set_position(deserializer.into_reader().position())?;
+    Ok(value)
+}
+
+/// Deserializes a value from a slice with [Endian::Native] byte order.
+///
+/// The whole slice must be consumed.
+///
+/// # Parameters
+///
+/// - `data`: The serialized data
+///
+/// # Returns
+///
+/// - `Ok(value)` containing the deserialized value
+/// - [`Error::NotValid`] if there are bytes left after the value
+/// - An error if the data is invalid or insufficient
+pub fn from_slice<'a, D: Deserialize<'a>>(data: &'a [u8]) -> Result<D> {
+    from_slice_with_endian(data, Endian::Native)
+}
+
+/// Deserializes a value from a slice with the specified byte order.
+///
+/// The whole slice must be consumed.
+///
+/// # Parameters
+///
+/// - `data`: The serialized data
+/// - `endian`: The byte order to use for multi-byte values
+///
+/// # Returns
+///
+/// - `Ok(value)` containing the deserialized value
+/// - [`Error::NotValid`] if there are bytes left after the value
+/// - An error if the data is invalid or insufficient
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{common::Endian, error::Error, serde::from_slice_with_endian};
+///
+/// let value: u16 = from_slice_with_endian(&[0x01, 0x02], Endian::Big).unwrap();
+/// assert_eq!(value, 0x0102);
+///
+/// let result = from_slice_with_endian::<u8>(&[0x01, 0x02], Endian::Big);
+/// assert!(matches!(result, Err(Error::NotValid)));
+/// ```
+pub fn from_slice_with_endian<'a, D: Deserialize<'a>>(data: &'a [u8], endian: Endian) -> Result<D> {
+    let mut reader: ByteReader<'a> = ByteReader::with_endian(data, endian);
+    let value: D = from_reader(&mut reader)?;
+
+    match reader.is_eof() {
+        true => Ok(value),
+        false => Err(Error::NotValid),
+    }
+}
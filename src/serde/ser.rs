@@ -0,0 +1,632 @@
+//! Serde serializer writing to a `ByteWriter`.
+
+use ::serde::ser::{self, Serialize};
+
+use crate::error::{Error, Result};
+use crate::writer::ByteWriter;
+
+/// A serde serializer writing values to a [`ByteWriter`].
+///
+/// See the [module-level documentation](super) for the description of the format.
+///
+/// # Type Parameters
+///
+/// - `'w`: The lifetime of the borrowed `ByteWriter` instance
+/// - `T`: The buffer type of the writer
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{serde::Serializer, writer::ByteWriter};
+/// use serde::Serialize;
+///
+/// let mut buffer = [0u8; 3];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+///
+/// Some('a').serialize(&mut Serializer::new(&mut writer)).unwrap();
+/// true.serialize(&mut Serializer::new(&mut writer)).unwrap();
+/// assert_eq!(buffer, [1, b'a', 1]);
+/// ```
+pub struct Serializer<'w, T: AsRef<[u8]> + AsMut<[u8]>> {
+    writer: &'w mut ByteWriter<T>,
+}
+
+impl<'w, T: AsRef<[u8]> + AsMut<[u8]>> Serializer<'w, T> {
+    /// Creates a new serializer writing to `writer`.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer`: The writer to serialize into
+    pub fn new(writer: &'w mut ByteWriter<T>) -> Self {
+        Self { writer }
+    }
+
+    /// Writes a length as `u32` prefix.
+    fn write_len(&mut self, len: usize) -> Result<()> {
+        if len > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        self.writer.write(&(len as u32))
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::Serializer for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.writer.write(&v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.write_len(v.len())?;
+        self.writer.write_bytes(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.write_len(v.len())?;
+        self.writer.write_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.writer.write(&0u8)
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<()> {
+        self.writer.write(&1u8)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.writer.write(&index)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        index: u32,
+        _variant: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.writer.write(&index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        self.write_len(len.ok_or(Error::NotValid)?)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.writer.write(&index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self> {
+        self.write_len(len.ok_or(Error::NotValid)?)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.writer.write(&index)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeSeq for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeTuple for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeTupleStruct for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeTupleVariant for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeMap for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<()> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeStruct for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]>> ser::SerializeStructVariant for &mut Serializer<'_, T> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A serde serializer computing the size of the serialized value without writing it.
+///
+/// Used by [`to_vec`](super::to_vec) to allocate the output buffer once.
+#[derive(Default)]
+pub(crate) struct SizeCounter {
+    size: usize,
+}
+
+impl SizeCounter {
+    /// Returns the number of bytes counted so far.
+    pub(crate) fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Counts `size` bytes.
+    fn add(&mut self, size: usize) -> Result<()> {
+        self.size = self.size.checked_add(size).ok_or(Error::NotValid)?;
+        Ok(())
+    }
+
+    /// Counts a length as `u32` prefix.
+    fn add_len(&mut self, len: usize) -> Result<()> {
+        if len > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        self.add(size_of::<u32>())
+    }
+}
+
+impl ser::Serializer for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, _v: bool) -> Result<()> {
+        self.add(1)
+    }
+
+    fn serialize_i8(self, _v: i8) -> Result<()> {
+        self.add(1)
+    }
+
+    fn serialize_i16(self, _v: i16) -> Result<()> {
+        self.add(2)
+    }
+
+    fn serialize_i32(self, _v: i32) -> Result<()> {
+        self.add(4)
+    }
+
+    fn serialize_i64(self, _v: i64) -> Result<()> {
+        self.add(8)
+    }
+
+    fn serialize_i128(self, _v: i128) -> Result<()> {
+        self.add(16)
+    }
+
+    fn serialize_u8(self, _v: u8) -> Result<()> {
+        self.add(1)
+    }
+
+    fn serialize_u16(self, _v: u16) -> Result<()> {
+        self.add(2)
+    }
+
+    fn serialize_u32(self, _v: u32) -> Result<()> {
+        self.add(4)
+    }
+
+    fn serialize_u64(self, _v: u64) -> Result<()> {
+        self.add(8)
+    }
+
+    fn serialize_u128(self, _v: u128) -> Result<()> {
+        self.add(16)
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<()> {
+        self.add(4)
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<()> {
+        self.add(8)
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.add(v.len_utf8())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.add_len(v.len())?;
+        self.add(v.len())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.add_len(v.len())?;
+        self.add(v.len())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.add(1)
+    }
+
+    fn serialize_some<V: Serialize + ?Sized>(self, value: &V) -> Result<()> {
+        self.add(1)?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+    ) -> Result<()> {
+        self.add(4)
+    }
+
+    fn serialize_newtype_struct<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<V: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        self.add(4)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self> {
+        self.add_len(len.ok_or(Error::NotValid)?)?;
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.add(4)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self> {
+        self.add_len(len.ok_or(Error::NotValid)?)?;
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self> {
+        self.add(4)?;
+        Ok(self)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+}
+
+impl ser::SerializeSeq for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<()> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for &mut SizeCounter {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        _key: &'static str,
+        value: &V,
+    ) -> Result<()> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,327 @@
+//! Tagged unions with a discriminant read before the variant data.
+//!
+//! The `tagged` module provides the [`tagged_enum!`](crate::tagged_enum)
+//! macro, which declares an enum together with its `Readable`, `Peekable`
+//! and `Writable` implementations. Every variant is mapped to a tag value;
+//! the tag is read first, then the variant payload.
+//!
+//! # Tag Types
+//!
+//! The tag can be any type implementing `Readable`, `Writable`, `PartialEq`,
+//! `Clone` and `Debug`, for example:
+//!
+//! - `u8`, `u16` or `u32`, using the endianness of the reader or writer
+//! - [`VarU32`](crate::types::VarU32) or [`VarU64`](crate::types::VarU64) for varint tags
+//! - `&'a str` for length-prefixed string tags
+//!
+//! # Variants
+//!
+//! A variant is either a unit variant or a variant with one payload type.
+//! Several values are combined with a tuple payload, like `Point((u16, u16))`.
+//!
+//! # Unknown Tags
+//!
+//! By default, a tag without variant results in an [`Error::Custom`]
+//! holding an [`UnknownTag`] error with the enum name and the tag value.
+//! For forward compatibility, an enum with a lifetime can declare a fallback
+//! variant with `_ => Unknown`. It keeps the tag and the payload bytes, and
+//! writes them back unchanged.
+//!
+//! The length of an unknown payload cannot be derived from the tag, so
+//! `read()` takes all remaining bytes of the stream as the payload. It only
+//! works for the last item of a stream, like a framed record. When records
+//! follow each other, for example in a `Vec` or a struct, the payload length
+//! must be passed with `read_with(len)`, which reads the tag and exactly
+//! `len` payload bytes. A known variant whose payload does not consume `len`
+//! bytes results in [`Error::NotValid`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{reader::ByteReader, tagged_enum, writer::ByteWriter};
+//!
+//! tagged_enum! {
+//!     #[derive(Debug, PartialEq)]
+//!     pub enum Message<'a>: u8 {
+//!         Ping = 0x01,
+//!         Move((i16, i16)) = 0x02,
+//!         Text(&'a str) = 0x03,
+//!         _ => Unknown,
+//!     }
+//! }
+//!
+//! let mut reader = ByteReader::new(&[0x02, 0xFF, 0xFF, 0x05, 0x00]);
+//! assert_eq!(reader.read::<Message>().unwrap(), Message::Move((-1, 5))); // Assuming little endian
+//!
+//! let mut reader = ByteReader::new(&[0x7F, 0xAA, 0xBB]);
+//! let message: Message = reader.read().unwrap();
+//! assert_eq!(message, Message::Unknown(0x7F, &[0xAA, 0xBB]));
+//! assert_eq!(message.tag(), 0x7F);
+//!
+//! let mut buffer = [0u8; 3];
+//! let mut writer = ByteWriter::new(&mut buffer[..]);
+//! writer.write(&message).unwrap();
+//! assert_eq!(buffer, [0x7F, 0xAA, 0xBB]);
+//!
+//! // Length-prefixed records
+//! let mut reader = ByteReader::new(&[0x01, 0x7F, 0xAA, 0x00, 0x01]);
+//! let len: u8 = reader.read().unwrap();
+//! assert_eq!(reader.read_with::<Message, _>(len as usize).unwrap(), Message::Unknown(0x7F, &[0xAA]));
+//! let len: u8 = reader.read().unwrap();
+//! assert_eq!(reader.read_with::<Message, _>(len as usize).unwrap(), Message::Ping);
+//! ```
+
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::error::Error;
+
+/// An error returned when a tag does not match any variant of a tagged enum.
+///
+/// The error is wrapped into [`Error::Custom`] and can be recovered with
+/// `downcast_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{error::Error, reader::ByteReader, tagged::UnknownTag, tagged_enum};
+///
+/// tagged_enum! {
+///     #[derive(Debug)]
+///     enum Command: u16 {
+///         Start = 1,
+///         Stop = 2,
+///     }
+/// }
+///
+/// let mut reader = ByteReader::new(&[0x09, 0x00]);
+/// match reader.read::<Command>() {
+///     Err(Error::Custom(err)) => {
+///         let err: &UnknownTag = err.downcast_ref().unwrap();
+///         assert_eq!(err.name(), "Command");
+///         assert_eq!(err.tag(), "9");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownTag {
+    name: &'static str,
+    tag: String,
+}
+
+impl UnknownTag {
+    /// Creates a new error for the enum `name` and the unknown `tag`.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the enum type
+    /// - `tag`: The tag value that does not match any variant
+    pub fn new(name: &'static str, tag: &dyn Debug) -> Self {
+        Self {
+            name,
+            tag: format!("{tag:?}"),
+        }
+    }
+
+    /// Returns the name of the enum type.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the `Debug` representation of the unknown tag.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+}
+
+impl Display for UnknownTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown tag {} for {}", self.tag, self.name)
+    }
+}
+
+impl std::error::Error for UnknownTag {}
+
+impl From<UnknownTag> for Error {
+    fn from(value: UnknownTag) -> Self {
+        Error::Custom(Box::new(value))
+    }
+}
+
+/// Declares an enum mapped to tag values, with `Readable`, `ReadableWith<usize>`,
+/// `Peekable` and `Writable` implementations.
+///
+/// See the [module-level documentation](crate::tagged) for the description.
+///
+/// # Syntax
+///
+/// ```text
+/// tagged_enum! {
+///     #[attributes]
+///     visibility enum Name<'lifetime>: TagType {
+///         UnitVariant = tag,
+///         PayloadVariant(PayloadType) = tag,
+///         _ => FallbackVariant,
+///     }
+/// }
+/// ```
+///
+/// The lifetime and the fallback variant are optional. The lifetime must be
+/// used by a payload or by the fallback variant, which requires it. Tag values
+/// are compared with `==` and must have the tag type when written, so
+/// integer literals, constants and string literals can be used. The macro also generates a `tag()` method
+/// returning the tag of the value.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{reader::ByteReader, tagged_enum, types::VarU32, writer::ByteWriter};
+///
+/// tagged_enum! {
+///     #[derive(Debug, PartialEq)]
+///     pub enum Field<'a>: &'a str {
+///         Id(VarU32) = "id",
+///         Name(&'a str) = "name",
+///     }
+/// }
+///
+/// let mut buffer = [0u8; 8];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&Field::Id(VarU32(300))).unwrap();
+/// assert_eq!(buffer, [2, 0, 0, 0, b'i', b'd', 0xAC, 0x02]); // Assuming little endian
+///
+/// let mut reader = ByteReader::new(&buffer);
+/// let field: Field = reader.read().unwrap();
+/// assert_eq!(field, Field::Id(VarU32(300)));
+/// assert_eq!(field.tag(), "id");
+/// ```
+#[macro_export]
+macro_rules! tagged_enum {
+    (@bind $T:ty, $p:pat) => {
+        $p
+    };
+
+    (
+        @impls [$lt:lifetime] [$($gen:lifetime)?] $Name:ident, $Tag:ty,
+        [$($V:ident $(($T:ty))? = $tag:expr),+] [$($F:ident)?]
+    ) => {
+        impl<$($gen)?> $Name<$($gen)?> {
+            /// Returns the tag of the value.
+            pub fn tag(&self) -> $Tag {
+                match self {
+                    $(Self::$V $(($crate::tagged_enum!(@bind $T, _)))? => $tag,)+
+                    $(Self::$F(tag, _) => ::core::clone::Clone::clone(tag),)?
+                }
+            }
+        }
+
+        impl<$lt> $crate::readable::Readable<$lt> for $Name<$($gen)?> {
+            /// Reads the tag and then the payload of the matching variant.
+            fn read(mut s: $crate::reader::ReadStream<$lt, '_>) -> $crate::error::Result<Self> {
+                let tag: $Tag = s.read()?;
+
+                $(
+                    if tag == $tag {
+                        return ::core::result::Result::Ok(Self::$V $((s.read::<$T>()?))?);
+                    }
+                )+
+
+                let len: usize = s.rest_len();
+                $crate::tagged_enum!(@unknown s, tag, len, $Name, [$($F)?])
+            }
+        }
+
+        impl<$lt> $crate::readable::ReadableWith<$lt, usize> for $Name<$($gen)?> {
+            /// Reads the tag and then a payload of exactly `len` bytes.
+            fn read_with(mut s: $crate::reader::ReadStream<$lt, '_>, len: usize) -> $crate::error::Result<Self> {
+                let tag: $Tag = s.read()?;
+                let rest: usize = s.rest_len();
+
+                $(
+                    if tag == $tag {
+                        let value: Self = Self::$V $((s.read::<$T>()?))?;
+                        if rest - s.rest_len() != len {
+                            return ::core::result::Result::Err($crate::error::Error::NotValid);
+                        }
+                        return ::core::result::Result::Ok(value);
+                    }
+                )+
+
+                $crate::tagged_enum!(@unknown s, tag, len, $Name, [$($F)?])
+            }
+        }
+
+        impl<$lt> $crate::peekable::Peekable<$lt> for $Name<$($gen)?> {
+            /// Peeks the tag and then the payload of the matching variant.
+            fn peek(s: $crate::reader::PeekStream<$lt, '_>) -> $crate::error::Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+
+        impl<$($gen)?> $crate::writable::Writable for $Name<$($gen)?> {
+            /// Writes the tag and then the payload of the variant.
+            fn write<__B>(mut s: $crate::writer::WriteStream<__B>, val: &Self) -> $crate::error::Result<()>
+            where
+                __B: ::core::convert::AsRef<[u8]> + ::core::convert::AsMut<[u8]>,
+            {
+                match val {
+                    $(
+                        Self::$V $(($crate::tagged_enum!(@bind $T, value)))? => {
+                            let tag: $Tag = $tag;
+                            s.write(&tag)?;
+                            $(s.write::<$T>(value)?;)?
+                            ::core::result::Result::Ok(())
+                        }
+                    )+
+                    $(
+                        Self::$F(tag, data) => {
+                            s.write::<$Tag>(tag)?;
+                            s.write_exact(data)
+                        }
+                    )?
+                }
+            }
+        }
+    };
+
+    (@unknown $s:ident, $tag:ident, $len:ident, $Name:ident, []) => {
+        ::core::result::Result::Err($crate::tagged::UnknownTag::new(::core::stringify!($Name), &$tag).into())
+    };
+
+    (@unknown $s:ident, $tag:ident, $len:ident, $Name:ident, [$F:ident]) => {
+        ::core::result::Result::Ok(Self::$F($tag, $s.read_exact($len)?))
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $Name:ident<$lt:lifetime>: $Tag:ty {
+            $($(#[$vmeta:meta])* $V:ident $(($T:ty))? = $tag:expr),+
+            $(, _ => $F:ident)? $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $Name<$lt> {
+            $($(#[$vmeta])* $V $(($T))?,)+
+            $(
+                /// A value with an unknown tag and its payload bytes.
+                $F($Tag, &$lt [u8]),
+            )?
+        }
+
+        $crate::tagged_enum!(@impls [$lt] [$lt] $Name, $Tag, [$($V $(($T))? = $tag),+] [$($F)?]);
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $Name:ident: $Tag:ty {
+            $($(#[$vmeta:meta])* $V:ident $(($T:ty))? = $tag:expr),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $Name {
+            $($(#[$vmeta])* $V $(($T))?,)+
+        }
+
+        $crate::tagged_enum!(@impls ['a] [] $Name, $Tag, [$($V $(($T))? = $tag),+] []);
+    };
+}
@@ -0,0 +1,323 @@
+//! Binary-coded decimal and ASCII digit strings.
+//!
+//! The `digits` module provides fixed-width numeric encodings used by
+//! industrial protocols, smart cards and text-based headers:
+//!
+//! - [`Bcd`] - packed binary-coded decimal, two digits per byte
+//! - [`AsciiDecimal`] - ASCII decimal digits, like `"000123"`
+//! - [`AsciiHex`] - ASCII hexadecimal digits, like `"00FF"`
+//!
+//! Each type stores the value as `u64` and has a fixed width of `N` bytes.
+//! Digits are ordered from the most significant, and the value is padded
+//! with leading zeros when written. The encoding does not depend on the
+//! endianness of the reader or writer.
+//!
+//! Invalid digits are rejected with an [`Error::Custom`] holding an
+//! [`InvalidDigit`] error with the index and the value of the digit. Values
+//! that do not fit in `u64` on read, or in `N` bytes on write, result in
+//! [`Error::NotValid`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{reader::ByteReader, types::{AsciiDecimal, AsciiHex, Bcd}};
+//!
+//! let data = [0x12, 0x34, b'0', b'4', b'2', b'f', b'F'];
+//! let mut reader = ByteReader::new(&data);
+//!
+//! assert_eq!(reader.read::<Bcd<2>>().unwrap(), Bcd(1234));
+//! assert_eq!(reader.read::<AsciiDecimal<3>>().unwrap(), AsciiDecimal(42));
+//! assert_eq!(reader.read::<AsciiHex<2>>().unwrap(), AsciiHex(0xFF));
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+use crate::error::{Error, Result};
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{ByteReader, PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+/// An error returned when a digit is not valid for the encoding.
+///
+/// The error is wrapped into [`Error::Custom`] and can be recovered with
+/// `downcast_ref`.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{error::Error, reader::ByteReader, types::InvalidDigit};
+///
+/// let mut reader = ByteReader::new(&[0x12, 0x3A]);
+/// match reader.read_bcd(2) {
+///     Err(Error::Custom(err)) => {
+///         let err: &InvalidDigit = err.downcast_ref().unwrap();
+///         assert_eq!(err.index(), 3);
+///         assert_eq!(err.value(), 0x0A);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidDigit {
+    index: usize,
+    value: u8,
+}
+
+impl InvalidDigit {
+    /// Creates a new error for the digit at `index`.
+    ///
+    /// # Parameters
+    ///
+    /// - `index`: The index of the digit, starting from the most significant
+    /// - `value`: The invalid nibble of a BCD digit, or the invalid byte of an ASCII digit
+    pub fn new(index: usize, value: u8) -> Self {
+        Self { index, value }
+    }
+
+    /// Returns the index of the invalid digit, starting from the most significant.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the invalid nibble of a BCD digit, or the invalid byte of an ASCII digit.
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+impl Display for InvalidDigit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Invalid digit 0x{:02X} at index {}",
+            self.value, self.index
+        )
+    }
+}
+
+impl std::error::Error for InvalidDigit {}
+
+impl From<InvalidDigit> for Error {
+    fn from(value: InvalidDigit) -> Self {
+        Error::Custom(Box::new(value))
+    }
+}
+
+/// Appends a digit to a value, returning [`Error::NotValid`] on overflow.
+pub(crate) fn push_digit(value: u64, digit: u32, radix: u32) -> Result<u64> {
+    value
+        .checked_mul(radix as u64)
+        .and_then(|value| value.checked_add(digit as u64))
+        .ok_or(Error::NotValid)
+}
+
+impl ByteReader<'_> {
+    /// Reads a packed binary-coded decimal number of `size` bytes and advances position.
+    ///
+    /// Every byte holds two decimal digits, the high nibble first, and the
+    /// digits are ordered from the most significant. The encoding does not
+    /// depend on the endianness of the reader.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of bytes, holding `2 * size` digits
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the decoded number
+    /// - [`Error::Custom`] holding an [`InvalidDigit`] error if a nibble is greater than 9
+    /// - [`Error::NotValid`] if the number does not fit in `u64`
+    /// - [`Error::InsufficientData`] if not enough bytes are available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::reader::ByteReader;
+    ///
+    /// let mut reader = ByteReader::new(&[0x20, 0x25, 0x10, 0x18]);
+    /// assert_eq!(reader.read_bcd(2).unwrap(), 2025);
+    /// assert_eq!(reader.read_bcd(2).unwrap(), 1018);
+    /// ```
+    pub fn read_bcd(&mut self, size: usize) -> Result<u64> {
+        parse_bcd(self.read_bytes(size)?)
+    }
+}
+
+impl ReadStream<'_, '_> {
+    /// Reads a packed binary-coded decimal number of `size` bytes and advances position.
+    ///
+    /// Works like [`ByteReader::read_bcd`].
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the decoded number
+    /// - An error if the value cannot be read or a digit is invalid
+    pub fn read_bcd(&mut self, size: usize) -> Result<u64> {
+        parse_bcd(self.read_exact(size)?)
+    }
+}
+
+/// Parses packed BCD digits, the high nibble first.
+fn parse_bcd(bytes: &[u8]) -> Result<u64> {
+    let digits = bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0F]);
+
+    digits
+        .enumerate()
+        .try_fold(0, |value, (index, digit)| match digit {
+            0..=9 => push_digit(value, digit as u32, 10),
+            _ => Err(InvalidDigit::new(index, digit).into()),
+        })
+}
+
+/// Parses ASCII digits of the given radix.
+fn parse_ascii(text: &str, radix: u32) -> Result<u64> {
+    text.bytes()
+        .enumerate()
+        .try_fold(0, |value, (index, byte)| {
+            match (byte as char).to_digit(radix) {
+                Some(digit) => push_digit(value, digit, radix),
+                None => Err(InvalidDigit::new(index, byte).into()),
+            }
+        })
+}
+
+/// Writes formatted digits, returning [`Error::NotValid`] if they are longer than `size`.
+fn write_ascii<T>(mut s: WriteStream<T>, text: String, size: usize) -> Result<()>
+where
+    T: AsRef<[u8]> + AsMut<[u8]>,
+{
+    match text.len() <= size {
+        true => s.write_exact(text.as_bytes()),
+        false => Err(Error::NotValid),
+    }
+}
+
+/// A packed binary-coded decimal number of `N` bytes.
+///
+/// Every byte holds two decimal digits, the high nibble first, so `N` bytes
+/// hold `2 * N` digits. Read and written with
+/// [`ByteReader::read_bcd`](crate::reader::ByteReader::read_bcd) and
+/// [`ByteWriter::write_bcd`](crate::writer::ByteWriter::write_bcd).
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{types::Bcd, writer::ByteWriter};
+///
+/// let mut buffer = [0u8; 3];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+///
+/// writer.write(&Bcd::<3>(2025)).unwrap();
+///
+/// // 7 digits do not fit in 3 bytes
+/// assert!(writer.write(&Bcd::<3>(1_000_000)).is_err());
+/// assert_eq!(buffer, [0x00, 0x20, 0x25]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bcd<const N: usize>(pub u64);
+
+/// A number of `N` ASCII decimal digits.
+///
+/// Only the digits `0` to `9` are accepted, without sign or spaces.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{types::AsciiDecimal, writer::ByteWriter};
+///
+/// let mut buffer = [0u8; 5];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+///
+/// writer.write(&AsciiDecimal::<5>(42)).unwrap();
+/// assert_eq!(&buffer, b"00042");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiDecimal<const N: usize>(pub u64);
+
+/// A number of `N` ASCII hexadecimal digits.
+///
+/// Uppercase and lowercase digits are accepted on read, uppercase digits
+/// are written.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{reader::ByteReader, types::AsciiHex, writer::ByteWriter};
+///
+/// let mut reader = ByteReader::new(b"1aF0");
+/// assert_eq!(reader.read::<AsciiHex<4>>().unwrap(), AsciiHex(0x1AF0));
+///
+/// let mut buffer = [0u8; 4];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&AsciiHex::<4>(0xBEE)).unwrap();
+/// assert_eq!(&buffer, b"0BEE");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiHex<const N: usize>(pub u64);
+
+impl<'a, const N: usize> Readable<'a> for Bcd<N> {
+    /// Reads `N` bytes of packed BCD digits.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.read_bcd(N)?))
+    }
+}
+
+impl<'a, const N: usize> Readable<'a> for AsciiDecimal<N> {
+    /// Reads `N` ASCII decimal digits.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(parse_ascii(s.read_ascii(N)?, 10)?))
+    }
+}
+
+impl<'a, const N: usize> Readable<'a> for AsciiHex<N> {
+    /// Reads `N` ASCII hexadecimal digits.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(parse_ascii(s.read_ascii(N)?, 16)?))
+    }
+}
+
+impl<const N: usize> Writable for Bcd<N> {
+    /// Writes the value as `N` bytes of packed BCD digits.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_bcd(val.0, N)
+    }
+}
+
+impl<const N: usize> Writable for AsciiDecimal<N> {
+    /// Writes the value as `N` ASCII decimal digits with leading zeros.
+    fn write<T>(s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        write_ascii(s, format!("{:01$}", val.0, N), N)
+    }
+}
+
+impl<const N: usize> Writable for AsciiHex<N> {
+    /// Writes the value as `N` uppercase ASCII hexadecimal digits with leading zeros.
+    fn write<T>(s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        write_ascii(s, format!("{:01$X}", val.0, N), N)
+    }
+}
+
+macro_rules! impl_digits_peekable {
+    ($($Name:ident),*) => {
+        $(
+            impl<'a, const N: usize> Peekable<'a> for $Name<N> {
+                fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                    s.preview::<Self>()
+                }
+            }
+        )*
+    };
+}
+
+impl_digits_peekable!(Bcd, AsciiDecimal, AsciiHex);
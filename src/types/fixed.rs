@@ -0,0 +1,358 @@
+//! Fixed-point and normalized numbers.
+//!
+//! The `fixed` module provides [`Fixed`], a binary fixed-point number stored
+//! as an integer with `FRAC` fractional bits, and the normalized types
+//! [`UNorm8`] and [`SNorm16`], integers mapped to the ranges `0.0..=1.0` and
+//! `-1.0..=1.0`. They are read and written with the built-in implementations
+//! of the underlying integer type, so multi-byte values use the endianness of
+//! the stream.
+//!
+//! Common fixed-point formats have aliases: [`F2Dot14`] and [`F16Dot16`]
+//! from TrueType and OpenType fonts, and [`Q15`] used for audio samples.
+//!
+//! # Conversion
+//!
+//! - Conversion to `f32` and `f64` is done through `f64`, which is exact for
+//!   integers up to 32 bits
+//! - Conversion from `f32` and `f64` rounds to the nearest value, ties to even
+//! - Values out of range saturate to the smallest or largest value, NaN
+//!   becomes zero
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{common::Endian, reader::ByteReader, types::{F2Dot14, UNorm8}};
+//!
+//! let data = [0xC0, 0x00, 0x80];
+//! let mut reader = ByteReader::with_endian(&data, Endian::Big);
+//!
+//! let scale: F2Dot14 = reader.read().unwrap();
+//! let alpha: UNorm8 = reader.read().unwrap();
+//!
+//! assert_eq!(scale.to_f32(), -1.0);
+//! assert_eq!(alpha.to_f32(), 128.0 / 255.0);
+//! assert_eq!(UNorm8::from_f32(2.0), UNorm8::MAX);
+//! ```
+
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::error::Result;
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+/// An integer type that can store the bits of a [`Fixed`] number.
+///
+/// Implemented for `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32` and `u64`.
+pub trait FixedInt: Copy {
+    /// Converts the integer to `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Converts an `f64` to the integer, saturating out of range values
+    /// and converting NaN to zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert, already rounded to an integer
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_fixed_int {
+    ($($Type:ty),*) => {
+        $(
+            impl FixedInt for $Type {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $Type
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// A binary fixed-point number with `FRAC` fractional bits.
+///
+/// The value is `bits / 2^FRAC`, where `bits` is the stored integer of type
+/// `I`. The number is read and written as `I`, and comparisons are done on
+/// the stored integer. See the [module-level documentation](self) for the
+/// conversion rules.
+///
+/// # Type Parameters
+///
+/// - `I`: The integer type storing the bits, see [`FixedInt`]
+/// - `FRAC`: The number of fractional bits
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::types::{F16Dot16, Fixed, Q15};
+///
+/// let value: F16Dot16 = F16Dot16::from_f64(1.5);
+/// assert_eq!(value.to_bits(), 0x0001_8000);
+///
+/// // Saturation
+/// assert_eq!(Q15::from_f32(1.0).to_bits(), i16::MAX);
+/// assert_eq!(Q15::from_f32(-1.0).to_bits(), i16::MIN);
+///
+/// // Any integer type and number of fractional bits
+/// let value: Fixed<u8, 4> = Fixed::from_f32(2.3);
+/// assert_eq!(value.to_bits(), 0x25);
+/// assert_eq!(value.to_f32(), 2.3125);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+/// Signed 2.14 fixed-point number used by TrueType and OpenType fonts.
+pub type F2Dot14 = Fixed<i16, 14>;
+
+/// Signed 16.16 fixed-point number, the `Fixed` type of TrueType and OpenType fonts.
+pub type F16Dot16 = Fixed<i32, 16>;
+
+/// Signed Q15 fixed-point number in the range `-1.0..1.0`, used for audio samples.
+pub type Q15 = Fixed<i16, 15>;
+
+impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+    /// Creates a value from its binary representation.
+    ///
+    /// # Parameters
+    ///
+    /// - `bits`: The stored integer
+    pub const fn from_bits(bits: I) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the binary representation of the value.
+    pub fn to_bits(self) -> I {
+        self.0
+    }
+
+    /// Converts an `f64` to the nearest fixed-point value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert, saturated to the range of the type
+    pub fn from_f64(value: f64) -> Self {
+        Self(I::from_f64((value * Self::scale()).round_ties_even()))
+    }
+
+    /// Converts an `f32` to the nearest fixed-point value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert, saturated to the range of the type
+    pub fn from_f32(value: f32) -> Self {
+        Self::from_f64(value as f64)
+    }
+
+    /// Converts the value to `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64() / Self::scale()
+    }
+
+    /// Converts the value to `f32`.
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// Returns `2^FRAC`.
+    fn scale() -> f64 {
+        2f64.powi(FRAC as i32)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> From<Fixed<I, FRAC>> for f32 {
+    fn from(value: Fixed<I, FRAC>) -> Self {
+        value.to_f32()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> From<Fixed<I, FRAC>> for f64 {
+    fn from(value: Fixed<I, FRAC>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Debug for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Display for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<'a, I: Readable<'a>, const FRAC: u32> Readable<'a> for Fixed<I, FRAC> {
+    /// Reads the stored integer.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.read()?))
+    }
+}
+
+impl<'a, I: Peekable<'a>, const FRAC: u32> Peekable<'a> for Fixed<I, FRAC> {
+    /// Peeks the stored integer.
+    fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.peek()?))
+    }
+}
+
+impl<I: Writable, const FRAC: u32> Writable for Fixed<I, FRAC> {
+    /// Writes the stored integer.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(&val.0)
+    }
+}
+
+macro_rules! impl_norm {
+    ($(#[$meta:meta])* $Name:ident, $Type:ty, $Min:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name($Type);
+
+        impl $Name {
+            /// The smallest value.
+            pub const MIN: Self = Self(<$Type>::MIN);
+
+            /// The largest value, `1.0`.
+            pub const MAX: Self = Self(<$Type>::MAX);
+
+            /// Creates a value from its binary representation.
+            ///
+            /// # Parameters
+            ///
+            /// - `bits`: The stored integer
+            pub const fn from_bits(bits: $Type) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the binary representation of the value.
+            pub const fn to_bits(self) -> $Type {
+                self.0
+            }
+
+            /// Converts an `f32` to the nearest normalized value.
+            ///
+            /// # Parameters
+            ///
+            /// - `value`: The value to convert, saturated to the range of the type
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            /// Converts an `f64` to the nearest normalized value.
+            ///
+            /// # Parameters
+            ///
+            /// - `value`: The value to convert, saturated to the range of the type
+            pub fn from_f64(value: f64) -> Self {
+                let value: f64 = value.clamp($Min, 1.0);
+                Self((value * <$Type>::MAX as f64).round_ties_even() as $Type)
+            }
+
+            /// Converts the value to `f32`.
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            /// Converts the value to `f64`.
+            pub fn to_f64(self) -> f64 {
+                (self.0 as f64 / <$Type>::MAX as f64).max($Min)
+            }
+        }
+
+        impl From<$Name> for f32 {
+            fn from(value: $Name) -> Self {
+                value.to_f32()
+            }
+        }
+
+        impl From<$Name> for f64 {
+            fn from(value: $Name) -> Self {
+                value.to_f64()
+            }
+        }
+
+        impl Debug for $Name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl Display for $Name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.to_f64(), f)
+            }
+        }
+
+        impl<'a> Readable<'a> for $Name {
+            /// Reads the stored integer.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.read()?))
+            }
+        }
+
+        impl<'a> Peekable<'a> for $Name {
+            /// Peeks the stored integer.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.peek()?))
+            }
+        }
+
+        impl Writable for $Name {
+            /// Writes the stored integer.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.write(&val.0)
+            }
+        }
+    };
+}
+
+impl_norm!(
+    /// An unsigned normalized 8-bit number in the range `0.0..=1.0`.
+    ///
+    /// The value is `bits / 255`, as used for color channels and texture data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::types::UNorm8;
+    ///
+    /// assert_eq!(UNorm8::from_f32(0.5).to_bits(), 128);
+    /// assert_eq!(UNorm8::from_f32(-0.1), UNorm8::MIN);
+    /// assert_eq!(UNorm8::from_bits(255).to_f32(), 1.0);
+    /// ```
+    UNorm8, u8, 0.0
+);
+
+impl_norm!(
+    /// A signed normalized 16-bit number in the range `-1.0..=1.0`.
+    ///
+    /// The value is `bits / 32767`. Both `-32768` and `-32767` represent
+    /// `-1.0`, so every value has an exact negation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::types::SNorm16;
+    ///
+    /// assert_eq!(SNorm16::from_f32(-1.0).to_bits(), -32767);
+    /// assert_eq!(SNorm16::MIN.to_f32(), -1.0);
+    /// assert_eq!(SNorm16::from_f32(f32::NAN).to_bits(), 0);
+    /// ```
+    SNorm16, i16, -1.0
+);
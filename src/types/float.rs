@@ -0,0 +1,330 @@
+//! Half-precision and bfloat16 floating point numbers.
+//!
+//! The `float` module provides [`F16`], the IEEE 754 binary16 format, and
+//! [`BF16`], the bfloat16 format with the exponent range of `f32`. Both are
+//! stored as their 16-bit representation and are read and written as `u16`
+//! with the endianness of the stream. Arithmetic is done after conversion
+//! to `f32`.
+//!
+//! # Conversion
+//!
+//! - Conversion to `f32` and `f64` is exact
+//! - Conversion from `f32` rounds to the nearest value, ties to even
+//! - Values too large for the format become infinity, values too small
+//!   become subnormal numbers or zero
+//! - NaN stays NaN, keeping the sign and the high bits of the payload
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{common::Endian, reader::ByteReader, types::{BF16, F16}};
+//!
+//! let data = [0x3C, 0x00, 0x3F, 0x80];
+//! let mut reader = ByteReader::with_endian(&data, Endian::Big);
+//!
+//! let half: F16 = reader.read().unwrap();
+//! let brain: BF16 = reader.read().unwrap();
+//!
+//! assert_eq!(half.to_f32(), 1.0);
+//! assert_eq!(f32::from(brain), 1.0);
+//! assert_eq!(F16::from_f32(65520.0), F16::INFINITY);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+
+use crate::error::Result;
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+/// An IEEE 754 half-precision (binary16) floating point number.
+///
+/// 1 sign bit, 5 exponent bits and 10 mantissa bits. The largest finite
+/// value is `65504`. See the [module-level documentation](self) for the
+/// conversion rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::types::F16;
+///
+/// let value: F16 = F16::from_f32(0.1);
+/// assert_eq!(value.to_bits(), 0x2E66);
+/// assert_eq!(value.to_f32(), 0.099975586);
+///
+/// // Subnormal numbers
+/// assert_eq!(F16::from_bits(0x0001).to_f32(), 2f32.powi(-24));
+/// assert_eq!(F16::from_f32(2f32.powi(-25)).to_bits(), 0x0000); // Ties to even
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/// A bfloat16 floating point number.
+///
+/// 1 sign bit, 8 exponent bits and 7 mantissa bits: the upper half of an
+/// `f32`. It keeps the range of `f32` with reduced precision. See the
+/// [module-level documentation](self) for the conversion rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::types::BF16;
+///
+/// let value: BF16 = BF16::from_f32(3.14159);
+/// assert_eq!(value.to_bits(), 0x4049);
+/// assert_eq!(value.to_f32(), 3.140625);
+/// assert!(BF16::from_f32(f32::NAN).is_nan());
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct BF16(u16);
+
+impl F16 {
+    /// Positive zero.
+    pub const ZERO: F16 = F16(0x0000);
+    /// One.
+    pub const ONE: F16 = F16(0x3C00);
+    /// Largest finite value, `65504`.
+    pub const MAX: F16 = F16(0x7BFF);
+    /// Smallest finite value, `-65504`.
+    pub const MIN: F16 = F16(0xFBFF);
+    /// Smallest positive normal value, `2^-14`.
+    pub const MIN_POSITIVE: F16 = F16(0x0400);
+    /// Positive infinity.
+    pub const INFINITY: F16 = F16(0x7C00);
+    /// Negative infinity.
+    pub const NEG_INFINITY: F16 = F16(0xFC00);
+    /// Not a number.
+    pub const NAN: F16 = F16(0x7E00);
+
+    /// Converts an `f32` to the nearest `F16` value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert
+    pub fn from_f32(value: f32) -> Self {
+        let x: u32 = value.to_bits();
+        let sign: u16 = ((x >> 16) & 0x8000) as u16;
+        let exp: i32 = ((x >> 23) & 0xFF) as i32;
+        let man: u32 = x & 0x007F_FFFF;
+
+        // Infinity and NaN, NaN keeps the high bits of the payload and becomes quiet
+        if exp == 0xFF {
+            return match man {
+                0 => Self(sign | 0x7C00),
+                _ => Self(sign | 0x7E00 | (man >> 13) as u16),
+            };
+        }
+
+        let exp: i32 = exp - 127 + 15;
+
+        // Overflow
+        if exp >= 0x1F {
+            return Self(sign | 0x7C00);
+        }
+
+        // Subnormal numbers and underflow
+        if exp <= 0 {
+            if exp < -10 {
+                return Self(sign);
+            }
+
+            let man: u32 = man | 0x0080_0000;
+            let shift: u32 = (14 - exp) as u32;
+
+            return Self(sign | round_shift(man, shift) as u16);
+        }
+
+        // Normal numbers, a carry out of the mantissa increments the exponent
+        let bits: u32 = ((exp as u32) << 10) | (man >> 13);
+        let bits: u32 = bits + round_bit(bits, man & 0x1FFF, 0x1000);
+
+        Self(sign | bits as u16)
+    }
+
+    /// Converts the value to `f32` without loss of precision.
+    pub fn to_f32(self) -> f32 {
+        let sign: u32 = ((self.0 & 0x8000) as u32) << 16;
+        let exp: u32 = ((self.0 >> 10) & 0x1F) as u32;
+        let man: u32 = (self.0 & 0x03FF) as u32;
+
+        let bits: u32 = match (exp, man) {
+            (0, 0) => sign,
+            (0, _) => {
+                // Subnormal, normalize the mantissa
+                let shift: u32 = man.leading_zeros() - 21;
+                sign | ((113 - shift) << 23) | (((man << shift) & 0x03FF) << 13)
+            }
+            (0x1F, _) => sign | 0x7F80_0000 | (man << 13),
+            _ => sign | ((exp + 112) << 23) | (man << 13),
+        };
+
+        f32::from_bits(bits)
+    }
+
+    /// Returns `true` if the value is NaN.
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7C00 == 0x7C00 && self.0 & 0x03FF != 0
+    }
+
+    /// Returns `true` if the value is positive or negative infinity.
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7FFF == 0x7C00
+    }
+}
+
+impl BF16 {
+    /// Positive zero.
+    pub const ZERO: BF16 = BF16(0x0000);
+    /// One.
+    pub const ONE: BF16 = BF16(0x3F80);
+    /// Largest finite value.
+    pub const MAX: BF16 = BF16(0x7F7F);
+    /// Smallest finite value.
+    pub const MIN: BF16 = BF16(0xFF7F);
+    /// Smallest positive normal value, `2^-126`.
+    pub const MIN_POSITIVE: BF16 = BF16(0x0080);
+    /// Positive infinity.
+    pub const INFINITY: BF16 = BF16(0x7F80);
+    /// Negative infinity.
+    pub const NEG_INFINITY: BF16 = BF16(0xFF80);
+    /// Not a number.
+    pub const NAN: BF16 = BF16(0x7FC0);
+
+    /// Converts an `f32` to the nearest `BF16` value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to convert
+    pub fn from_f32(value: f32) -> Self {
+        let x: u32 = value.to_bits();
+
+        // NaN keeps the high bits of the payload and becomes quiet
+        if value.is_nan() {
+            return Self((x >> 16) as u16 | 0x0040);
+        }
+
+        // A carry out of the mantissa increments the exponent, up to infinity
+        let bits: u32 = x >> 16;
+        Self((bits + round_bit(bits, x & 0xFFFF, 0x8000)) as u16)
+    }
+
+    /// Converts the value to `f32` without loss of precision.
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+
+    /// Returns `true` if the value is NaN.
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7F80 == 0x7F80 && self.0 & 0x007F != 0
+    }
+
+    /// Returns `true` if the value is positive or negative infinity.
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7FFF == 0x7F80
+    }
+}
+
+/// Returns `1` if the truncated `bits` must be rounded up, ties to even.
+fn round_bit(bits: u32, rest: u32, half: u32) -> u32 {
+    (rest > half || (rest == half && bits & 1 == 1)) as u32
+}
+
+/// Shifts `value` right by `shift` bits, rounding to nearest, ties to even.
+fn round_shift(value: u32, shift: u32) -> u32 {
+    let bits: u32 = value >> shift;
+    let rest: u32 = value & ((1 << shift) - 1);
+    bits + round_bit(bits, rest, 1 << (shift - 1))
+}
+
+macro_rules! impl_float {
+    ($Type:ident) => {
+        impl $Type {
+            /// Creates a value from its binary representation.
+            ///
+            /// # Parameters
+            ///
+            /// - `bits`: The binary representation
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the binary representation of the value.
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Converts the value to `f64` without loss of precision.
+            pub fn to_f64(self) -> f64 {
+                self.to_f32() as f64
+            }
+        }
+
+        impl From<$Type> for f32 {
+            fn from(value: $Type) -> Self {
+                value.to_f32()
+            }
+        }
+
+        impl From<$Type> for f64 {
+            fn from(value: $Type) -> Self {
+                value.to_f64()
+            }
+        }
+
+        impl PartialEq for $Type {
+            /// Compares the values as floating point numbers, so NaN is not
+            /// equal to itself and `0.0` is equal to `-0.0`.
+            fn eq(&self, other: &Self) -> bool {
+                self.to_f32() == other.to_f32()
+            }
+        }
+
+        impl PartialOrd for $Type {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.to_f32().partial_cmp(&other.to_f32())
+            }
+        }
+
+        impl Debug for $Type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl Display for $Type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl<'a> Readable<'a> for $Type {
+            /// Reads the binary representation as `u16` with the stream endianness.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.read()?))
+            }
+        }
+
+        impl<'a> Peekable<'a> for $Type {
+            /// Peeks the binary representation as `u16` with the stream endianness.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.peek()?))
+            }
+        }
+
+        impl Writable for $Type {
+            /// Writes the binary representation as `u16` with the stream endianness.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.write(&val.0)
+            }
+        }
+    };
+}
+
+impl_float!(F16);
+impl_float!(BF16);
@@ -0,0 +1,198 @@
+//! Integers with 24-bit and 48-bit width.
+//!
+//! The `int` module provides [`U24`], [`I24`], [`U48`] and [`I48`], integers
+//! stored in 3 or 6 bytes, as found in audio samples, file offsets and network
+//! headers. Values are kept in the next larger Rust integer, and constructors
+//! check that they fit in the range of the type.
+//!
+//! The types are read and written with the endianness of the stream, using
+//! [`ByteReader::read_uint_n`](crate::reader::ByteReader::read_uint_n) and
+//! [`ByteReader::read_int_n`](crate::reader::ByteReader::read_int_n). Signed
+//! types are sign-extended on read. Integers of other widths can be read and
+//! written with those functions directly.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{common::Endian, reader::ByteReader, types::{I24, U48}};
+//!
+//! let data = [0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+//! let mut reader = ByteReader::with_endian(&data, Endian::Big);
+//!
+//! let sample: I24 = reader.read().unwrap();
+//! let offset: U48 = reader.read().unwrap();
+//!
+//! assert_eq!(sample.get(), -2);
+//! assert_eq!(offset.get(), 0x0100_0000);
+//! ```
+
+use core::fmt::{Display, Formatter};
+
+use crate::error::{Error, Result};
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+macro_rules! impl_int {
+    ($(#[$meta:meta])* $Name:ident, $Type:ty, $Bits:expr, $Min:expr, $Max:expr, $read:ident, $write:ident, $Wide:ty) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name($Type);
+
+        impl $Name {
+            /// The number of bits of the type.
+            pub const BITS: u32 = $Bits;
+
+            /// The smallest value of the type.
+            pub const MIN: Self = Self($Min);
+
+            /// The largest value of the type.
+            pub const MAX: Self = Self($Max);
+
+            /// Creates a value if it is in the range of the type.
+            ///
+            /// # Parameters
+            ///
+            /// - `value`: The value to store
+            ///
+            /// # Returns
+            ///
+            /// - `Some(value)` if `value` is between [`MIN`](Self::MIN) and [`MAX`](Self::MAX)
+            /// - `None` otherwise
+            pub const fn new(value: $Type) -> Option<Self> {
+                match value >= Self::MIN.0 && value <= Self::MAX.0 {
+                    true => Some(Self(value)),
+                    false => None,
+                }
+            }
+
+            /// Returns the value as the underlying integer type.
+            pub const fn get(self) -> $Type {
+                self.0
+            }
+        }
+
+        impl From<$Name> for $Type {
+            fn from(value: $Name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<$Type> for $Name {
+            type Error = Error;
+
+            /// Converts the value, returning [`Error::NotValid`] if it is out of range.
+            fn try_from(value: $Type) -> Result<Self> {
+                Self::new(value).ok_or(Error::NotValid)
+            }
+        }
+
+        impl Display for $Name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<'a> Readable<'a> for $Name {
+            /// Reads the value from `BITS / 8` bytes with the stream endianness.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.$read($Bits / 8)? as $Type))
+            }
+        }
+
+        impl<'a> Peekable<'a> for $Name {
+            /// Peeks the value from `BITS / 8` bytes with the stream endianness.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+
+        impl Writable for $Name {
+            /// Writes the value as `BITS / 8` bytes with the stream endianness.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.$write(val.0 as $Wide, $Bits / 8)
+            }
+        }
+    };
+}
+
+impl_int!(
+    /// An unsigned 24-bit integer stored in 3 bytes.
+    ///
+    /// The range is `0..=16_777_215`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, types::U24, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    ///
+    /// writer.write(&U24::new(0x123456).unwrap()).unwrap();
+    /// assert_eq!(buffer, [0x56, 0x34, 0x12]);
+    ///
+    /// assert_eq!(U24::new(0x0100_0000), None);
+    /// ```
+    U24, u32, 24, 0, (1 << 24) - 1, read_uint_n, write_uint_n, u64
+);
+
+impl_int!(
+    /// A signed 24-bit integer stored in 3 bytes in two's complement form.
+    ///
+    /// The range is `-8_388_608..=8_388_607`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, reader::ByteReader, types::I24};
+    ///
+    /// let mut reader = ByteReader::with_endian(&[0x00, 0x00, 0x80], Endian::Little);
+    ///
+    /// let value: I24 = reader.read().unwrap();
+    /// assert_eq!(value, I24::MIN);
+    /// assert_eq!(i32::from(value), -8_388_608);
+    /// ```
+    I24, i32, 24, -(1 << 23), (1 << 23) - 1, read_int_n, write_int_n, i64
+);
+
+impl_int!(
+    /// An unsigned 48-bit integer stored in 6 bytes.
+    ///
+    /// The range is `0..=281_474_976_710_655`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::types::U48;
+    ///
+    /// let value: U48 = U48::try_from(0xFFFF_FFFF_FFFFu64).unwrap();
+    /// assert_eq!(value, U48::MAX);
+    /// assert!(U48::try_from(1u64 << 48).is_err());
+    /// ```
+    U48, u64, 48, 0, (1 << 48) - 1, read_uint_n, write_uint_n, u64
+);
+
+impl_int!(
+    /// A signed 48-bit integer stored in 6 bytes in two's complement form.
+    ///
+    /// The range is `-140_737_488_355_328..=140_737_488_355_327`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, types::I48, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 6];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    ///
+    /// writer.write(&I48::new(-1).unwrap()).unwrap();
+    /// assert_eq!(buffer, [0xFF; 6]);
+    /// ```
+    I48, i64, 48, -(1 << 47), (1 << 47) - 1, read_int_n, write_int_n, i64
+);
@@ -0,0 +1,34 @@
+//! Wire types for common binary encodings.
+//!
+//! The `types` module provides newtypes for values whose binary encoding
+//! differs from the plain fixed-size representation of the underlying Rust
+//! type. Every type implements [`Readable`](crate::readable::Readable),
+//! [`Peekable`](crate::peekable::Peekable) and
+//! [`Writable`](crate::writable::Writable), so it can be used as a field of
+//! other structures.
+//!
+//! # Modules
+//!
+//! - [`digits`] - Binary-coded decimal and ASCII digit strings
+//! - [`fixed`] - Fixed-point and normalized numbers
+//! - [`float`] - Half-precision and bfloat16 floating point numbers
+//! - [`int`] - Integers with 24-bit and 48-bit width
+//! - [`net`] - MAC addresses, UUIDs and Microsoft GUIDs
+//! - [`time`] - Timestamps of file systems, archives and network protocols
+//! - [`varint`] - Variable-length integers in LEB128 encoding
+
+pub mod digits;
+pub mod fixed;
+pub mod float;
+pub mod int;
+pub mod net;
+pub mod time;
+pub mod varint;
+
+pub use digits::{AsciiDecimal, AsciiHex, Bcd, InvalidDigit};
+pub use fixed::{F16Dot16, F2Dot14, Fixed, FixedInt, SNorm16, UNorm8, Q15};
+pub use float::{BF16, F16};
+pub use int::{I24, I48, U24, U48};
+pub use net::{MacAddr, MsGuid, Uuid};
+pub use time::{DosDateTime, FileTime, HfsTime, NtpTimestamp, UnixNanos, UnixTime32, UnixTime64};
+pub use varint::{VarU32, VarU64};
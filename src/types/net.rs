@@ -0,0 +1,366 @@
+//! MAC addresses, UUIDs and Microsoft GUIDs.
+//!
+//! The `net` module provides identifier types found in packet captures,
+//! partition tables and file format headers:
+//!
+//! - [`MacAddr`] - 6-byte IEEE 802 MAC address
+//! - [`Uuid`] - 16-byte UUID in RFC 9562 byte order, big-endian fields
+//! - [`MsGuid`] - the same identifier in the Microsoft GUID layout, with the
+//!   first three fields in little-endian order
+//!
+//! The byte order of these types is fixed and does not depend on the
+//! endianness of the reader or writer. The `std::net` address types are
+//! supported directly, see the [`Readable`] implementations.
+//!
+//! All types implement `Display` and `FromStr` with the usual text forms,
+//! `01:23:45:67:89:ab` and `01234567-89ab-cdef-0123-456789abcdef`. Parsing
+//! returns [`Error::NotValid`] for malformed text.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{reader::ByteReader, types::{MsGuid, Uuid}};
+//!
+//! let data = [
+//!     0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66,
+//!     0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+//! ];
+//! let mut reader = ByteReader::new(&data);
+//!
+//! let guid: MsGuid = reader.read().unwrap();
+//! assert_eq!(guid.to_string(), "00112233-4455-6677-8899-aabbccddeeff");
+//! assert_eq!(Uuid::from(guid), "00112233-4455-6677-8899-AABBCCDDEEFF".parse().unwrap());
+//! ```
+
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+/// Parses exactly two hexadecimal digits.
+fn parse_hex_byte(text: &str) -> Result<u8> {
+    match text.len() == 2 && text.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        true => u8::from_str_radix(text, 16).map_err(|_| Error::NotValid),
+        false => Err(Error::NotValid),
+    }
+}
+
+/// A 6-byte IEEE 802 MAC address.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{reader::ByteReader, types::MacAddr};
+///
+/// let mut reader = ByteReader::new(&[0x01, 0x00, 0x5E, 0x00, 0x00, 0xFB]);
+/// let mac: MacAddr = reader.read().unwrap();
+///
+/// assert_eq!(mac.to_string(), "01:00:5e:00:00:fb");
+/// assert!(mac.is_multicast());
+/// assert_eq!("01-00-5E-00-00-FB".parse::<MacAddr>().unwrap(), mac);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// The broadcast address, `ff:ff:ff:ff:ff:ff`.
+    pub const BROADCAST: MacAddr = MacAddr([0xFF; 6]);
+
+    /// Returns the 6 octets of the address.
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Returns `true` for the broadcast address.
+    pub fn is_broadcast(&self) -> bool {
+        *self == Self::BROADCAST
+    }
+
+    /// Returns `true` if the group bit of the first octet is set.
+    pub const fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Returns `true` if the locally administered bit of the first octet is set.
+    pub const fn is_local(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(value: [u8; 6]) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for MacAddr {
+    /// Formats the address as lowercase hexadecimal octets separated by colons.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = Error;
+
+    /// Parses 6 hexadecimal octets separated by colons or hyphens.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut octets: [u8; 6] = [0u8; 6];
+        let mut parts = s.split([':', '-']);
+
+        for octet in octets.iter_mut() {
+            *octet = parse_hex_byte(parts.next().ok_or(Error::NotValid)?)?;
+        }
+
+        match parts.next() {
+            None => Ok(Self(octets)),
+            Some(_) => Err(Error::NotValid),
+        }
+    }
+}
+
+/// A 16-byte universally unique identifier.
+///
+/// The bytes are stored in RFC 9562 order, so the fields of the identifier
+/// are big-endian. See [`MsGuid`] for the Microsoft layout.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{types::Uuid, writer::ByteWriter};
+///
+/// let uuid: Uuid = "6ba7b810-9dad-11d1-80b4-00c04fd430c8".parse().unwrap();
+/// assert_eq!(uuid.version(), 1);
+///
+/// let mut buffer = [0u8; 16];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&uuid).unwrap();
+/// assert_eq!(buffer[..4], [0x6B, 0xA7, 0xB8, 0x10]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Uuid(pub [u8; 16]);
+
+impl Uuid {
+    /// The nil UUID, all bits are zero.
+    pub const NIL: Uuid = Uuid([0x00; 16]);
+
+    /// The max UUID, all bits are one.
+    pub const MAX: Uuid = Uuid([0xFF; 16]);
+
+    /// Returns the 16 bytes of the identifier.
+    pub const fn as_bytes(&self) -> &[u8; 16] {
+        &self.0
+    }
+
+    /// Returns the version number from the high nibble of byte 6.
+    pub const fn version(&self) -> u8 {
+        self.0[6] >> 4
+    }
+}
+
+impl From<[u8; 16]> for Uuid {
+    fn from(value: [u8; 16]) -> Self {
+        Self(value)
+    }
+}
+
+impl Display for Uuid {
+    /// Formats the identifier in the lowercase hyphenated form.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        for (index, byte) in self.0.iter().enumerate() {
+            if matches!(index, 4 | 6 | 8 | 10) {
+                f.write_str("-")?;
+            }
+
+            write!(f, "{byte:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = Error;
+
+    /// Parses the hyphenated form, with uppercase or lowercase digits.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut bytes: [u8; 16] = [0u8; 16];
+        let mut parts = s.split('-');
+
+        for (group, size) in [(0, 4), (4, 2), (6, 2), (8, 2), (10, 6)] {
+            let part: &str = parts.next().ok_or(Error::NotValid)?;
+
+            if part.len() != size * 2 || !part.is_ascii() {
+                return Err(Error::NotValid);
+            }
+
+            for index in 0..size {
+                bytes[group + index] = parse_hex_byte(&part[index * 2..index * 2 + 2])?;
+            }
+        }
+
+        match parts.next() {
+            None => Ok(Self(bytes)),
+            Some(_) => Err(Error::NotValid),
+        }
+    }
+}
+
+/// A GUID in the Microsoft layout.
+///
+/// Holds the same identifier as [`Uuid`], but the first three fields are
+/// stored in little-endian order, as in the Windows `GUID` structure, COM,
+/// GPT partition tables and many Microsoft file formats. The last 8 bytes
+/// are stored unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{types::MsGuid, writer::ByteWriter};
+///
+/// let guid: MsGuid = MsGuid::from_fields(0x00112233, 0x4455, 0x6677, [0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF]);
+///
+/// let mut buffer = [0u8; 16];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&guid).unwrap();
+/// assert_eq!(buffer[..8], [0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MsGuid(pub Uuid);
+
+impl MsGuid {
+    /// Creates a GUID from the fields of the Windows `GUID` structure.
+    ///
+    /// # Parameters
+    ///
+    /// - `data1`: The first 4 bytes
+    /// - `data2`: The next 2 bytes
+    /// - `data3`: The next 2 bytes
+    /// - `data4`: The last 8 bytes
+    pub fn from_fields(data1: u32, data2: u16, data3: u16, data4: [u8; 8]) -> Self {
+        let mut bytes: [u8; 16] = [0u8; 16];
+
+        bytes[..4].copy_from_slice(&data1.to_be_bytes());
+        bytes[4..6].copy_from_slice(&data2.to_be_bytes());
+        bytes[6..8].copy_from_slice(&data3.to_be_bytes());
+        bytes[8..].copy_from_slice(&data4);
+
+        Self(Uuid(bytes))
+    }
+
+    /// Converts between the RFC 9562 and the Microsoft byte order.
+    fn swap(mut bytes: [u8; 16]) -> [u8; 16] {
+        bytes[..4].reverse();
+        bytes[4..6].reverse();
+        bytes[6..8].reverse();
+        bytes
+    }
+}
+
+impl From<Uuid> for MsGuid {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<MsGuid> for Uuid {
+    fn from(value: MsGuid) -> Self {
+        value.0
+    }
+}
+
+impl Display for MsGuid {
+    /// Formats the identifier in the lowercase hyphenated form.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for MsGuid {
+    type Err = Error;
+
+    /// Parses the hyphenated form, with optional braces.
+    fn from_str(s: &str) -> Result<Self> {
+        let s: &str = match s.strip_prefix('{') {
+            Some(inner) => inner.strip_suffix('}').ok_or(Error::NotValid)?,
+            None => s,
+        };
+
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl<'a> Readable<'a> for MacAddr {
+    /// Reads the 6 octets of the address.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.read()?))
+    }
+}
+
+impl<'a> Readable<'a> for Uuid {
+    /// Reads the 16 bytes in RFC 9562 order.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.read()?))
+    }
+}
+
+impl<'a> Readable<'a> for MsGuid {
+    /// Reads the 16 bytes in the Microsoft layout.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(Uuid(Self::swap(s.read()?))))
+    }
+}
+
+impl<'a> Peekable<'a> for MacAddr {
+    fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.peek()?))
+    }
+}
+
+impl<'a> Peekable<'a> for Uuid {
+    fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(s.peek()?))
+    }
+}
+
+impl<'a> Peekable<'a> for MsGuid {
+    fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+        Ok(Self(Uuid(Self::swap(s.peek()?))))
+    }
+}
+
+impl Writable for MacAddr {
+    /// Writes the 6 octets of the address.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_exact(&val.0)
+    }
+}
+
+impl Writable for Uuid {
+    /// Writes the 16 bytes in RFC 9562 order.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_exact(&val.0)
+    }
+}
+
+impl Writable for MsGuid {
+    /// Writes the 16 bytes in the Microsoft layout.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_exact(&Self::swap(val.0 .0))
+    }
+}
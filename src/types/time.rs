@@ -0,0 +1,570 @@
+//! Timestamps of common binary formats.
+//!
+//! The `time` module provides wrappers for the raw timestamp values found in
+//! file systems, archives and network protocols:
+//!
+//! - [`UnixTime32`] and [`UnixTime64`] - seconds since 1970-01-01
+//! - [`UnixNanos`] - nanoseconds since 1970-01-01
+//! - [`FileTime`] - Windows `FILETIME`, 100-nanosecond intervals since 1601-01-01
+//! - [`DosDateTime`] - MS-DOS date and time used by FAT and ZIP, with 2 second resolution
+//! - [`NtpTimestamp`] - NTP 32.32 fixed-point seconds since 1900-01-01
+//! - [`HfsTime`] - Mac HFS seconds since 1904-01-01
+//!
+//! Every type is read and written as its underlying integers with the
+//! endianness of the stream, and converts to and from
+//! [`SystemTime`] and [`Duration`]. The conversion to `Duration` returns the
+//! time elapsed since the epoch of the format. Conversions check for
+//! overflow and return [`Error::NotValid`] if the time cannot be represented.
+//! Converting to a type with a lower resolution rounds the time down.
+//!
+//! All timestamps are treated as UTC. `Readable` and `Writable` for
+//! [`Duration`] itself are implemented with the other standard types, as
+//! `u64` seconds followed by `u32` nanoseconds.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use std::time::{Duration, SystemTime, UNIX_EPOCH};
+//!
+//! use bytecraft::{common::Endian, reader::ByteReader, types::{FileTime, UnixTime32}};
+//!
+//! let data = [0x00, 0x80, 0x3E, 0xD5, 0xDE, 0xB1, 0x9D, 0x01, 0x80, 0x51, 0x01, 0x00];
+//! let mut reader = ByteReader::with_endian(&data, Endian::Little);
+//!
+//! let created: FileTime = reader.read().unwrap();
+//! let modified: UnixTime32 = reader.read().unwrap();
+//!
+//! assert_eq!(created.to_system_time().unwrap(), UNIX_EPOCH);
+//! assert_eq!(modified.to_duration().unwrap(), Duration::from_secs(86400));
+//! ```
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::{Error, Result};
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+/// Nanoseconds per second.
+const NANOS: i128 = 1_000_000_000;
+
+/// Seconds from 1601-01-01 to the Unix epoch.
+const FILETIME_EPOCH: i128 = 11_644_473_600;
+
+/// Seconds from 1980-01-01 to the Unix epoch, negative as the Unix epoch is earlier.
+const DOS_EPOCH: i128 = -315_532_800;
+
+/// Seconds from 1900-01-01 to the Unix epoch.
+const NTP_EPOCH: i128 = 2_208_988_800;
+
+/// Seconds from 1904-01-01 to the Unix epoch.
+const HFS_EPOCH: i128 = 2_082_844_800;
+
+/// Converts an integer, returning [`Error::NotValid`] if it is out of range.
+fn convert<T: TryFrom<i128>>(value: i128) -> Result<T> {
+    T::try_from(value).map_err(|_| Error::NotValid)
+}
+
+/// Converts non-negative nanoseconds to [`Duration`].
+fn duration(nanos: i128) -> Result<Duration> {
+    let secs: u64 = convert(nanos.div_euclid(NANOS))?;
+    Ok(Duration::new(secs, nanos.rem_euclid(NANOS) as u32))
+}
+
+/// Converts nanoseconds since the Unix epoch to [`SystemTime`].
+fn system_time(nanos: i128) -> Result<SystemTime> {
+    match nanos >= 0 {
+        true => UNIX_EPOCH.checked_add(duration(nanos)?),
+        false => UNIX_EPOCH.checked_sub(duration(-nanos)?),
+    }
+    .ok_or(Error::NotValid)
+}
+
+/// Returns the nanoseconds since the Unix epoch, negative for earlier times.
+fn unix_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_nanos() as i128,
+        Err(err) => -(err.duration().as_nanos() as i128),
+    }
+}
+
+/// Returns the number of days since the Unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let yoe: i64 = year - era * 400;
+    let doy: i64 = (153 * ((month as i64 + 9) % 12) + 2) / 5 + day as i64 - 1;
+    let doe: i64 = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146_097 + doe - 719_468
+}
+
+/// Returns the year, month and day of a number of days since the Unix epoch.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days: i64 = days + 719_468;
+    let era: i64 = days.div_euclid(146_097);
+    let doe: i64 = days - era * 146_097;
+    let yoe: i64 = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: i64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: i64 = (5 * doy + 2) / 153;
+    let day: u32 = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month: u32 = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+
+    (yoe + era * 400 + (month <= 2) as i64, month, day)
+}
+
+/// Implements the conversions of a timestamp type with `unix_nanos()` and
+/// `from_unix_nanos()` functions.
+macro_rules! impl_time {
+    ($Type:ident, $Epoch:expr) => {
+        impl $Type {
+            /// Converts the timestamp to [`SystemTime`].
+            ///
+            /// # Returns
+            ///
+            /// - `Ok(time)` containing the time
+            /// - [`Error::NotValid`] if the timestamp is invalid or out of range of `SystemTime`
+            pub fn to_system_time(self) -> Result<SystemTime> {
+                system_time(self.unix_nanos()?)
+            }
+
+            /// Converts a [`SystemTime`] to the timestamp, rounding down to its resolution.
+            ///
+            /// # Parameters
+            ///
+            /// - `time`: The time to convert
+            ///
+            /// # Returns
+            ///
+            /// - `Ok(timestamp)` containing the timestamp
+            /// - [`Error::NotValid`] if the time is out of range of the timestamp
+            pub fn from_system_time(time: SystemTime) -> Result<Self> {
+                Self::from_unix_nanos(unix_nanos(time))
+            }
+
+            /// Returns the time elapsed since the epoch of the format.
+            ///
+            /// # Returns
+            ///
+            /// - `Ok(duration)` containing the elapsed time
+            /// - [`Error::NotValid`] if the timestamp is invalid or before the epoch
+            pub fn to_duration(self) -> Result<Duration> {
+                duration(self.unix_nanos()? + $Epoch * NANOS)
+            }
+
+            /// Creates the timestamp from the time elapsed since the epoch of the format.
+            ///
+            /// # Parameters
+            ///
+            /// - `duration`: The elapsed time, rounded down to the resolution of the timestamp
+            ///
+            /// # Returns
+            ///
+            /// - `Ok(timestamp)` containing the timestamp
+            /// - [`Error::NotValid`] if the duration is out of range of the timestamp
+            pub fn from_duration(duration: Duration) -> Result<Self> {
+                Self::from_unix_nanos(duration.as_nanos() as i128 - $Epoch * NANOS)
+            }
+        }
+
+        impl TryFrom<$Type> for SystemTime {
+            type Error = Error;
+
+            fn try_from(value: $Type) -> Result<Self> {
+                value.to_system_time()
+            }
+        }
+
+        impl TryFrom<SystemTime> for $Type {
+            type Error = Error;
+
+            fn try_from(value: SystemTime) -> Result<Self> {
+                Self::from_system_time(value)
+            }
+        }
+
+        impl<'a> Peekable<'a> for $Type {
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+    };
+}
+
+/// Implements `Readable` and `Writable` of a timestamp newtype as its integer.
+macro_rules! impl_time_int {
+    ($Type:ident) => {
+        impl<'a> Readable<'a> for $Type {
+            /// Reads the timestamp integer with the stream endianness.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                Ok(Self(s.read()?))
+            }
+        }
+
+        impl Writable for $Type {
+            /// Writes the timestamp integer with the stream endianness.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.write(&val.0)
+            }
+        }
+    };
+}
+
+/// Unsigned 32-bit seconds since the Unix epoch, 1970-01-01.
+///
+/// Covers the years 1970 to 2106.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use bytecraft::types::UnixTime32;
+///
+/// let time: UnixTime32 = UnixTime32(1_000_000_000);
+/// assert_eq!(time.to_system_time().unwrap(), UNIX_EPOCH + Duration::from_secs(1_000_000_000));
+/// assert!(UnixTime32::from_system_time(UNIX_EPOCH - Duration::from_secs(1)).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTime32(pub u32);
+
+/// Signed 64-bit seconds since the Unix epoch, 1970-01-01.
+///
+/// Negative values are times before the epoch.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use bytecraft::types::UnixTime64;
+///
+/// let time: UnixTime64 = UnixTime64::from_system_time(UNIX_EPOCH - Duration::from_millis(1500)).unwrap();
+/// assert_eq!(time, UnixTime64(-2));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTime64(pub i64);
+
+/// Signed 64-bit nanoseconds since the Unix epoch, 1970-01-01.
+///
+/// Covers the years 1677 to 2262.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use bytecraft::types::UnixNanos;
+///
+/// let time: UnixNanos = UnixNanos(1_500_000_000);
+/// assert_eq!(time.to_system_time().unwrap(), UNIX_EPOCH + Duration::from_millis(1500));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixNanos(pub i64);
+
+/// Windows `FILETIME`, 100-nanosecond intervals since 1601-01-01.
+///
+/// Used by NTFS, the Windows API and many Microsoft file formats.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use bytecraft::types::FileTime;
+///
+/// let time: FileTime = FileTime::from_system_time(UNIX_EPOCH).unwrap();
+/// assert_eq!(time, FileTime(116_444_736_000_000_000));
+/// assert_eq!(FileTime(10_000_000).to_duration().unwrap(), Duration::from_secs(1));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileTime(pub u64);
+
+/// NTP timestamp, 32.32 fixed-point seconds since 1900-01-01.
+///
+/// The upper 32 bits hold the seconds and the lower 32 bits the fraction
+/// of a second. Only era 0, up to the year 2036, is supported.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use bytecraft::types::NtpTimestamp;
+///
+/// let time: NtpTimestamp = NtpTimestamp::new(2_208_988_800, 0x8000_0000);
+/// assert_eq!(time.to_system_time().unwrap(), UNIX_EPOCH + Duration::from_millis(500));
+/// assert_eq!(time.seconds(), 2_208_988_800);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp(pub u64);
+
+/// Mac HFS and HFS+ time, unsigned 32-bit seconds since 1904-01-01.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::UNIX_EPOCH;
+///
+/// use bytecraft::types::HfsTime;
+///
+/// assert_eq!(HfsTime(2_082_844_800).to_system_time().unwrap(), UNIX_EPOCH);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HfsTime(pub u32);
+
+/// MS-DOS date and time, used by FAT file systems and ZIP archives.
+///
+/// Covers the years 1980 to 2107 with a resolution of 2 seconds. The time
+/// is read and written first, then the date, both as `u16` with the
+/// endianness of the stream, in the order of ZIP headers and FAT directory
+/// entries. Reading does not validate the fields; the conversions return
+/// [`Error::NotValid`] for invalid dates.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{reader::ByteReader, types::DosDateTime};
+///
+/// let mut reader = ByteReader::new(&[0x1E, 0x7C, 0x21, 0x5B]); // Assuming little endian
+/// let time: DosDateTime = reader.read().unwrap();
+///
+/// assert_eq!((time.year(), time.month(), time.day()), (2025, 9, 1));
+/// assert_eq!((time.hour(), time.minute(), time.second()), (15, 32, 60));
+/// assert!(time.to_system_time().is_err());
+///
+/// let time: DosDateTime = DosDateTime::new(2025, 9, 1, 15, 32, 59).unwrap();
+/// assert_eq!(time.second(), 58);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DosDateTime {
+    /// The date: day in bits 0-4, month in bits 5-8 and years since 1980 in bits 9-15.
+    pub date: u16,
+    /// The time: seconds divided by 2 in bits 0-4, minutes in bits 5-10 and hours in bits 11-15.
+    pub time: u16,
+}
+
+impl UnixTime32 {
+    fn unix_nanos(self) -> Result<i128> {
+        Ok(self.0 as i128 * NANOS)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        Ok(Self(convert(nanos.div_euclid(NANOS))?))
+    }
+}
+
+impl UnixTime64 {
+    fn unix_nanos(self) -> Result<i128> {
+        Ok(self.0 as i128 * NANOS)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        Ok(Self(convert(nanos.div_euclid(NANOS))?))
+    }
+}
+
+impl UnixNanos {
+    fn unix_nanos(self) -> Result<i128> {
+        Ok(self.0 as i128)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        Ok(Self(convert(nanos)?))
+    }
+}
+
+impl FileTime {
+    fn unix_nanos(self) -> Result<i128> {
+        Ok(self.0 as i128 * 100 - FILETIME_EPOCH * NANOS)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        Ok(Self(convert(
+            (nanos + FILETIME_EPOCH * NANOS).div_euclid(100),
+        )?))
+    }
+}
+
+impl NtpTimestamp {
+    /// Creates a timestamp from seconds and a fraction of a second.
+    ///
+    /// # Parameters
+    ///
+    /// - `seconds`: The seconds since 1900-01-01
+    /// - `fraction`: The fraction of a second in units of `2^-32` seconds
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        Self(((seconds as u64) << 32) | fraction as u64)
+    }
+
+    /// Returns the seconds since 1900-01-01.
+    pub const fn seconds(self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// Returns the fraction of a second in units of `2^-32` seconds.
+    pub const fn fraction(self) -> u32 {
+        self.0 as u32
+    }
+
+    fn unix_nanos(self) -> Result<i128> {
+        let fraction: i128 = (self.fraction() as i128 * NANOS) >> 32;
+        Ok((self.seconds() as i128 - NTP_EPOCH) * NANOS + fraction)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        let nanos: i128 = nanos + NTP_EPOCH * NANOS;
+        let seconds: u32 = convert(nanos.div_euclid(NANOS))?;
+        let fraction: i128 = (nanos.rem_euclid(NANOS) << 32) / NANOS;
+
+        Ok(Self::new(seconds, fraction as u32))
+    }
+}
+
+impl HfsTime {
+    fn unix_nanos(self) -> Result<i128> {
+        Ok((self.0 as i128 - HFS_EPOCH) * NANOS)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        Ok(Self(convert(nanos.div_euclid(NANOS) + HFS_EPOCH)?))
+    }
+}
+
+impl DosDateTime {
+    /// Creates a date and time from its fields.
+    ///
+    /// The seconds are rounded down to an even number.
+    ///
+    /// # Parameters
+    ///
+    /// - `year`: The year, from 1980 to 2107
+    /// - `month`: The month, from 1 to 12
+    /// - `day`: The day of the month, from 1
+    /// - `hour`: The hour, from 0 to 23
+    /// - `minute`: The minute, from 0 to 59
+    /// - `second`: The second, from 0 to 59
+    ///
+    /// # Returns
+    ///
+    /// - `Some(value)` if the date and time are valid
+    /// - `None` otherwise
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if !(1980..=2107).contains(&year) || hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+
+        let days: i64 = days_from_civil(year as i64, month as u32, day as u32);
+
+        if civil_from_days(days) != (year as i64, month as u32, day as u32) {
+            return None;
+        }
+
+        Some(Self {
+            date: ((year - 1980) << 9) | ((month as u16) << 5) | day as u16,
+            time: ((hour as u16) << 11) | ((minute as u16) << 5) | (second as u16 / 2),
+        })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> u16 {
+        (self.date >> 9) + 1980
+    }
+
+    /// Returns the month, 1 to 12 for a valid date.
+    pub fn month(&self) -> u8 {
+        ((self.date >> 5) & 0x0F) as u8
+    }
+
+    /// Returns the day of the month, from 1 for a valid date.
+    pub fn day(&self) -> u8 {
+        (self.date & 0x1F) as u8
+    }
+
+    /// Returns the hour, 0 to 23 for a valid time.
+    pub fn hour(&self) -> u8 {
+        (self.time >> 11) as u8
+    }
+
+    /// Returns the minute, 0 to 59 for a valid time.
+    pub fn minute(&self) -> u8 {
+        ((self.time >> 5) & 0x3F) as u8
+    }
+
+    /// Returns the second, an even number from 0 to 58 for a valid time.
+    pub fn second(&self) -> u8 {
+        (self.time & 0x1F) as u8 * 2
+    }
+
+    fn unix_nanos(self) -> Result<i128> {
+        let (year, month, day) = (self.year(), self.month(), self.day());
+        let (hour, minute, second) = (self.hour(), self.minute(), self.second());
+
+        if Self::new(year, month, day, hour, minute, second) != Some(self) {
+            return Err(Error::NotValid);
+        }
+
+        let days: i64 = days_from_civil(year as i64, month as u32, day as u32);
+        let seconds: i64 = days * 86_400 + hour as i64 * 3600 + minute as i64 * 60 + second as i64;
+
+        Ok(seconds as i128 * NANOS)
+    }
+
+    fn from_unix_nanos(nanos: i128) -> Result<Self> {
+        let seconds: i64 = convert(nanos.div_euclid(NANOS))?;
+        let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+        let time: i64 = seconds.rem_euclid(86_400);
+
+        let year: u16 = convert(year as i128)?;
+        let (hour, minute, second) = (time / 3600, time / 60 % 60, time % 60);
+
+        Self::new(
+            year,
+            month as u8,
+            day as u8,
+            hour as u8,
+            minute as u8,
+            second as u8,
+        )
+        .ok_or(Error::NotValid)
+    }
+}
+
+impl<'a> Readable<'a> for DosDateTime {
+    /// Reads the time and then the date as `u16` with the stream endianness.
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let (time, date) = s.read::<(u16, u16)>()?;
+        Ok(Self { date, time })
+    }
+}
+
+impl Writable for DosDateTime {
+    /// Writes the time and then the date as `u16` with the stream endianness.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(&val.time)?;
+        s.write(&val.date)
+    }
+}
+
+impl_time!(UnixTime32, 0);
+impl_time!(UnixTime64, 0);
+impl_time!(UnixNanos, 0);
+impl_time!(FileTime, FILETIME_EPOCH);
+impl_time!(NtpTimestamp, NTP_EPOCH);
+impl_time!(HfsTime, HFS_EPOCH);
+impl_time!(DosDateTime, DOS_EPOCH);
+
+impl_time_int!(UnixTime32);
+impl_time_int!(UnixTime64);
+impl_time_int!(UnixNanos);
+impl_time_int!(FileTime);
+impl_time_int!(NtpTimestamp);
+impl_time_int!(HfsTime);
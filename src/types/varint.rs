@@ -0,0 +1,150 @@
+//! Variable-length integers in LEB128 encoding.
+//!
+//! The `varint` module provides [`VarU32`] and [`VarU64`], unsigned integers
+//! encoded in the unsigned LEB128 format used by Protocol Buffers, WebAssembly
+//! and DWARF. Every byte carries 7 bits of the value starting from the least
+//! significant group, and the high bit of a byte is set when more bytes
+//! follow. Small values take less space, so varints are often used for
+//! lengths and tags.
+//!
+//! The encoding does not depend on the endianness of the reader or writer.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytecraft::{reader::ByteReader, types::VarU32, writer::ByteWriter};
+//!
+//! let mut buffer = [0u8; 3];
+//! let mut writer = ByteWriter::new(&mut buffer[..]);
+//!
+//! writer.write(&VarU32(1)).unwrap();
+//! writer.write(&VarU32(300)).unwrap();
+//! assert_eq!(buffer, [0x01, 0xAC, 0x02]);
+//!
+//! let mut reader = ByteReader::new(&buffer);
+//! assert_eq!(reader.read::<VarU32>().unwrap(), VarU32(1));
+//! assert_eq!(reader.read::<VarU32>().unwrap().0, 300);
+//! ```
+
+use crate::error::{Error, Result};
+use crate::reader::peekable::Peekable;
+use crate::reader::readable::Readable;
+use crate::reader::{PeekStream, ReadStream};
+use crate::writer::writable::Writable;
+use crate::writer::WriteStream;
+
+macro_rules! impl_varint {
+    ($(#[$meta:meta])* $Name:ident, $Type:ty, $MaxLen:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name(pub $Type);
+
+        impl $Name {
+            /// The maximum number of bytes in an encoded value.
+            pub const MAX_LEN: usize = $MaxLen;
+
+            /// Returns the number of bytes in the encoded value.
+            ///
+            /// # Examples
+            ///
+            /// ```rust
+            #[doc = concat!("use bytecraft::types::", stringify!($Name), ";")]
+            ///
+            #[doc = concat!("assert_eq!(", stringify!($Name), "(127).encoded_len(), 1);")]
+            #[doc = concat!("assert_eq!(", stringify!($Name), "(128).encoded_len(), 2);")]
+            /// ```
+            pub fn encoded_len(&self) -> usize {
+                let bits: u32 = <$Type>::BITS - self.0.leading_zeros();
+                (bits.max(1) as usize).div_ceil(7)
+            }
+        }
+
+        impl From<$Type> for $Name {
+            fn from(value: $Type) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$Name> for $Type {
+            fn from(value: $Name) -> Self {
+                value.0
+            }
+        }
+
+        impl<'a> Readable<'a> for $Name {
+            /// Reads a LEB128 encoded value.
+            ///
+            /// # Error Propagation
+            ///
+            /// Returns [`Error::NotValid`] if the value does not fit in the
+            /// underlying type or is longer than [`Self::MAX_LEN`] bytes.
+            fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+                let mut value: $Type = 0;
+                let mut shift: u32 = 0;
+
+                loop {
+                    let byte: u8 = s.read()?;
+                    let part: $Type = (byte & 0x7F) as $Type;
+
+                    if shift >= <$Type>::BITS || (part << shift) >> shift != part {
+                        return Err(Error::NotValid);
+                    }
+
+                    value |= part << shift;
+
+                    if byte & 0x80 == 0 {
+                        return Ok(Self(value));
+                    }
+
+                    shift += 7;
+                }
+            }
+        }
+
+        impl<'a> Peekable<'a> for $Name {
+            /// Peeks a LEB128 encoded value.
+            fn peek<'r>(s: PeekStream<'a, 'r>) -> Result<Self> {
+                s.preview::<Self>()
+            }
+        }
+
+        impl Writable for $Name {
+            /// Writes the value in LEB128 encoding, using the minimal number of bytes.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                let mut bytes: [u8; $MaxLen] = [0u8; $MaxLen];
+                let mut value: $Type = val.0;
+                let mut len: usize = 0;
+
+                loop {
+                    bytes[len] = (value & 0x7F) as u8;
+                    value >>= 7;
+                    len += 1;
+
+                    if value == 0 {
+                        break;
+                    }
+
+                    bytes[len - 1] |= 0x80;
+                }
+
+                s.write_exact(&bytes[..len])
+            }
+        }
+    };
+}
+
+impl_varint!(
+    /// An unsigned 32-bit integer in LEB128 encoding, 1 to 5 bytes.
+    VarU32,
+    u32,
+    5
+);
+impl_varint!(
+    /// An unsigned 64-bit integer in LEB128 encoding, 1 to 10 bytes.
+    VarU64,
+    u64,
+    10
+);
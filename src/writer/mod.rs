@@ -16,6 +16,8 @@
 //!
 //! - [`WriteStream`] - Provides the interface for `Writable` implementations
 //!   to write data and advance the writer's position
+//! - [`DynWriter`] - A writer over a mutable byte slice, used to write
+//!   [`DynWritable`] trait objects into a writer of any buffer type
 //!
 //! ## Navigation and Positioning
 //!
@@ -155,11 +157,29 @@
 //! ```
 pub mod writable;
 
+use std::any::Any;
 use std::io::Write;
+use std::sync::Arc;
 
+use crate::checksum::Checksum;
 use crate::common::{Endian, SeekFrom};
+use crate::compression::Codec;
+use crate::encoding::{Base64, Hex};
 use crate::error::{Error, Result};
-use writable::Writable;
+use crate::hexdump::HexDump;
+use writable::{DynWritable, Writable, WritableWith};
+
+/// Initial size of the scratch buffer of [`ByteWriter::compressed()`].
+const SCRATCH_SIZE: usize = 256;
+
+/// A writer over a mutable byte slice, used as the type-erased sink for
+/// [`DynWritable`] values.
+///
+/// Any `ByteWriter<T>` can lend its buffer as a `DynWriter` with the same
+/// position, endianness and context, so trait objects are written into it
+/// without knowing the buffer type `T`. See
+/// [`ByteWriter::write_dyn()`] for details.
+pub type DynWriter<'w> = ByteWriter<&'w mut [u8]>;
 
 /// A versatile binary data writer for serializing structured binary formats.
 ///
@@ -171,7 +191,7 @@ use writable::Writable;
 /// # Type Parameters
 ///
 /// - `T`: The underlying buffer type that must implement both `AsRef<[u8]>`
-///        for reading current data and `AsMut<[u8]>` for writing new data
+///   for reading current data and `AsMut<[u8]>` for writing new data
 ///
 /// # Performance Characteristics
 ///
@@ -195,11 +215,11 @@ use writable::Writable;
 /// # Examples
 ///
 /// See the [module-level documentation](self) for comprehensive examples.
-#[derive(Debug, Eq)]
 pub struct ByteWriter<T: AsRef<[u8]> + AsMut<[u8]>> {
     data: T,
     pos: usize,
     endian: Endian,
+    context: Option<Arc<dyn Any + Send + Sync>>,
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
@@ -211,7 +231,7 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
     /// # Parameters
     ///
     /// - `data`: The target buffer to write to. Must implement both
-    ///           `AsRef<[u8]>` and `AsMut<[u8]>`.
+    ///   `AsRef<[u8]>` and `AsMut<[u8]>`.
     ///
     /// # Returns
     ///
@@ -231,6 +251,7 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
             data,
             pos: 0,
             endian: Endian::Native,
+            context: None,
         }
     }
 
@@ -261,9 +282,79 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
             data,
             pos: 0,
             endian,
+            context: None,
+        }
+    }
+
+    /// Creates a new `ByteWriter` with an attached user context.
+    ///
+    /// The context is available to every nested [`Writable`] implementation
+    /// through [WriteStream::context()]. The context is shared between clones
+    /// of the writer.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The context type
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: The target buffer to write to
+    /// - `context`: The user context
+    ///
+    /// # Returns
+    ///
+    /// A new `ByteWriter` instance with native endianness and the attached context.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let writer = ByteWriter::with_context(&mut buffer[..], 2u8);
+    /// assert_eq!(writer.context::<u8>(), Some(&2));
+    /// ```
+    pub fn with_context<C: Any + Send + Sync>(data: T, context: C) -> Self {
+        Self {
+            data,
+            pos: 0,
+            endian: Endian::Native,
+            context: Some(Arc::new(context)),
         }
     }
 
+    /// Returns a reference to the attached user context of type `C`.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The expected context type
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&context)` if a context of type `C` is attached
+    /// - `None` if there is no context or it has a different type
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.context.as_deref()?.downcast_ref::<C>()
+    }
+
+    /// Attaches a user context, replacing the previous one.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The context type
+    ///
+    /// # Parameters
+    ///
+    /// - `context`: The new user context
+    pub fn set_context<C: Any + Send + Sync>(&mut self, context: C) {
+        self.context = Some(Arc::new(context));
+    }
+
+    /// Removes the attached user context.
+    pub fn clear_context(&mut self) {
+        self.context = None;
+    }
+
     /// Returns the current endianness setting.
     ///
     /// This setting affects how multi-byte values (u16, u32, f32, etc.) are
@@ -692,6 +783,80 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
         self.data
     }
 
+    /// Encodes the bytes before the current position as lowercase hexadecimal digits.
+    ///
+    /// # Returns
+    ///
+    /// The hexadecimal text of the written data, see [`Hex`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    ///
+    /// writer.write(&0xCAFEu16).unwrap();
+    /// assert_eq!(writer.to_hex(), "cafe");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        Hex::encode(&self.data.as_ref()[..self.pos])
+    }
+
+    /// Encodes the bytes before the current position as Base64 text.
+    ///
+    /// # Parameters
+    ///
+    /// - `base64`: The alphabet and padding of the text, see [`Base64`]
+    ///
+    /// # Returns
+    ///
+    /// The Base64 text of the written data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{encoding::Base64, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// writer.write_bytes(&[0xFB, 0xFF]).unwrap();
+    /// assert_eq!(writer.to_base64(Base64::Standard), "+/8=");
+    /// assert_eq!(writer.to_base64(Base64::UrlSafeNoPad), "-_8");
+    /// ```
+    pub fn to_base64(&self, base64: Base64) -> String {
+        base64.encode(&self.data.as_ref()[..self.pos])
+    }
+
+    /// Creates a [`HexDump`] of the whole buffer with the current position highlighted.
+    ///
+    /// The dump uses the canonical `hexdump -C` layout by default and can be
+    /// configured with the builder methods of [`HexDump`].
+    ///
+    /// # Returns
+    ///
+    /// A [`HexDump`] borrowing the buffer of the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    /// writer.write(&0xCAFEu16).unwrap();
+    ///
+    /// assert_eq!(
+    ///     writer.hexdump().width(8).to_string(),
+    ///     "00000000  ca fe[00]00 00 00 00 00  |........|"
+    /// );
+    /// ```
+    pub fn hexdump(&self) -> HexDump<'_> {
+        HexDump::new(self.data.as_ref()).highlight(self.pos..self.pos + 1)
+    }
+
     /// Returns the total length of the underlying buffer.
     ///
     /// Provides the size of the entire buffer in bytes, regardless of
@@ -904,7 +1069,7 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
     ///
     /// # Type Parameters
     ///
-    /// - `W`: A type that implements [`Writable`]
+    /// - `W`: A type that implements [`Writable`], possibly unsized like `str` or `[T]`
     ///
     /// # Parameters
     ///
@@ -925,11 +1090,278 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
     ///
     /// writer.write(&42u32).unwrap(); // Write using Writable implementation
     /// assert_eq!(writer.position(), 4);
+    ///
+    /// writer.write("ab").unwrap(); // Unsized types are written without allocation
+    /// assert_eq!(&buffer[4..], &[2, 0, 0, 0, b'a', b'b']);
     /// ```
-    pub fn write<W: Writable>(&mut self, val: &W) -> Result<()> {
+    pub fn write<W: Writable + ?Sized>(&mut self, val: &W) -> Result<()> {
         W::write(WriteStream { writer: self }, val)
     }
 
+    /// Writes a value of type `W` using external arguments and advances position.
+    ///
+    /// Works like [`write()`](ByteWriter::write) for types that need additional
+    /// information to be serialized, such as an array whose length is stored
+    /// in a previous field.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `W`: A type that implements [`WritableWith`]
+    /// - `A`: The type of arguments
+    ///
+    /// # Parameters
+    ///
+    /// - `val`: A reference to the value to write
+    /// - `args`: The arguments passed to the [`WritableWith`] implementation
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the value was successfully written
+    /// - An error if the value cannot be written or buffer space is insufficient
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let values: Vec<u8> = vec![1, 2];
+    /// writer.write(&(values.len() as u8)).unwrap();
+    /// writer.write_with(&values, values.len()).unwrap();
+    /// assert_eq!(buffer, [2, 1, 2]);
+    /// ```
+    pub fn write_with<W: WritableWith<A> + ?Sized, A>(&mut self, val: &W, args: A) -> Result<()> {
+        W::write_with(WriteStream { writer: self }, val, args)
+    }
+
+    /// Writes a type-erased value and advances position.
+    ///
+    /// The value is written into a [`DynWriter`] borrowing the buffer of
+    /// this writer with the same position, endianness and context. After
+    /// writing, the state of the `DynWriter` is copied back, so the result
+    /// is the same as writing the concrete type with [`write()`](ByteWriter::write).
+    ///
+    /// # Parameters
+    ///
+    /// - `val`: A reference to the trait object to write
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the value was successfully written
+    /// - An error if the value cannot be written or buffer space is insufficient
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{writable::DynWritable, writer::ByteWriter};
+    ///
+    /// let fields: Vec<Box<dyn DynWritable>> = vec![Box::new(1u8), Box::new(2u16)];
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// for field in &fields {
+    ///     writer.write_dyn(field.as_ref()).unwrap();
+    /// }
+    /// assert_eq!(buffer, [1, 2, 0]); // Assuming little endian
+    /// ```
+    pub fn write_dyn(&mut self, val: &dyn DynWritable) -> Result<()> {
+        let mut sink: DynWriter = ByteWriter {
+            data: self.data.as_mut(),
+            pos: self.pos,
+            endian: self.endian,
+            context: self.context.clone(),
+        };
+
+        let result: Result<()> = val.write_dyn(&mut sink);
+
+        self.pos = sink.pos;
+        self.endian = sink.endian;
+        self.context = sink.context;
+
+        result
+    }
+
+    /// Runs a closure and computes a checksum over the bytes it wrote.
+    ///
+    /// The checksum covers the buffer between the position before and after
+    /// the closure. See the [checksum](crate::checksum) module for the built-in algorithms.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The checksum algorithm, see [`Checksum`]
+    /// - `R`: The type of the value returned by the closure
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The closure writing the data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, checksum))` containing the result of the closure and the checksum
+    /// - [`Error::NotValid`] if the closure moved the position backwards
+    /// - An error returned by the closure
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{checksum::Crc32c, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 9];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let ((), crc) = writer.digest::<Crc32c, _>(|s| s.write_exact(b"123456789")).unwrap();
+    /// assert_eq!(crc, 0xE3069283);
+    /// ```
+    pub fn digest<C: Checksum, R>(
+        &mut self,
+        f: impl FnOnce(&mut WriteStream<'_, T>) -> Result<R>,
+    ) -> Result<(R, C::Output)> {
+        let start: usize = self.pos;
+        let value: R = f(&mut WriteStream { writer: self })?;
+        let bytes: &[u8] = self
+            .data
+            .as_ref()
+            .get(start..self.pos)
+            .ok_or(Error::NotValid)?;
+
+        Ok((value, C::checksum(bytes)))
+    }
+
+    /// Runs a closure, then writes the checksum of the bytes it wrote.
+    ///
+    /// The checksum is written as `C::Output` with the endianness of the writer.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The checksum algorithm, see [`Checksum`]
+    /// - `R`: The type of the value returned by the closure
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The closure writing the data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the result of the closure
+    /// - [`Error::NotValid`] if the closure moved the position backwards
+    /// - An error returned by the closure or when writing the checksum
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{checksum::Crc16Xmodem, common::Endian, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 11];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    ///
+    /// writer.write_checksum::<Crc16Xmodem, _>(|s| s.write_exact(b"123456789")).unwrap();
+    /// assert_eq!(buffer[9..], [0x31, 0xC3]);
+    /// ```
+    pub fn write_checksum<C: Checksum, R>(
+        &mut self,
+        f: impl FnOnce(&mut WriteStream<'_, T>) -> Result<R>,
+    ) -> Result<R>
+    where
+        C::Output: Writable,
+    {
+        let (value, checksum) = self.digest::<C, R>(f)?;
+        self.write(&checksum)?;
+
+        Ok(value)
+    }
+
+    /// Runs a closure and compresses the bytes it wrote.
+    ///
+    /// The closure writes the uncompressed data into a scratch buffer, with
+    /// the endianness and the context of this writer. The scratch buffer
+    /// starts small; when the closure fails with [`Error::InsufficientData`]
+    /// because the buffer is full, the closure is called again with a larger
+    /// buffer, so it may run several times. Only the compressed data is
+    /// written to this writer, so its buffer needs room for the compressed
+    /// data alone. See the [compression](crate::compression) module for the
+    /// built-in codecs.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `C`: The compression codec, see [`Codec`]
+    /// - `R`: The type of the value returned by the closure
+    ///
+    /// # Parameters
+    ///
+    /// - `f`: The closure writing the uncompressed data
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, size))` containing the result of the closure and the size of the compressed data
+    /// - [`Error::InsufficientData`] if the compressed data does not fit, or
+    ///   the scratch buffer cannot be allocated
+    /// - An error returned by the closure or the codec
+    ///
+    /// On error, the buffer and the position of this writer are not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{compression::Rle, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let ((), size) = writer
+    ///     .compressed::<Rle, _>(|s| s.write_exact(&[0u8; 16]))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(size, 2);
+    /// assert_eq!(writer.position(), 2);
+    /// assert_eq!(buffer[..2], [0xF1, 0x00]);
+    /// ```
+    pub fn compressed<C: Codec, R>(
+        &mut self,
+        mut f: impl FnMut(&mut WriteStream<'_, Vec<u8>>) -> Result<R>,
+    ) -> Result<(R, usize)> {
+        let mut size: usize = SCRATCH_SIZE;
+
+        loop {
+            let mut scratch: ByteWriter<Vec<u8>> = ByteWriter {
+                data: Vec::new(),
+                pos: 0,
+                endian: self.endian,
+                context: self.context.clone(),
+            };
+            scratch
+                .data
+                .try_reserve_exact(size)
+                .map_err(|_| Error::InsufficientData {
+                    requested: size,
+                    available: 0,
+                })?;
+            scratch.data.resize(size, 0);
+
+            match f(&mut WriteStream {
+                writer: &mut scratch,
+            }) {
+                Ok(value) => {
+                    let compressed: Vec<u8> = C::compress(&scratch.data[..scratch.pos])?;
+                    self.write_exact(&compressed)?;
+                    return Ok((value, compressed.len()));
+                }
+                // The scratch buffer is full, retry with a larger one
+                Err(Error::InsufficientData { requested, .. })
+                    if scratch
+                        .pos
+                        .checked_add(requested)
+                        .is_some_and(|end| end > size) =>
+                {
+                    size = size.saturating_mul(2).max(scratch.pos + requested);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     /// Writes exactly `bytes` to the buffer and advances position.
     ///
     /// A public convenience method equivalent to `write_exact()` for writing
@@ -960,6 +1392,151 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> ByteWriter<T> {
         self.write_exact(bytes)
     }
 
+    /// Writes an unsigned integer as `size` bytes and advances position.
+    ///
+    /// Writes integers of any width from 1 to 8 bytes, such as 24-bit or 48-bit
+    /// values, using the current endianness of the writer.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to write
+    /// - `size`: The number of bytes of the integer, from 1 to 8
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - [`Error::NotValid`] if `size` is 0 or greater than 8, or `value` does not fit in `size` bytes
+    /// - [`Error::InsufficientData`] if not enough buffer space is available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    ///
+    /// writer.write_uint_n(0x010203, 3).unwrap();
+    /// assert_eq!(buffer, [0x01, 0x02, 0x03]);
+    /// ```
+    pub fn write_uint_n(&mut self, value: u64, size: usize) -> Result<()> {
+        if size == 0 || size > size_of::<u64>() {
+            return Err(Error::NotValid);
+        }
+
+        if size < size_of::<u64>() && value >> (size * 8) != 0 {
+            return Err(Error::NotValid);
+        }
+
+        let bytes: [u8; 8] = value.to_le_bytes();
+        let mut buffer: [u8; 8] = [0u8; 8];
+        buffer[..size].copy_from_slice(&bytes[..size]);
+
+        match self.endian {
+            Endian::Big => buffer[..size].reverse(),
+            Endian::Little => {}
+            #[cfg(target_endian = "big")]
+            Endian::Native => buffer[..size].reverse(),
+            #[cfg(target_endian = "little")]
+            Endian::Native => {}
+        }
+
+        self.write_exact(&buffer[..size])
+    }
+
+    /// Writes a signed integer as `size` bytes and advances position.
+    ///
+    /// Works like [`write_uint_n()`](ByteWriter::write_uint_n) with the value
+    /// in two's complement form.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to write
+    /// - `size`: The number of bytes of the integer, from 1 to 8
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - [`Error::NotValid`] if `size` is 0 or greater than 8, or `value` does not fit in `size` bytes
+    /// - [`Error::InsufficientData`] if not enough buffer space is available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{common::Endian, writer::ByteWriter};
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    ///
+    /// writer.write_int_n(-2, 3).unwrap();
+    /// assert_eq!(buffer, [0xFE, 0xFF, 0xFF]);
+    /// ```
+    pub fn write_int_n(&mut self, value: i64, size: usize) -> Result<()> {
+        if size == 0 || size > size_of::<i64>() {
+            return Err(Error::NotValid);
+        }
+
+        let shift: usize = 64 - size * 8;
+
+        if (value << shift) >> shift != value {
+            return Err(Error::NotValid);
+        }
+
+        let mask: u64 = u64::MAX >> shift;
+        self.write_uint_n(value as u64 & mask, size)
+    }
+
+    /// Writes a number as packed binary-coded decimal of `size` bytes and advances position.
+    ///
+    /// Every byte holds two decimal digits, the high nibble first, and the
+    /// digits are ordered from the most significant. The number is padded
+    /// with leading zeros. The encoding does not depend on the endianness of
+    /// the writer.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The value to write
+    /// - `size`: The number of bytes, holding `2 * size` digits
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - [`Error::NotValid`] if `value` has more than `2 * size` digits
+    /// - [`Error::InsufficientData`] if not enough buffer space is available
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 3];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// writer.write_bcd(1234, 3).unwrap();
+    /// assert_eq!(buffer, [0x00, 0x12, 0x34]);
+    /// ```
+    pub fn write_bcd(&mut self, value: u64, size: usize) -> Result<()> {
+        let limit: Option<u64> = u32::try_from(size)
+            .ok()
+            .and_then(|size| 100u64.checked_pow(size));
+        if limit.is_some_and(|limit| value >= limit) {
+            return Err(Error::NotValid);
+        }
+
+        self.check_bounds(size)?;
+        let data: &mut [u8] = self.data.as_mut();
+        let mut rest: u64 = value;
+
+        // Encoded in place, leading bytes become zero once `rest` reaches zero
+        for byte in data[self.pos..self.pos + size].iter_mut().rev() {
+            *byte = ((((rest / 10) % 10) << 4) | (rest % 10)) as u8;
+            rest /= 100;
+        }
+
+        self.pos += size;
+        Ok(())
+    }
+
     /// Checks if the requested write size is within buffer bounds.
     ///
     /// Internal helper method that validates whether writing `size` bytes from
@@ -1034,6 +1611,51 @@ impl<'a, T: AsRef<[u8]> + AsMut<[u8]>> WriteStream<'a, T> {
         self.writer.endian()
     }
 
+    /// Returns a reference to the user context of type `C` attached to the underlying writer.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::context`] method.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::{
+    ///     writer::{ByteWriter, WriteStream},
+    ///     writable::Writable,
+    ///     error::Result
+    /// };
+    ///
+    /// struct Wide(bool);
+    ///
+    /// struct Offset(u64);
+    ///
+    /// impl Writable for Offset {
+    ///     fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    ///     where
+    ///         T: AsRef<[u8]> + AsMut<[u8]>,
+    ///     {
+    ///         match s.context::<Wide>() {
+    ///             Some(Wide(true)) => s.write(&val.0),
+    ///             _ => s.write(&(val.0 as u32)),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = ByteWriter::with_context(&mut buffer[..], Wide(false));
+    /// writer.write(&Offset(1)).unwrap();
+    /// assert_eq!(writer.position(), 4);
+    /// ```
+    pub fn context<C: Any>(&self) -> Option<&C> {
+        self.writer.context::<C>()
+    }
+
+    /// Attaches a user context to the underlying writer, replacing the previous one.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::set_context`] method.
+    pub fn set_context<C: Any + Send + Sync>(&mut self, context: C) {
+        self.writer.set_context(context);
+    }
+
     /// Check if the current position is at or beyond the end of the buffer
     ///
     /// # Returns
@@ -1077,6 +1699,42 @@ impl<'a, T: AsRef<[u8]> + AsMut<[u8]>> WriteStream<'a, T> {
         self.writer.write_exact(bytes)
     }
 
+    /// Writes an unsigned integer as `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_uint_n`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - An error if the value cannot be written
+    pub fn write_uint_n(&mut self, value: u64, size: usize) -> Result<()> {
+        self.writer.write_uint_n(value, size)
+    }
+
+    /// Writes a signed integer as `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_int_n`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - An error if the value cannot be written
+    pub fn write_int_n(&mut self, value: i64, size: usize) -> Result<()> {
+        self.writer.write_int_n(value, size)
+    }
+
+    /// Writes a number as packed binary-coded decimal of `size` bytes and advances position.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_bcd`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the write was successful
+    /// - An error if the value cannot be written
+    pub fn write_bcd(&mut self, value: u64, size: usize) -> Result<()> {
+        self.writer.write_bcd(value, size)
+    }
+
     /// Writes a value of type `W` and advances position.
     ///
     /// Delegates to the underlying writer's [`ByteWriter::write`] method.
@@ -1093,9 +1751,90 @@ impl<'a, T: AsRef<[u8]> + AsMut<[u8]>> WriteStream<'a, T> {
     ///
     /// - `Ok(())`
     /// - An error if the value cannot be written
-    pub fn write<W: Writable>(&mut self, val: &W) -> Result<()> {
+    pub fn write<W: Writable + ?Sized>(&mut self, val: &W) -> Result<()> {
         self.writer.write::<W>(val)
     }
+
+    /// Writes a value of type `W` using external arguments and advances position.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_with`] method.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `W`: A type that implements [`WritableWith`]
+    /// - `A`: The type of arguments
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`
+    /// - An error if the value cannot be written
+    pub fn write_with<W: WritableWith<A> + ?Sized, A>(&mut self, val: &W, args: A) -> Result<()> {
+        self.writer.write_with::<W, A>(val, args)
+    }
+
+    /// Writes a type-erased value and advances position.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_dyn`] method.
+    ///
+    /// # Parameters
+    ///
+    /// - `val`: A reference to the trait object to write
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())`
+    /// - An error if the value cannot be written
+    pub fn write_dyn(&mut self, val: &dyn DynWritable) -> Result<()> {
+        self.writer.write_dyn(val)
+    }
+
+    /// Runs a closure and computes a checksum over the bytes it wrote.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::digest`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, checksum))` containing the result of the closure and the checksum
+    /// - An error returned by the closure
+    pub fn digest<C: Checksum, R>(
+        &mut self,
+        f: impl FnOnce(&mut WriteStream<'_, T>) -> Result<R>,
+    ) -> Result<(R, C::Output)> {
+        self.writer.digest::<C, R>(f)
+    }
+
+    /// Runs a closure, then writes the checksum of the bytes it wrote.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::write_checksum`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(value)` containing the result of the closure
+    /// - An error if the data or the checksum cannot be written
+    pub fn write_checksum<C: Checksum, R>(
+        &mut self,
+        f: impl FnOnce(&mut WriteStream<'_, T>) -> Result<R>,
+    ) -> Result<R>
+    where
+        C::Output: Writable,
+    {
+        self.writer.write_checksum::<C, R>(f)
+    }
+
+    /// Runs a closure and compresses the bytes it wrote.
+    ///
+    /// Delegates to the underlying writer's [`ByteWriter::compressed`] method.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok((value, size))` containing the result of the closure and the size of the compressed data
+    /// - An error returned by the closure or if the compressed data does not fit
+    pub fn compressed<C: Codec, R>(
+        &mut self,
+        f: impl FnMut(&mut WriteStream<'_, Vec<u8>>) -> Result<R>,
+    ) -> Result<(R, usize)> {
+        self.writer.compressed::<C, R>(f)
+    }
 }
 
 impl<T: AsRef<[u8]> + AsMut<[u8]> + Clone> Clone for ByteWriter<T> {
@@ -1104,6 +1843,7 @@ impl<T: AsRef<[u8]> + AsMut<[u8]> + Clone> Clone for ByteWriter<T> {
             data: self.data.clone(),
             pos: self.pos,
             endian: self.endian,
+            context: self.context.clone(),
         }
     }
 }
@@ -1114,6 +1854,18 @@ impl<T: AsRef<[u8]> + AsMut<[u8]>> PartialEq for ByteWriter<T> {
     }
 }
 
+impl<T: AsRef<[u8]> + AsMut<[u8]>> Eq for ByteWriter<T> {}
+
+impl<T: AsRef<[u8]> + AsMut<[u8]> + core::fmt::Debug> core::fmt::Debug for ByteWriter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ByteWriter")
+            .field("data", &self.data)
+            .field("pos", &self.pos)
+            .field("endian", &self.endian)
+            .finish()
+    }
+}
+
 impl<T: AsRef<[u8]> + AsMut<[u8]>> From<T> for ByteWriter<T> {
     fn from(value: T) -> Self {
         Self::new(value)
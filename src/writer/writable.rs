@@ -13,6 +13,19 @@
 //! Any type that implements `Writable` can be written to a [`WriteStream`] using
 //! the [ByteWriter::write()](crate::writer::ByteWriter::write) method.
 //!
+//! ## WritableWith Trait
+//!
+//! The [`WritableWith`] trait is used for types that need external arguments,
+//! such as a length stored in a previous field. They are written with the
+//! [ByteWriter::write_with()](crate::writer::ByteWriter::write_with) method.
+//!
+//! ## DynWritable Trait
+//!
+//! [`Writable`] is not object safe because it is generic over the buffer type.
+//! The [`DynWritable`] trait is implemented for every `Writable` type and
+//! allows heterogeneous values to be stored as `Box<dyn DynWritable>` and
+//! written into any writer.
+//!
 //! ## Composition and Nesting
 //!
 //! `Writable` implementations can compose other `Writable` types, enabling
@@ -133,6 +146,23 @@
 //! writer.write(&vec).unwrap();
 //! ```
 //!
+//! Borrowed `str`, `[T]`, `CStr` and `Path` are written directly, with the
+//! same format as their owned counterparts:
+//!
+//! ```rust
+//! use bytecraft::writer::ByteWriter;
+//!
+//! let mut buffer = [0u8; 16];
+//! let mut writer = ByteWriter::new(&mut buffer[..]);
+//!
+//! writer.write("Hello").unwrap();
+//! writer.write(&[1u8, 2, 3][..]).unwrap();
+//! ```
+//!
+//! `HashMap`, `BTreeMap`, `HashSet`, `BTreeSet` and `VecDeque` use the same
+//! `u32` length prefix. Hashed collections are written in iteration order,
+//! or in ascending key order with the [`SortedKeys`] argument.
+//!
 //! # Custom Implementation Guide
 //!
 //! To make your type `Writable`, implement the trait:
@@ -179,11 +209,24 @@
 //! - Errors are propagated appropriately
 //! - The stream position is advanced correctly
 //! - The implementation does not cause recursion
-use std::ffi::CString;
+use core::marker::PhantomData;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+};
+use core::ops::Range;
+use core::time::Duration;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::ffi::{CStr, CString};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::common::Endian;
 use crate::error::{Error, Result};
-use crate::writer::WriteStream;
+use crate::writer::{DynWriter, WriteStream};
 
 /// A trait for types that can be written to a binary stream.
 ///
@@ -205,6 +248,47 @@ use crate::writer::WriteStream;
 /// `Writable` implementations can compose other `Writable` types, enabling
 /// complex nested structures to be written seamlessly.
 ///
+/// # Unsized Types
+///
+/// `Writable` has no `Sized` bound, so it is implemented for `str`, `[T]`,
+/// [`CStr`] and [`Path`] with the same format as `String`, `Vec<T>`,
+/// [`CString`] and [`PathBuf`]. Trait objects can be written as well by
+/// implementing `Writable` for `dyn Trait`:
+///
+/// ```rust
+/// use bytecraft::{
+///     writer::{ByteWriter, WriteStream},
+///     writable::Writable,
+///     error::Result
+/// };
+///
+/// trait Shape {
+///     fn id(&self) -> u8;
+/// }
+///
+/// struct Circle;
+///
+/// impl Shape for Circle {
+///     fn id(&self) -> u8 { 7 }
+/// }
+///
+/// impl Writable for dyn Shape {
+///     fn write<T>(mut stream: WriteStream<T>, val: &Self) -> Result<()>
+///     where
+///         T: AsRef<[u8]> + AsMut<[u8]>,
+///     {
+///         stream.write(&val.id())
+///     }
+/// }
+///
+/// let shapes: Vec<Box<dyn Shape>> = vec![Box::new(Circle)];
+///
+/// let mut buffer = [0u8; 5];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write(&shapes[..]).unwrap();
+/// assert_eq!(buffer, [1, 0, 0, 0, 7]);
+/// ```
+///
 /// # Examples
 ///
 /// ## Simple Implementation
@@ -266,7 +350,7 @@ use crate::writer::WriteStream;
 ///
 /// - [`crate::writer::ByteWriter::write()`] - The primary method for writing `Writable` types
 /// - [`crate::writer::WriteStream`] - The stream type provided to implementations
-pub trait Writable: Sized {
+pub trait Writable {
     /// Writes a value of this type to the provided stream.
     ///
     /// This method is called by [`ByteWriter::write()`](super::ByteWriter::write) to serialize values.
@@ -281,7 +365,7 @@ pub trait Writable: Sized {
     /// # Type Parameters
     ///
     /// - `T`: The underlying buffer type that must support both reading and
-    ///        writing operations through `AsRef<[u8]>` and `AsMut<[u8]>`
+    ///   writing operations through `AsRef<[u8]>` and `AsMut<[u8]>`
     ///
     /// # Returns
     ///
@@ -538,6 +622,37 @@ impl<T: Writable> Writable for Vec<T> {
     /// assert_eq!(&buffer[4..7], &[1, 2, 3]);
     /// ```
     fn write<U>(mut s: WriteStream<U>, val: &Self) -> Result<()>
+    where
+        U: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<[T]>(val)
+    }
+}
+
+impl<T: Writable> Writable for [T] {
+    /// Writes a slice with length prefix and element data.
+    ///
+    /// The format is the same as for `Vec<T>`, so slices can be written
+    /// without copying them into a vector.
+    ///
+    /// # Error Handling
+    ///
+    /// - Returns [`Error::NotValid`] if slice length exceeds `u32::MAX`
+    /// - Propagates errors from element writing immediately
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 6];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let data: [u16; 4] = [1, 2, 3, 4];
+    /// writer.write(&data[1..2]).unwrap();
+    /// assert_eq!(buffer, [1, 0, 0, 0, 2, 0]);
+    /// ```
+    fn write<U>(mut s: WriteStream<U>, val: &Self) -> Result<()>
     where
         U: AsRef<[u8]> + AsMut<[u8]>,
     {
@@ -584,6 +699,34 @@ impl Writable for String {
     /// // Next 4 bytes: UTF-8 encoding of 🦀
     /// ```
     fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<str>(val)
+    }
+}
+
+impl Writable for str {
+    /// Writes a string slice with length prefix and UTF-8 encoded data.
+    ///
+    /// The format is the same as for `String`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if string length exceeds `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 6];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// writer.write("hi").unwrap();
+    /// assert_eq!(buffer, [2, 0, 0, 0, b'h', b'i']);
+    /// ```
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
     where
         T: AsRef<[u8]> + AsMut<[u8]>,
     {
@@ -625,6 +768,22 @@ impl Writable for CString {
     /// assert_eq!(&buffer[..5], &[b'T', b'e', b's', b't', b'\0']);
     /// ```
     fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<CStr>(val)
+    }
+}
+
+impl Writable for CStr {
+    /// Writes a C string slice including its null terminator.
+    ///
+    /// The format is the same as for `CString`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if string length exceeds `u32::MAX - 1`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
     where
         T: AsRef<[u8]> + AsMut<[u8]>,
     {
@@ -632,6 +791,740 @@ impl Writable for CString {
             return Err(Error::NotValid);
         }
 
-        s.write_exact(val.as_bytes_with_nul())
+        s.write_exact(val.to_bytes_with_nul())
+    }
+}
+
+impl Writable for Path {
+    /// Writes a path with length prefix and its encoded bytes.
+    ///
+    /// The bytes are the platform encoding of the underlying `OsStr`: raw
+    /// bytes on Unix and WTF-8 on Windows. Valid UTF-8 paths have the same
+    /// format as `String`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the encoded length exceeds `u32::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 7];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// writer.write(Path::new("a/b")).unwrap();
+    /// assert_eq!(buffer, [3, 0, 0, 0, b'a', b'/', b'b']);
+    /// ```
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        let bytes: &[u8] = val.as_os_str().as_encoded_bytes();
+
+        if bytes.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(bytes.len() as u32))?;
+        s.write_exact(bytes)
+    }
+}
+
+impl Writable for PathBuf {
+    /// Writes a path with length prefix, see [`Path`] implementation.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<Path>(val)
+    }
+}
+
+impl<U: Writable + ?Sized> Writable for &U {
+    /// Writes the referenced value using its `Writable` implementation.
+    ///
+    /// Allows collections of references, such as `Vec<&str>`, to be written.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<U>(val)
+    }
+}
+
+/// A trait for types that need external arguments to be written to a binary stream.
+///
+/// The writing counterpart of [`ReadableWith`](crate::readable::ReadableWith).
+/// Works like [`Writable`], but receives additional arguments of type `Args`
+/// from the caller through [ByteWriter::write_with()](crate::writer::ByteWriter::write_with).
+///
+/// Unlike `Writable`, this trait does not require `Sized`, so it can be
+/// implemented for slices. Every `Writable` type implements `WritableWith<()>`.
+///
+/// # Type Parameters
+///
+/// - `Args`: The type of arguments required for writing
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{
+///     writer::{ByteWriter, WriteStream},
+///     writable::WritableWith,
+///     error::Result
+/// };
+///
+/// struct Offset(u64);
+///
+/// impl WritableWith<u8> for Offset {
+///     fn write_with<T>(mut s: WriteStream<T>, val: &Self, pointer_size: u8) -> Result<()>
+///     where
+///         T: AsRef<[u8]> + AsMut<[u8]>,
+///     {
+///         match pointer_size {
+///             4 => s.write(&(val.0 as u32)),
+///             _ => s.write(&val.0),
+///         }
+///     }
+/// }
+///
+/// let mut buffer = [0u8; 12];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+/// writer.write_with(&Offset(1), 4).unwrap();
+/// writer.write_with(&Offset(2), 8).unwrap();
+/// assert_eq!(writer.position(), 12);
+/// ```
+pub trait WritableWith<Args> {
+    /// Writes a value of this type to the provided stream using `args`.
+    ///
+    /// This method is called by [`ByteWriter::write_with()`](super::ByteWriter::write_with)
+    /// to serialize values.
+    ///
+    /// # Parameters
+    ///
+    /// - `stream`: A [`WriteStream`] providing access to the binary buffer
+    /// - `val`: A reference to the value to be written
+    /// - `args`: The arguments required for writing
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the value was successfully written
+    /// - An error if serialization fails or buffer space is insufficient
+    fn write_with<T>(stream: WriteStream<T>, val: &Self, args: Args) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>;
+}
+
+impl<W: Writable + ?Sized> WritableWith<()> for W {
+    /// Writes the value with its [`Writable`] implementation.
+    fn write_with<T>(mut s: WriteStream<T>, val: &Self, _: ()) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(val)
+    }
+}
+
+impl<W: Writable> WritableWith<usize> for [W] {
+    /// Writes exactly `count` elements without a length prefix.
+    ///
+    /// # Error Handling
+    ///
+    /// - Returns [`Error::NotValid`] if the slice length differs from `count`
+    /// - Propagates errors from element writing immediately
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 4];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let bytes: &[u8] = b"abc";
+    /// writer.write(&(bytes.len() as u8)).unwrap();
+    /// writer.write_with(bytes, bytes.len()).unwrap();
+    /// assert_eq!(&buffer, b"\x03abc");
+    /// ```
+    fn write_with<T>(mut s: WriteStream<T>, val: &Self, count: usize) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() != count {
+            return Err(Error::NotValid);
+        }
+
+        for val in val {
+            s.write(val)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Writable> WritableWith<usize> for Vec<W> {
+    /// Writes exactly `count` elements without a length prefix.
+    ///
+    /// Returns [`Error::NotValid`] if the vector length differs from `count`.
+    fn write_with<T>(s: WriteStream<T>, val: &Self, count: usize) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        <[W]>::write_with(s, val.as_slice(), count)
+    }
+}
+
+impl Writable for () {
+    /// Writes nothing. The stream position is not changed.
+    fn write<T>(_: WriteStream<T>, _: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        Ok(())
+    }
+}
+
+impl<U: ?Sized> Writable for PhantomData<U> {
+    /// Writes nothing. The stream position is not changed.
+    fn write<T>(_: WriteStream<T>, _: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        Ok(())
+    }
+}
+
+impl<U: Writable> Writable for Option<U> {
+    /// Writes an optional value with a tag byte.
+    ///
+    /// # Process
+    /// 1. Writes `0u8` for `None`, or `1u8` for `Some`
+    /// 2. Writes the contained value using its `Writable` implementation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0xFFu8; 3];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// writer.write(&None::<u8>).unwrap();
+    /// writer.write(&Some(42u8)).unwrap();
+    /// assert_eq!(buffer, [0x00, 0x01, 0x2A]);
+    /// ```
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        match val {
+            None => s.write(&0u8),
+            Some(val) => {
+                s.write(&1u8)?;
+                s.write(val)
+            }
+        }
+    }
+}
+
+impl<U: Writable + ?Sized> Writable for Box<U> {
+    /// Writes the boxed value using its `Writable` implementation.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<U>(val)
+    }
+}
+
+impl<U: Writable + ?Sized> Writable for Rc<U> {
+    /// Writes the shared value using its `Writable` implementation.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<U>(val)
     }
 }
+
+impl<U: Writable + ?Sized> Writable for Arc<U> {
+    /// Writes the shared value using its `Writable` implementation.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write::<U>(val)
+    }
+}
+
+macro_rules! impl_non_zero {
+    ($Type:ty) => {
+        impl Writable for $Type {
+            /// Writes the underlying number.
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.write(&val.get())
+            }
+        }
+    };
+}
+
+impl_non_zero!(NonZeroU8);
+impl_non_zero!(NonZeroU16);
+impl_non_zero!(NonZeroU32);
+impl_non_zero!(NonZeroU64);
+impl_non_zero!(NonZeroU128);
+impl_non_zero!(NonZeroUsize);
+impl_non_zero!(NonZeroI8);
+impl_non_zero!(NonZeroI16);
+impl_non_zero!(NonZeroI32);
+impl_non_zero!(NonZeroI64);
+impl_non_zero!(NonZeroI128);
+impl_non_zero!(NonZeroIsize);
+
+impl<U: Writable> Writable for Wrapping<U> {
+    /// Writes the underlying value using its `Writable` implementation.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(&val.0)
+    }
+}
+
+impl Writable for Cow<'_, [u8]> {
+    /// Writes a byte slice with length prefix.
+    ///
+    /// Uses the same format as `Vec<u8>`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        s.write_exact(val)
+    }
+}
+
+impl Writable for Cow<'_, str> {
+    /// Writes a string with length prefix and UTF-8 encoded data.
+    ///
+    /// Uses the same format as `String`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        s.write_exact(val.as_bytes())
+    }
+}
+
+impl<U: Writable> Writable for Range<U> {
+    /// Writes the `start` and then the `end` bound of the range.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(&val.start)?;
+        s.write(&val.end)
+    }
+}
+
+impl Writable for Ipv4Addr {
+    /// Writes the 4 octets of the address.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_exact(&val.octets())
+    }
+}
+
+impl Writable for Ipv6Addr {
+    /// Writes the 16 octets of the address.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write_exact(&val.octets())
+    }
+}
+
+impl Writable for IpAddr {
+    /// Writes a version byte, `4` or `6`, and then the address.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        match val {
+            IpAddr::V4(ip) => s.write(&(4u8, ip)),
+            IpAddr::V6(ip) => s.write(&(6u8, ip)),
+        }
+    }
+}
+
+impl Writable for SocketAddrV4 {
+    /// Writes the address and then the port in network byte order.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(val.ip())?;
+        s.write_exact(&val.port().to_be_bytes())
+    }
+}
+
+impl Writable for SocketAddrV6 {
+    /// Writes the address and then the port in network byte order.
+    ///
+    /// The flow information and the scope identifier are not written.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(val.ip())?;
+        s.write_exact(&val.port().to_be_bytes())
+    }
+}
+
+impl Writable for SocketAddr {
+    /// Writes a version byte, `4` or `6`, and then the socket address.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        match val {
+            SocketAddr::V4(addr) => s.write(&(4u8, addr)),
+            SocketAddr::V6(addr) => s.write(&(6u8, addr)),
+        }
+    }
+}
+
+impl Writable for Duration {
+    /// Writes the seconds as `u64` and then the subsecond nanoseconds as `u32`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        s.write(&val.as_secs())?;
+        s.write(&val.subsec_nanos())
+    }
+}
+
+impl<U: Writable> Writable for VecDeque<U> {
+    /// Writes a double-ended queue with length prefix and element data.
+    ///
+    /// The format is the same as for `Vec<T>`.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        for val in val {
+            s.write(val)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Writable, V: Writable, S> Writable for HashMap<K, V, S> {
+    /// Writes a map with entry count prefix and entry data.
+    ///
+    /// # Process
+    /// 1. Validates map length fits in `u32`
+    /// 2. Writes length as `u32`
+    /// 3. Writes each entry as a key followed by its value
+    ///
+    /// Entries are written in the iteration order of the map, which is not
+    /// deterministic. Use [`write_with()`](super::ByteWriter::write_with) with
+    /// [`SortedKeys`] to get a reproducible output.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        for (key, value) in val {
+            s.write(key)?;
+            s.write(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Writable, V: Writable> Writable for BTreeMap<K, V> {
+    /// Writes an ordered map with entry count prefix and entry data.
+    ///
+    /// The format is the same as for `HashMap<K, V>`. Entries are written in
+    /// the ascending order of the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::BTreeMap;
+    /// use bytecraft::writer::ByteWriter;
+    ///
+    /// let mut buffer = [0u8; 8];
+    /// let mut writer = ByteWriter::new(&mut buffer[..]);
+    ///
+    /// let map: BTreeMap<u8, u8> = BTreeMap::from([(2, 20), (1, 10)]);
+    /// writer.write(&map).unwrap();
+    /// assert_eq!(buffer, [2, 0, 0, 0, 1, 10, 2, 20]);
+    /// ```
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        for (key, value) in val {
+            s.write(key)?;
+            s.write(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<U: Writable, S> Writable for HashSet<U, S> {
+    /// Writes a set with length prefix and element data.
+    ///
+    /// The format is the same as for `Vec<T>`. Elements are written in the
+    /// iteration order of the set, which is not deterministic. Use
+    /// [`write_with()`](super::ByteWriter::write_with) with [`SortedKeys`]
+    /// to get a reproducible output.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        for val in val {
+            s.write(val)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<U: Writable> Writable for BTreeSet<U> {
+    /// Writes an ordered set with length prefix and element data.
+    ///
+    /// The format is the same as for `Vec<T>`. Elements are written in
+    /// ascending order.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        s.write(&(val.len() as u32))?;
+        for val in val {
+            s.write(val)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Argument for writing hashed collections in a deterministic order.
+///
+/// `HashMap` and `HashSet` are written in their iteration order, which
+/// depends on the hasher state. Passing `SortedKeys` to
+/// [`ByteWriter::write_with()`](super::ByteWriter::write_with) writes the
+/// entries in ascending key order instead, so equal collections always
+/// produce the same bytes. The format is the same as with `write()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use bytecraft::{writable::SortedKeys, writer::ByteWriter};
+///
+/// let mut buffer = [0u8; 10];
+/// let mut writer = ByteWriter::new(&mut buffer[..]);
+///
+/// let map: HashMap<u8, u8> = HashMap::from([(3, 30), (1, 10), (2, 20)]);
+/// writer.write_with(&map, SortedKeys).unwrap();
+/// assert_eq!(buffer, [3, 0, 0, 0, 1, 10, 2, 20, 3, 30]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SortedKeys;
+
+impl<K: Writable + Ord, V: Writable, S> WritableWith<SortedKeys> for HashMap<K, V, S> {
+    /// Writes the map entries in ascending key order.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write_with<T>(mut s: WriteStream<T>, val: &Self, _: SortedKeys) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        let mut entries: Vec<(&K, &V)> = val.iter().collect();
+        entries.sort_unstable_by(|a, b| a.0.cmp(b.0));
+
+        s.write(&(entries.len() as u32))?;
+        for (key, value) in entries {
+            s.write(key)?;
+            s.write(value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<U: Writable + Ord, S> WritableWith<SortedKeys> for HashSet<U, S> {
+    /// Writes the set elements in ascending order.
+    ///
+    /// # Error Handling
+    ///
+    /// Returns [`Error::NotValid`] if the length exceeds `u32::MAX`.
+    fn write_with<T>(mut s: WriteStream<T>, val: &Self, _: SortedKeys) -> Result<()>
+    where
+        T: AsRef<[u8]> + AsMut<[u8]>,
+    {
+        if val.len() > u32::MAX as usize {
+            return Err(Error::NotValid);
+        }
+
+        let mut elements: Vec<&U> = val.iter().collect();
+        elements.sort_unstable();
+
+        s.write(&(elements.len() as u32))?;
+        for val in elements {
+            s.write(val)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An object-safe version of [`Writable`] for heterogeneous collections.
+///
+/// `Writable::write` is generic over the buffer type of the writer, so
+/// `dyn Writable` cannot exist. `DynWritable` writes into a [`DynWriter`]
+/// instead, which any [`ByteWriter`](super::ByteWriter) can provide through
+/// [`write_dyn()`](super::ByteWriter::write_dyn).
+///
+/// The trait is implemented for every `Writable` type, and `dyn DynWritable`
+/// implements `Writable` itself, so `Box<dyn DynWritable>` and
+/// `Vec<Box<dyn DynWritable>>` are written with the usual
+/// [`write()`](super::ByteWriter::write) method.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytecraft::{writable::DynWritable, writer::ByteWriter, common::Endian};
+///
+/// let message: Vec<Box<dyn DynWritable>> = vec![
+///     Box::new(0x01u8),
+///     Box::new(0x0203u16),
+///     Box::new("ab".to_string()),
+/// ];
+///
+/// let mut buffer = [0u8; 13];
+/// let mut writer = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+///
+/// writer.write(&message).unwrap();
+/// assert_eq!(buffer, [0, 0, 0, 3, 0x01, 0x02, 0x03, 0, 0, 0, 2, b'a', b'b']);
+/// ```
+pub trait DynWritable {
+    /// Writes this value to the provided type-erased writer.
+    ///
+    /// # Parameters
+    ///
+    /// - `writer`: The [`DynWriter`] to write into
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the value was successfully written
+    /// - An error if serialization fails or buffer space is insufficient
+    fn write_dyn(&self, writer: &mut DynWriter<'_>) -> Result<()>;
+}
+
+impl<W: Writable> DynWritable for W {
+    /// Writes the value with its [`Writable`] implementation.
+    fn write_dyn(&self, writer: &mut DynWriter<'_>) -> Result<()> {
+        writer.write(self)
+    }
+}
+
+macro_rules! impl_dyn_writable {
+    ($Type:ty) => {
+        impl Writable for $Type {
+            /// Writes the trait object with [`DynWritable::write_dyn()`].
+            fn write<T>(mut s: WriteStream<T>, val: &Self) -> Result<()>
+            where
+                T: AsRef<[u8]> + AsMut<[u8]>,
+            {
+                s.write_dyn(val)
+            }
+        }
+    };
+}
+
+impl_dyn_writable!(dyn DynWritable + '_);
+impl_dyn_writable!(dyn DynWritable + Send + '_);
+impl_dyn_writable!(dyn DynWritable + Send + Sync + '_);
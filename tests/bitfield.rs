@@ -0,0 +1,99 @@
+use bytecraft::bitfield;
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+bitfield! {
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct Register(u32) {
+        pub mode, set_mode: u8 [0..3],
+        pub enabled, set_enabled: bool [3],
+        pub counter, set_counter: u16 [8..20],
+        pub channel, set_channel: u8 [24..32],
+    }
+}
+
+bitfield! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Full(u8) {
+        low, set_low: u8 [0..4],
+        high, set_high: u8 [4..8],
+    }
+}
+
+#[test]
+fn accessors() {
+    let mut reg: Register = Register::default();
+    reg.set_mode(5);
+    reg.set_enabled(true);
+    reg.set_counter(0xABC);
+    reg.set_channel(0xFE);
+    assert_eq!(reg.bits(), 0xFE0A_BC0D);
+    assert_eq!(u32::from(reg), 0xFE0A_BC0D);
+
+    assert_eq!(reg.mode(), 5);
+    assert!(reg.enabled());
+    assert_eq!(reg.counter(), 0xABC);
+    assert_eq!(reg.channel(), 0xFE);
+
+    // Values wider than the field are truncated
+    reg.set_mode(0xFF);
+    assert_eq!(reg.mode(), 7);
+    assert!(reg.enabled());
+    reg.set_enabled(false);
+    assert_eq!(reg.bits(), 0xFE0A_BC07);
+
+    assert_eq!(Register::RESERVED, 0x00F0_00F0);
+    assert_eq!(Full::RESERVED, 0);
+
+    let mut full: Full = Full::from_bits_retain(0x5A);
+    assert_eq!((full.low(), full.high()), (0xA, 0x5));
+    full.set_high(0x1);
+    assert_eq!(full.bits(), 0x1A);
+}
+
+#[test]
+fn reserved_bits() {
+    assert!(Register::from_bits(0xFF0F_FF0F).is_ok());
+    assert!(matches!(
+        Register::from_bits(0x0000_0010),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        Register::from_bits(0x0010_0000),
+        Err(Error::NotValid)
+    ));
+
+    let retained: Register = Register::from_bits_retain(0x0010_0000);
+    assert_eq!(retained.bits(), 0x0010_0000);
+    assert_eq!(retained.counter(), 0);
+}
+
+#[test]
+fn read_write() -> Result<()> {
+    let mut reg: Register = Register::default();
+    reg.set_counter(0x123);
+    reg.set_channel(0x45);
+
+    let mut buffer: [u8; 5] = [0u8; 5];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&reg)?;
+    writer.write(&Full::from_bits_retain(0x99))?;
+    assert_eq!(buffer, [0x45, 0x01, 0x23, 0x00, 0x99]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<Register>()?, reg);
+    assert_eq!(reader.read::<Register>()?, reg);
+    assert_eq!(reader.read::<Full>()?.high(), 0x9);
+
+    // Same bits with another byte order
+    let mut reader: ByteReader = ByteReader::with_endian(&[0x00, 0x23, 0x01, 0x45], Endian::Little);
+    assert_eq!(reader.read::<Register>()?, reg);
+
+    // Reserved bits are checked on read and peek
+    let reader: ByteReader = ByteReader::with_endian(&[0x00, 0x00, 0x00, 0x10], Endian::Big);
+    assert!(matches!(reader.peek::<Register>(), Err(Error::NotValid)));
+
+    Ok(())
+}
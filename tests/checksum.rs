@@ -0,0 +1,144 @@
+use bytecraft::checksum::{
+    Adler32, Checksum, ChecksumMismatch, Crc16Arc, Crc16CcittFalse, Crc16Kermit, Crc16Modbus,
+    Crc16Xmodem, Crc32, Crc32c, Fletcher16, Fletcher32, InternetChecksum,
+};
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+const CHECK: &[u8] = b"123456789";
+
+/// Feeds the data in chunks of every size to test incremental updates.
+fn chunked<C: Checksum>(bytes: &[u8]) -> Vec<C::Output> {
+    (1..=bytes.len())
+        .map(|size| {
+            let mut checksum: C = C::default();
+            bytes.chunks(size).for_each(|chunk| checksum.update(chunk));
+            checksum.finish()
+        })
+        .collect()
+}
+
+fn check<C: Checksum>(bytes: &[u8], expected: C::Output)
+where
+    C::Output: std::fmt::Debug,
+{
+    assert_eq!(C::checksum(bytes), expected);
+    assert!(chunked::<C>(bytes).iter().all(|value| *value == expected));
+}
+
+#[test]
+fn check_values() {
+    check::<Crc32>(CHECK, 0xCBF4_3926);
+    check::<Crc32c>(CHECK, 0xE306_9283);
+    check::<Crc16Arc>(CHECK, 0xBB3D);
+    check::<Crc16Modbus>(CHECK, 0x4B37);
+    check::<Crc16Kermit>(CHECK, 0x2189);
+    check::<Crc16CcittFalse>(CHECK, 0x29B1);
+    check::<Crc16Xmodem>(CHECK, 0x31C3);
+    check::<Adler32>(b"Wikipedia", 0x11E6_0398);
+    check::<Fletcher16>(b"abcde", 0xC8F0);
+    check::<Fletcher16>(b"abcdef", 0x2057);
+    check::<Fletcher32>(b"abcde", 0xF04F_C729);
+    check::<Fletcher32>(b"abcdef", 0x5650_2D2A);
+    check::<InternetChecksum>(&[0x00, 0x01, 0xF2, 0x03, 0xF4, 0xF5, 0xF6, 0xF7], 0x220D);
+    check::<InternetChecksum>(&[0x00, 0x01, 0xF2], 0x0DFE);
+
+    assert_eq!(Crc32::checksum(&[]), 0);
+    assert_eq!(Adler32::checksum(&[]), 1);
+    assert_eq!(InternetChecksum::checksum(&[]), 0xFFFF);
+}
+
+#[test]
+fn internet_checksum_ipv4_header() -> Result<()> {
+    let header: [u8; 20] = [
+        0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xB8, 0x61, 0xC0, 0xA8, 0x00,
+        0x01, 0xC0, 0xA8, 0x00, 0xC7,
+    ];
+
+    assert_eq!(InternetChecksum::checksum(&header), 0);
+
+    let mut zeroed: [u8; 20] = header;
+    zeroed[10..12].fill(0);
+    assert_eq!(InternetChecksum::checksum(&zeroed), 0xB861);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&zeroed, Endian::Big);
+    let ((), checksum) = reader.digest::<InternetChecksum, _>(|s| s.skip(20))?;
+    assert_eq!(checksum, 0xB861);
+
+    Ok(())
+}
+
+#[test]
+fn digest_scope() -> Result<()> {
+    let data: &[u8] = b"xx123456789yy";
+    let mut reader: ByteReader = ByteReader::new(data);
+
+    reader.skip(2)?;
+    let (text, crc) = reader.digest::<Crc32, _>(|s| s.read_ascii(9))?;
+    assert_eq!((text, crc), ("123456789", 0xCBF4_3926));
+    assert_eq!(reader.position(), 11);
+
+    let (nested, outer) =
+        reader.digest::<Crc16Arc, _>(|s| s.digest::<Crc16Arc, _>(|s| s.read::<u8>()))?;
+    assert_eq!(nested, (b'y', Crc16Arc::checksum(b"y")));
+    assert_eq!(outer, Crc16Arc::checksum(b"y"));
+
+    let result = reader.digest::<Crc32, _>(|s| s.read::<u64>());
+    assert!(matches!(result, Err(Error::InsufficientData { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn write_and_verify() -> Result<()> {
+    let mut buffer: [u8; 20] = [0u8; 20];
+
+    for endian in [Endian::Little, Endian::Big] {
+        let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], endian);
+
+        writer.write_checksum::<Crc32, _>(|s| s.write(&0x0102_0304u32))?;
+        let count: usize = writer.write_checksum::<Fletcher16, _>(|s| {
+            s.write_exact(b"abcde")?;
+            Ok(5)
+        })?;
+        assert_eq!(count, 5);
+        assert_eq!(writer.position(), 15);
+
+        let mut reader: ByteReader = ByteReader::with_endian(&buffer, endian);
+        assert_eq!(reader.verify::<Crc32, _>(|s| s.read::<u32>())?, 0x0102_0304);
+        assert_eq!(
+            reader.verify::<Fletcher16, _>(|s| s.read_exact(5))?,
+            b"abcde"
+        );
+        assert_eq!(reader.position(), 15);
+    }
+
+    assert_eq!(buffer[13..15], [0xC8, 0xF0]);
+
+    buffer[0] ^= 0x80;
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    match reader.verify::<Crc32, _>(|s| s.read::<u32>()) {
+        Err(Error::Custom(err)) => {
+            let err: &ChecksumMismatch = err.downcast_ref().unwrap();
+            assert_eq!(err.actual(), Crc32::checksum(&buffer[..4]) as u64);
+            assert_eq!(
+                err.expected(),
+                u32::from_be_bytes([buffer[4], buffer[5], buffer[6], buffer[7]]) as u64
+            );
+            assert!(err
+                .to_string()
+                .starts_with("Checksum mismatch: expected 0x"));
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    let mut buffer: [u8; 5] = [0u8; 5];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    let result = writer.write_checksum::<Crc32, _>(|s| s.write(&1u16));
+    assert!(matches!(result, Err(Error::InsufficientData { .. })));
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
-use bytecraft::error::Result;
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
 use bytecraft::readable::Readable;
 use bytecraft::reader::{ByteReader, ReadStream};
-use bytecraft::writer::writable::Writable;
+use bytecraft::writer::writable::{SortedKeys, Writable};
 use bytecraft::writer::{ByteWriter, WriteStream};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::ffi::CString;
 
 #[test]
@@ -213,3 +215,112 @@ fn test_collection_position_tracking() -> Result<()> {
     assert_eq!(string2, read_string2);
     Ok(())
 }
+
+#[test]
+fn test_map_roundtrip() -> Result<()> {
+    let hash_map: HashMap<u16, String> =
+        HashMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+    let btree_map: BTreeMap<String, Vec<u8>> =
+        BTreeMap::from([("b".to_string(), vec![2]), ("a".to_string(), vec![1, 1])]);
+
+    let mut buffer: Vec<u8> = vec![0u8; 1024];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&hash_map)?;
+    writer.write(&btree_map)?;
+    let written: usize = writer.position();
+
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    assert_eq!(reader.peek::<HashMap<u16, String>>()?, hash_map);
+    assert_eq!(reader.read::<HashMap<u16, String>>()?, hash_map);
+    assert_eq!(reader.read::<BTreeMap<String, Vec<u8>>>()?, btree_map);
+    assert_eq!(reader.position(), written);
+
+    Ok(())
+}
+
+#[test]
+fn test_set_roundtrip() -> Result<()> {
+    let hash_set: HashSet<u32> = HashSet::from([10, 20, 30]);
+    let btree_set: BTreeSet<i8> = BTreeSet::from([3, -1, 2]);
+    let deque: VecDeque<u16> = VecDeque::from([5, 6, 7]);
+
+    let mut buffer: Vec<u8> = vec![0u8; 1024];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&hash_set)?;
+    writer.write(&btree_set)?;
+    writer.write(&deque)?;
+
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    assert_eq!(reader.read::<HashSet<u32>>()?, hash_set);
+    assert_eq!(reader.peek::<BTreeSet<i8>>()?, btree_set);
+    assert_eq!(reader.read::<Vec<i8>>()?, vec![-1, 2, 3]);
+    assert_eq!(reader.read::<VecDeque<u16>>()?, deque);
+
+    Ok(())
+}
+
+#[test]
+fn test_map_sorted_keys() -> Result<()> {
+    let map: HashMap<u32, u8> = (0..64).map(|i| (i * 7 % 64, i as u8)).collect();
+    let set: HashSet<u8> = (0..64).rev().collect();
+
+    let mut sorted: Vec<u8> = vec![0u8; 1024];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut sorted[..], Endian::Big);
+    writer.write_with(&map, SortedKeys)?;
+    writer.write_with(&set, SortedKeys)?;
+
+    // Same bytes as the ordered collections
+    let mut expected: Vec<u8> = vec![0u8; 1024];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut expected[..], Endian::Big);
+    writer.write(&map.clone().into_iter().collect::<BTreeMap<_, _>>())?;
+    writer.write(&set.clone().into_iter().collect::<BTreeSet<_>>())?;
+    assert_eq!(sorted, expected);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&sorted, Endian::Big);
+    assert_eq!(reader.read::<HashMap<u32, u8>>()?, map);
+    assert_eq!(reader.read::<HashSet<u8>>()?, set);
+
+    Ok(())
+}
+
+#[test]
+fn test_duplicate_keys() {
+    let data: [u8; 8] = [2, 0, 0, 0, 1, 10, 1, 20];
+
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(matches!(
+        reader.read::<HashMap<u8, u8>>(),
+        Err(Error::NotValid)
+    ));
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(matches!(
+        reader.read::<BTreeMap<u8, u8>>(),
+        Err(Error::NotValid)
+    ));
+
+    let data: [u8; 6] = [2, 0, 0, 0, 5, 5];
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(matches!(reader.read::<HashSet<u8>>(), Err(Error::NotValid)));
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(matches!(
+        reader.try_read::<BTreeSet<u8>>(),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn test_collection_huge_size() {
+    let data: [u8; 4] = [0xFF, 0xFF, 0xFF, 0x7F];
+
+    // The size prefix alone does not preallocate memory
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    assert!(matches!(
+        reader.read::<HashMap<u64, u64>>(),
+        Err(Error::InsufficientData { .. })
+    ));
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    assert!(reader.read::<HashSet<u64>>().is_err());
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    assert!(reader.read::<VecDeque<u64>>().is_err());
+}
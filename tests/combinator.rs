@@ -0,0 +1,197 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::readable::Readable;
+use bytecraft::reader::combinator::{
+    count, many0, many1, map, read, repeat_until, separated, tag, terminated_by, verify,
+};
+use bytecraft::reader::{ByteReader, ReadStream};
+
+#[test]
+fn tag_and_read() -> Result<()> {
+    let data: &[u8] = b"MAGIC\x01\x02";
+    let mut reader: ByteReader = ByteReader::with_endian(data, Endian::Big);
+
+    assert!(matches!(reader.parse(tag(b"MAGIK")), Err(Error::NotValid)));
+    assert!(matches!(
+        reader.parse(tag(b"MAGIC\x01\x02\x03")),
+        Err(Error::InsufficientData {
+            requested: 8,
+            available: 7
+        })
+    ));
+    assert_eq!(reader.position(), 0);
+
+    assert_eq!(reader.parse(tag(b"MAGIC"))?, b"MAGIC");
+    assert_eq!(reader.parse(read::<u16>())?, 0x0102);
+    assert!(reader.is_eof());
+
+    Ok(())
+}
+
+#[test]
+fn repetitions() -> Result<()> {
+    let data: [u8; 6] = [0x01, 0x01, 0x00, 0x05, 0x06, 0x07];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let flags: Vec<bool> = reader.parse(many0(read::<bool>()))?;
+    assert_eq!(flags, vec![true, true, false]);
+    assert_eq!(reader.position(), 3);
+
+    assert!(reader.parse(many1(read::<bool>())).is_err());
+    assert_eq!(reader.position(), 3);
+    assert!(reader.parse(many0(read::<bool>()))?.is_empty());
+
+    let items: Vec<u8> = reader.parse(count(read::<u8>(), 2))?;
+    assert_eq!(items, vec![0x05, 0x06]);
+
+    assert!(matches!(
+        reader.parse(count(read::<u8>(), 2)),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn many_stops_on_empty_item() -> Result<()> {
+    let data: [u8; 2] = [0x01, 0x02];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let empty: Vec<()> = reader.parse(many0(|_: &mut ReadStream<'_, '_>| Ok(())))?;
+    assert_eq!(empty.len(), 1);
+    assert_eq!(reader.position(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn loops_reject_empty_item() -> Result<()> {
+    let data: [u8; 2] = [0x01, 0x02];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    assert!(matches!(
+        reader.parse(repeat_until(read::<()>(), |_| false)),
+        Err(Error::NotValid)
+    ));
+    let units: Vec<()> = reader.parse(repeat_until(read::<()>(), |_| true))?;
+    assert_eq!(units.len(), 1);
+
+    assert!(matches!(
+        reader.parse(terminated_by(many0(tag(b"\x00")), b"\0")),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        reader.parse(terminated_by(read::<()>(), b"\0")),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(reader.position(), 0);
+
+    let items: Vec<()> = reader.parse(separated(read::<()>(), read::<()>()))?;
+    assert_eq!(items.len(), 2);
+    assert_eq!(reader.position(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn delimited() -> Result<()> {
+    let data: &[u8] = b"1,2,3;4,;abc\0rest";
+    let mut reader: ByteReader = ByteReader::new(data);
+
+    let items: Vec<u8> = reader.parse(separated(read::<u8>(), tag(b",")))?;
+    assert_eq!(items, b"123");
+    reader.parse(tag(b";"))?;
+
+    // Separator must be followed by an item
+    let digit = || verify(read::<u8>(), |v: &u8| v.is_ascii_digit());
+    assert!(matches!(
+        reader.parse(separated(digit(), tag(b","))),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(reader.rest_bytes(), b";abc\0rest");
+    reader.skip(1)?;
+
+    let name: Vec<u8> = reader.parse(terminated_by(read::<u8>(), b"\0"))?;
+    assert_eq!(name, b"abc");
+    assert_eq!(reader.rest_bytes(), b"rest");
+
+    assert!(matches!(
+        reader.parse(terminated_by(read::<u8>(), b"\0")),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    let mut reader: ByteReader = ByteReader::new(b"");
+    assert!(reader.parse(separated(read::<u8>(), tag(b",")))?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn repeat_until_last() -> Result<()> {
+    let data: [u8; 6] = [0x00, 0x0A, 0x01, 0x0B, 0x00, 0x0C];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let records: Vec<(bool, u8)> =
+        reader.parse(repeat_until(read::<(bool, u8)>(), |record| record.0))?;
+    assert_eq!(records, vec![(false, 0x0A), (true, 0x0B)]);
+    assert_eq!(reader.position(), 4);
+
+    assert!(reader
+        .parse(repeat_until(read::<(bool, u8)>(), |record| record.0))
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn map_and_verify() -> Result<()> {
+    let data: [u8; 3] = [0x01, 0x02, 0xFF];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let doubled: Vec<u16> = reader.parse(many0(map(verify(read::<u8>(), |v| *v < 0x10), |v| {
+        v as u16 * 2
+    })))?;
+    assert_eq!(doubled, vec![2, 4]);
+
+    assert!(matches!(
+        reader.parse(verify(read::<u8>(), |v| *v < 0x10)),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(reader.position(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn nested_in_readable() -> Result<()> {
+    #[derive(Debug, PartialEq)]
+    struct Table<'a> {
+        name: &'a [u8],
+        rows: Vec<(u8, u8)>,
+    }
+
+    impl<'a> Readable<'a> for Table<'a> {
+        fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+            s.parse(tag(b"TBL"))?;
+            let len: u8 = s.read()?;
+            let name: &'a [u8] = s.read_exact(len as usize)?;
+            let rows: Vec<(u8, u8)> = s.parse(terminated_by(read::<(u8, u8)>(), &[0xFF]))?;
+
+            Ok(Table { name, rows })
+        }
+    }
+
+    let data: &[u8] = b"TBL\x02ab\x01\x02\x03\x04\xFF";
+    let mut reader: ByteReader = ByteReader::new(data);
+
+    let table: Table = reader.read()?;
+    assert_eq!(
+        table,
+        Table {
+            name: b"ab",
+            rows: vec![(1, 2), (3, 4)]
+        }
+    );
+
+    Ok(())
+}
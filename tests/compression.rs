@@ -0,0 +1,365 @@
+use bytecraft::checksum::{Checksum, Crc32};
+use bytecraft::common::Endian;
+use bytecraft::compression::{Codec, LimitExceeded, Lz4Block, Rle, DEFAULT_LIMIT};
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::owned::OwnedReader;
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+/// Test inputs with runs, repeated phrases and noise.
+fn samples() -> Vec<Vec<u8>> {
+    let mut noise: Vec<u8> = Vec::new();
+    let mut state: u32 = 0x1234_5678;
+    for _ in 0..5000 {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        noise.push((state >> 24) as u8);
+    }
+
+    let mut mixed: Vec<u8> = Vec::new();
+    for index in 0..100u32 {
+        mixed.extend_from_slice(b"record ");
+        mixed.extend_from_slice(&index.to_le_bytes());
+        mixed.extend(core::iter::repeat(index as u8).take(index as usize));
+    }
+
+    vec![
+        vec![],
+        vec![0x42],
+        vec![7, 7],
+        vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+        vec![0u8; 100_000],
+        b"abcabcabcabcabcabcabcabcabcabcabcabcabcabc".to_vec(),
+        noise,
+        mixed,
+    ]
+}
+
+fn limit_exceeded(result: Result<Vec<u8>>) -> usize {
+    match result {
+        Err(Error::Custom(err)) => err.downcast_ref::<LimitExceeded>().unwrap().limit(),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+fn roundtrip<C: Codec>() {
+    for data in samples() {
+        let compressed: Vec<u8> = C::compress(&data).unwrap();
+        assert_eq!(C::decompress(&compressed, data.len()).unwrap(), data);
+
+        if !data.is_empty() {
+            assert_eq!(
+                limit_exceeded(C::decompress(&compressed, data.len() - 1)),
+                data.len() - 1
+            );
+        }
+    }
+}
+
+#[test]
+fn rle() {
+    roundtrip::<Rle>();
+
+    // Apple Technical Note TN1023
+    let data: [u8; 24] = [
+        0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0xAA, 0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0x22, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+    ];
+    let packed: [u8; 15] = [
+        0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A, 0xFD, 0xAA, 0x03, 0x80, 0x00, 0x2A, 0x22, 0xF7, 0xAA,
+    ];
+
+    assert_eq!(Rle::compress(&data).unwrap(), packed);
+    assert_eq!(Rle::decompress(&packed, 24).unwrap(), data);
+    assert_eq!(
+        Rle::decompress(&[0x80, 0x00, 0x01, 0x80], 1).unwrap(),
+        [0x01]
+    );
+    assert_eq!(
+        Rle::compress(&[0u8; 129]).unwrap(),
+        [0x81, 0x00, 0x00, 0x00]
+    );
+
+    assert!(matches!(
+        Rle::decompress(&[0x05, 0x01], 10),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(Rle::decompress(&[0xFE], 10), Err(Error::NotValid)));
+}
+
+#[test]
+fn lz4_block() {
+    roundtrip::<Lz4Block>();
+
+    // Produced by the reference lz4 implementation
+    let block: [u8; 69] = [
+        0xFF, 0x1E, 0x54, 0x68, 0x65, 0x20, 0x71, 0x75, 0x69, 0x63, 0x6B, 0x20, 0x62, 0x72, 0x6F,
+        0x77, 0x6E, 0x20, 0x66, 0x6F, 0x78, 0x20, 0x6A, 0x75, 0x6D, 0x70, 0x73, 0x20, 0x6F, 0x76,
+        0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6C, 0x61, 0x7A, 0x79, 0x20, 0x64, 0x6F, 0x67,
+        0x2E, 0x20, 0x2D, 0x00, 0x74, 0xAB, 0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38,
+        0x39, 0x0A, 0x00, 0x50, 0x35, 0x36, 0x37, 0x38, 0x39,
+    ];
+    let mut expected: Vec<u8> = b"The quick brown fox jumps over the lazy dog. ".repeat(4);
+    expected.extend_from_slice(&b"0123456789".repeat(3));
+
+    assert_eq!(
+        Lz4Block::decompress(&block, DEFAULT_LIMIT).unwrap(),
+        expected
+    );
+    assert_eq!(Lz4Block::compress(&[]).unwrap(), [0x00]);
+
+    // Literals must be followed by a complete offset
+    assert!(matches!(
+        Lz4Block::decompress(&[], 10),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        Lz4Block::decompress(&[0x20, 0x01], 10),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        Lz4Block::decompress(&[0x10, 0x01, 0x01], 10),
+        Err(Error::NotValid)
+    ));
+    // Zero offset and offset before the start of the output
+    assert!(matches!(
+        Lz4Block::decompress(&[0x10, 0x01, 0x00, 0x00], 10),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        Lz4Block::decompress(&[0x10, 0x01, 0x02, 0x00], 10),
+        Err(Error::NotValid)
+    ));
+    // Unterminated length
+    assert!(matches!(
+        Lz4Block::decompress(&[0xF0, 0xFF], 10),
+        Err(Error::NotValid)
+    ));
+}
+
+#[test]
+fn reader_decompress() -> Result<()> {
+    let packed: Vec<u8> = Rle::compress(&[0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02])?;
+    let mut data: Vec<u8> = vec![packed.len() as u8];
+    data.extend_from_slice(&packed);
+    data.push(0xFF);
+
+    let mut reader: ByteReader = ByteReader::with_context(&data, "context");
+    reader.set_endian(Endian::Big);
+
+    let size: u8 = reader.read()?;
+    let chunk: OwnedReader = reader.decompress::<Rle>(size as usize)?;
+    assert_eq!(chunk.len(), 8);
+    assert_eq!(chunk.endian(), Endian::Big);
+
+    let mut inner: ByteReader = chunk.reader();
+    assert_eq!(inner.read::<[u32; 2]>()?, [1, 2]);
+    assert_eq!(inner.context::<&str>(), Some(&"context"));
+    assert_eq!(reader.read::<u8>()?, 0xFF);
+
+    reader.set_position(1)?;
+    let result = reader.decompress_with_limit::<Rle>(size as usize, 7);
+    assert!(matches!(result, Err(Error::Custom(_))));
+    assert_eq!(reader.position(), 1);
+
+    let result = reader.decompress::<Rle>(data.len());
+    assert!(matches!(result, Err(Error::InsufficientData { .. })));
+    assert_eq!(reader.position(), 1);
+
+    // ReadStream delegates
+    let (chunks, crc) = reader.digest::<Crc32, _>(|s| {
+        let chunk: OwnedReader = s.decompress::<Rle>(size as usize)?;
+        let limited: Result<OwnedReader> = s.decompress_with_limit::<Rle>(1, 0);
+        Ok((chunk, limited.is_err()))
+    })?;
+    assert_eq!(chunks.0.into_inner(), [0, 0, 0, 1, 0, 0, 0, 2]);
+    assert!(chunks.1);
+    assert_eq!(crc, Crc32::checksum(&packed[..]));
+
+    Ok(())
+}
+
+#[test]
+fn writer_compressed() -> Result<()> {
+    let values: Vec<u32> = (0..64).map(|index| index / 16).collect();
+    let mut buffer: [u8; 300] = [0u8; 300];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+
+    writer.write(&0xABu8)?;
+    let (count, size) = writer.compressed::<Lz4Block, _>(|s| {
+        values.iter().try_for_each(|value| s.write(value))?;
+        Ok(values.len())
+    })?;
+    assert_eq!(count, 64);
+    assert!(size < 64);
+    assert_eq!(writer.position(), 1 + size);
+
+    // WriteStream delegate
+    let ((), crc) = writer.digest::<Crc32, _>(|s| {
+        let ((), size) = s.compressed::<Rle, _>(|s| s.write_exact(&[0x11; 10]))?;
+        assert_eq!(size, 2);
+        Ok(())
+    })?;
+    assert_eq!(crc, Crc32::checksum(&[0xF7, 0x11]));
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.read::<u8>()?, 0xAB);
+
+    let chunk: OwnedReader = reader.decompress::<Lz4Block>(size)?;
+    let decoded: Vec<u32> = chunk.reader().iter::<u32>().collect::<Result<Vec<u32>>>()?;
+    assert_eq!(decoded, values);
+    assert_eq!(reader.decompress::<Rle>(2)?.as_slice(), [0x11; 10]);
+
+    // The compressed data does not fit, the buffer is not changed
+    let mut buffer: [u8; 4] = [0xEE; 4];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&0xAAu8)?;
+    let result = writer.compressed::<Rle, _>(|s| s.write_exact(&[1, 2, 3, 4]));
+    assert!(matches!(result, Err(Error::InsufficientData { .. })));
+    assert_eq!(writer.position(), 1);
+    assert_eq!(buffer, [0xAA, 0xEE, 0xEE, 0xEE]);
+
+    // Only the compressed data needs to fit
+    let mut buffer: [u8; 16] = [0u8; 16];
+    let mut writer: ByteWriter<_> = ByteWriter::with_context(&mut buffer[..], 7u8);
+    writer.set_endian(Endian::Big);
+    let (context, size) = writer.compressed::<Rle, _>(|s| {
+        assert_eq!(s.get_endian(), Endian::Big);
+        s.write_exact(&[0u8; 64])?;
+        Ok(*s.context::<u8>().unwrap())
+    })?;
+    assert_eq!((context, size), (7, 2));
+    assert_eq!(writer.position(), size);
+
+    // The scratch buffer grows beyond its initial size
+    let data: Vec<u8> = (0..5000u32).map(|index| (index / 100) as u8).collect();
+    let mut buffer: Vec<u8> = vec![0u8; 1024];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    let mut calls: usize = 0;
+    let ((), size) = writer.compressed::<Rle, _>(|s| {
+        calls += 1;
+        data.chunks(100).try_for_each(|chunk| s.write_exact(chunk))
+    })?;
+    assert!(calls > 1);
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    assert_eq!(reader.decompress::<Rle>(size)?.as_slice(), data);
+
+    // Errors not caused by the scratch buffer are returned
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    let mut calls: usize = 0;
+    let result = writer.compressed::<Rle, u32>(|_| {
+        calls += 1;
+        ByteReader::new(&[0x01]).read::<u32>()
+    });
+    assert!(matches!(result, Err(Error::InsufficientData { .. })));
+    assert_eq!((calls, writer.position()), (1, 0));
+
+    Ok(())
+}
+
+#[cfg(feature = "deflate")]
+mod deflate {
+    use super::*;
+    use bytecraft::checksum::ChecksumMismatch;
+    use bytecraft::compression::{Deflate, Gzip, Zlib};
+
+    const HELLO_GZIP: [u8; 37] = [
+        0x1F, 0x8B, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00, 0x02, 0xFF, 0x61, 0x2E, 0x74, 0x78, 0x74,
+        0x00, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x57, 0x28, 0xCF, 0x2F, 0xCA, 0x49, 0x01, 0x00, 0x85,
+        0x11, 0x4A, 0x0D, 0x0B, 0x00, 0x00, 0x00,
+    ];
+
+    const HELLO_ZLIB: [u8; 19] = [
+        0x78, 0x9C, 0xCB, 0x48, 0xCD, 0xC9, 0xC9, 0x57, 0x28, 0xCF, 0x2F, 0xCA, 0x49, 0x01, 0x00,
+        0x1A, 0x0B, 0x04, 0x5D,
+    ];
+
+    #[test]
+    fn roundtrips() {
+        roundtrip::<Deflate>();
+        roundtrip::<Zlib>();
+        roundtrip::<Gzip>();
+    }
+
+    #[test]
+    fn reference_streams() {
+        assert_eq!(Gzip::decompress(&HELLO_GZIP, 11).unwrap(), b"hello world");
+        assert_eq!(Zlib::decompress(&HELLO_ZLIB, 11).unwrap(), b"hello world");
+        assert_eq!(
+            Deflate::decompress(&HELLO_ZLIB[2..15], 11).unwrap(),
+            b"hello world"
+        );
+    }
+
+    #[test]
+    fn corrupted_streams() {
+        let mut stream: [u8; 37] = HELLO_GZIP;
+        stream[29] ^= 0x01;
+        match Gzip::decompress(&stream, 100) {
+            Err(Error::Custom(err)) => {
+                let err: &ChecksumMismatch = err.downcast_ref().unwrap();
+                assert_eq!(err.actual(), Crc32::checksum(b"hello world") as u64);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let mut stream: [u8; 37] = HELLO_GZIP;
+        stream[33] = 12;
+        assert!(matches!(
+            Gzip::decompress(&stream, 100),
+            Err(Error::NotValid)
+        ));
+
+        let mut stream: [u8; 37] = HELLO_GZIP;
+        stream[3] |= 0x80;
+        assert!(matches!(
+            Gzip::decompress(&stream, 100),
+            Err(Error::NotValid)
+        ));
+
+        let mut stream: [u8; 19] = HELLO_ZLIB;
+        stream[18] ^= 0x01;
+        assert!(matches!(
+            Zlib::decompress(&stream, 100),
+            Err(Error::NotValid)
+        ));
+
+        assert!(Gzip::decompress(&HELLO_GZIP[..20], 100).is_err());
+        assert!(matches!(
+            Deflate::decompress(&[0xFF; 8], 100),
+            Err(Error::NotValid)
+        ));
+    }
+
+    #[test]
+    fn decompression_bomb() -> Result<()> {
+        let bomb: Vec<u8> = Zlib::compress(&vec![0u8; 10_000_000])?;
+        assert!(bomb.len() < 20_000);
+
+        let mut reader: ByteReader = ByteReader::new(&bomb);
+        match reader.decompress_with_limit::<Zlib>(bomb.len(), 1 << 20) {
+            Err(Error::Custom(err)) => {
+                assert_eq!(
+                    err.downcast_ref::<LimitExceeded>().unwrap().limit(),
+                    1 << 20
+                )
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.decompress::<Zlib>(bomb.len())?.len(), 10_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn writer_gzip() -> Result<()> {
+        let mut buffer: Vec<u8> = vec![0u8; 64];
+        let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer);
+        let ((), size) = writer.compressed::<Gzip, _>(|s| s.write_exact(b"hello world"))?;
+
+        let mut reader: ByteReader = ByteReader::new(&buffer);
+        assert_eq!(reader.decompress::<Gzip>(size)?.as_slice(), b"hello world");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use bytecraft::common::Endian;
+use bytecraft::diff::{Diff, Hunk, HunkKind};
+use bytecraft::error::Result;
+use bytecraft::hexdump::HexDump;
+use bytecraft::readable::Readable;
+use bytecraft::reader::trace::ParseMap;
+use bytecraft::reader::{ByteReader, ReadStream};
+
+/// Applies the hunks to the old buffer.
+fn apply(old: &[u8], new: &[u8], hunks: &[Hunk]) -> Vec<u8> {
+    let mut result: Vec<u8> = Vec::new();
+    let mut position: usize = 0;
+
+    for hunk in hunks {
+        result.extend_from_slice(&old[position..hunk.old_range().start]);
+        result.extend_from_slice(&new[hunk.new_range()]);
+        position = hunk.old_range().end;
+    }
+    result.extend_from_slice(&old[position..]);
+    result
+}
+
+/// Returns the length of the longest common subsequence.
+fn lcs(old: &[u8], new: &[u8]) -> usize {
+    let mut table: Vec<Vec<usize>> = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in 1..=old.len() {
+        for j in 1..=new.len() {
+            table[i][j] = match old[i - 1] == new[j - 1] {
+                true => table[i - 1][j - 1] + 1,
+                false => table[i - 1][j].max(table[i][j - 1]),
+            };
+        }
+    }
+    table[old.len()][new.len()]
+}
+
+#[test]
+fn shortest_edit_script() {
+    let mut seed: u32 = 0x2545F491;
+    let mut random = |bound: u32| -> u32 {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed % bound
+    };
+
+    for _ in 0..500 {
+        let old: Vec<u8> = (0..random(12)).map(|_| random(3) as u8).collect();
+        let new: Vec<u8> = (0..random(12)).map(|_| random(3) as u8).collect();
+        let diff: Diff = Diff::new(&old, &new);
+        let hunks: &[Hunk] = diff.hunks();
+
+        assert_eq!(apply(&old, &new, hunks), new, "{:?} -> {:?}", old, new);
+        assert_eq!(diff.is_identical(), old == new);
+
+        let edits: usize = hunks
+            .iter()
+            .map(|hunk| hunk.old_range().len() + hunk.new_range().len())
+            .sum();
+        let common: usize = lcs(&old, &new);
+        assert_eq!(
+            edits,
+            old.len() + new.len() - 2 * common,
+            "{:?} -> {:?}",
+            old,
+            new
+        );
+
+        // Hunks are separated by equal bytes
+        for pair in hunks.windows(2) {
+            assert!(pair[0].old_range().end < pair[1].old_range().start);
+            assert!(pair[0].new_range().end < pair[1].new_range().start);
+        }
+    }
+}
+
+#[test]
+fn kinds() {
+    let hunks: Vec<Hunk> = Diff::new(b"abcdef", b"aXcdEEf!").hunks().to_vec();
+    let summary: Vec<(HunkKind, bool)> = hunks
+        .iter()
+        .map(|hunk| (hunk.kind(), hunk.is_in_place()))
+        .collect();
+
+    assert_eq!(
+        summary,
+        [
+            (HunkKind::Replace, true),
+            (HunkKind::Replace, false),
+            (HunkKind::Insert, false),
+        ]
+    );
+    assert_eq!(hunks[1].old_range(), 4..5);
+    assert_eq!(hunks[1].new_range(), 4..6);
+    assert_eq!(hunks[2].old_range(), 6..6);
+
+    let hunks: Vec<Hunk> = Diff::new(b"abcdef", b"abef").hunks().to_vec();
+    assert_eq!(hunks.len(), 1);
+    assert_eq!(hunks[0].kind(), HunkKind::Delete);
+    assert_eq!((hunks[0].old_range(), hunks[0].new_range()), (2..4, 2..2));
+
+    assert!(Diff::new(b"", b"").is_identical());
+    assert_eq!(Diff::new(b"", b"ab").hunks()[0].kind(), HunkKind::Insert);
+    assert_eq!(Diff::new(b"ab", b"").hunks()[0].kind(), HunkKind::Delete);
+}
+
+#[test]
+fn limit() {
+    let old: Vec<u8> = (0u8..=255).collect();
+    let new: Vec<u8> = old.iter().map(|byte| byte.wrapping_add(1)).collect();
+
+    // Shifted by one byte: a deletion and an insertion
+    let hunks: Vec<Hunk> = Diff::new(&old, &new).hunks().to_vec();
+    assert_eq!(hunks.len(), 2);
+    assert_eq!(hunks[0].kind(), HunkKind::Delete);
+    assert_eq!(hunks[1].kind(), HunkKind::Insert);
+
+    // Compared in place: every byte differs
+    let hunks: Vec<Hunk> = Diff::with_limit(&old, &new, 1).hunks().to_vec();
+    assert_eq!(hunks.len(), 1);
+    assert_eq!(
+        (hunks[0].old_range(), hunks[0].new_range()),
+        (0..256, 0..256)
+    );
+
+    let hunks: Vec<Hunk> = Diff::with_limit(b"xaaay", b"xbabyzz", 1).hunks().to_vec();
+    let ranges: Vec<_> = hunks
+        .iter()
+        .map(|hunk| (hunk.old_range(), hunk.new_range()))
+        .collect();
+    assert_eq!(ranges, [(1..2, 1..2), (3..4, 3..4), (5..5, 5..7)]);
+}
+
+#[test]
+fn render() {
+    let old: &[u8] = b"The quick brown fox jumps over the lazy dog";
+    let new: &[u8] = b"The quick red fox jumps over the lazy dog!";
+    let diff: Diff = Diff::new(old, new);
+
+    assert_eq!(
+        diff.to_string(),
+        "delete 0xa..0xb -> 0xa..0xa\n\
+         replace 0xc..0xf -> 0xb..0xd\n\
+         00000000  54 68 65 20 71 75 69 63  6b 20[62]72[6f 77 6e]20  |The quick brown | \
+         | 00000000  54 68 65 20 71 75 69 63  6b 20 72[65 64]20 66 6f  |The quick red fo|\n\
+         insert 0x2b..0x2b -> 0x29..0x2a\n\
+         00000020  68 65 20 6c 61 7a 79 20  64 6f 67                 |he lazy dog|      \
+         | 00000020  20 6c 61 7a 79 20 64 6f  67[21]                   | lazy dog!|"
+    );
+
+    // Deleted rows have no counterpart
+    let diff: Diff =
+        Diff::new(&[0u8; 6], &[0u8; 2]).layout(HexDump::default().width(2).group(0).ascii(false));
+    assert_eq!(
+        diff.to_string(),
+        "delete 0x2..0x6 -> 0x2..0x2\n\
+         00000002 [00 00] | 00000000  00 00\n\
+         00000004 [00 00] |"
+    );
+
+    assert_eq!(Diff::new(old, old).to_string(), "");
+}
+
+#[test]
+fn color() {
+    let diff: Diff =
+        Diff::new(&[0x41, 0x00], &[0x42, 0x00]).layout(HexDump::default().color(true).ascii(false));
+    let rendered: String = diff.to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    assert_eq!(lines[0], "replace 0x0..0x1 -> 0x0..0x1");
+    assert!(lines[1].starts_with("00000000  \x1b[7;36m41\x1b[0m \x1b[90m00\x1b[0m"));
+
+    // Escape codes are not counted in the padding
+    let separator: usize = lines[1].find(" | ").unwrap();
+    let padding: usize = separator - lines[1][..separator].trim_end().len();
+    assert_eq!(padding, 8 + 1 + 16 * 3 + 1 - "00000000  41 00".len());
+}
+
+#[derive(Debug)]
+struct Entry<'a> {
+    key: &'a str,
+    value: u16,
+}
+
+impl<'a> Readable<'a> for Entry<'a> {
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let key: &str = s.field("key", |s| {
+            let length: u8 = s.read()?;
+            s.read_ascii(length as usize)
+        })?;
+        let value: u16 = s.read_field("value")?;
+
+        Ok(Entry { key, value })
+    }
+}
+
+fn parse(data: &[u8]) -> ParseMap {
+    let mut reader: ByteReader = ByteReader::with_endian(data, Endian::Big);
+    reader.start_trace();
+    while reader.rest_len() > 0 {
+        let entry: Entry = reader.read_field("entry").unwrap();
+        assert!(!entry.key.is_empty() || entry.value == 0);
+    }
+    reader.take_trace().unwrap()
+}
+
+#[test]
+fn fields() {
+    let old: &[u8] = b"\x04name\x00\x01\x03age\x00\x20";
+    let new: &[u8] = b"\x04name\x00\x02\x04ages\x00\x20";
+    let (old_map, new_map) = (parse(old), parse(new));
+
+    let diff: Diff = Diff::new(old, new).old_map(&old_map);
+    let labels: Vec<Vec<String>> = diff.hunks().iter().map(|hunk| diff.labels(hunk)).collect();
+    assert_eq!(
+        labels,
+        [
+            vec![
+                "entry.value: u16".to_string(),
+                "entry.key: &str".to_string()
+            ],
+            vec![
+                "entry.key: &str".to_string(),
+                "entry.value: u16".to_string()
+            ],
+        ]
+    );
+
+    let diff: Diff = diff
+        .new_map(&new_map)
+        .layout(HexDump::new(new).width(8).ascii(false).highlight(0..1));
+    assert_eq!(
+        diff.to_string(),
+        "replace 0x6..0x8 -> 0x6..0x8  entry.value: u16, entry.key: &str\n\
+         00000000  04 6e 61 6d 65 00[01 03] | 00000000  04 6e 61 6d 65 00[02 04]\n\
+         insert 0xb..0xb -> 0xb..0xc  entry.key: &str\n\
+         00000008  61 67 65 00 20          | 00000008  61 67 65[73]00 20"
+    );
+    assert!(Diff::new(old, new).labels(&diff.hunks()[0]).is_empty());
+}
@@ -0,0 +1,173 @@
+use bytecraft::common::Endian;
+use bytecraft::encoding::{Base64, Hex, InvalidCharacter};
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::owned::OwnedReader;
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+fn invalid_character(result: Result<Vec<u8>>) -> (usize, u8) {
+    match result {
+        Err(Error::Custom(err)) => {
+            let err: &InvalidCharacter = err.downcast_ref().unwrap();
+            (err.index(), err.value())
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn hex() {
+    let bytes: Vec<u8> = (0..=255).collect();
+    assert_eq!(Hex::decode(&Hex::encode(&bytes)).unwrap(), bytes);
+    assert_eq!(Hex::decode(&Hex::encode_upper(&bytes)).unwrap(), bytes);
+
+    assert_eq!(Hex::encode(&[]), "");
+    assert_eq!(Hex::encode(&[0x00, 0x7F, 0xAB]), "007fab");
+    assert_eq!(Hex::encode_upper(&[0x00, 0x7F, 0xAB]), "007FAB");
+
+    assert_eq!(Hex::decode("").unwrap(), []);
+    assert_eq!(Hex::decode(" \t\r\n").unwrap(), []);
+    assert_eq!(
+        Hex::decode("De aD\tbE\r\nEF ").unwrap(),
+        [0xDE, 0xAD, 0xBE, 0xEF]
+    );
+
+    assert!(matches!(Hex::decode("abc"), Err(Error::NotValid)));
+    assert!(matches!(Hex::decode("a bc"), Err(Error::NotValid)));
+    assert_eq!(invalid_character(Hex::decode("00 0g")), (4, b'g'));
+    assert_eq!(invalid_character(Hex::decode("0x10")), (1, b'x'));
+    assert_eq!(invalid_character(Hex::decode("00ä")), (2, 0xC3));
+}
+
+#[test]
+fn base64_rfc4648() {
+    let vectors: [(&str, &str, &str); 7] = [
+        ("", "", ""),
+        ("f", "Zg==", "Zg"),
+        ("fo", "Zm8=", "Zm8"),
+        ("foo", "Zm9v", "Zm9v"),
+        ("foob", "Zm9vYg==", "Zm9vYg"),
+        ("fooba", "Zm9vYmE=", "Zm9vYmE"),
+        ("foobar", "Zm9vYmFy", "Zm9vYmFy"),
+    ];
+
+    for (data, padded, unpadded) in vectors {
+        for (base64, text) in [
+            (Base64::Standard, padded),
+            (Base64::UrlSafe, padded),
+            (Base64::StandardNoPad, unpadded),
+            (Base64::UrlSafeNoPad, unpadded),
+        ] {
+            assert_eq!(base64.encode(data.as_bytes()), text);
+            assert_eq!(base64.decode(text).unwrap(), data.as_bytes());
+        }
+    }
+}
+
+#[test]
+fn base64_alphabets() {
+    let bytes: Vec<u8> = (0..=255).collect();
+
+    for base64 in [
+        Base64::Standard,
+        Base64::StandardNoPad,
+        Base64::UrlSafe,
+        Base64::UrlSafeNoPad,
+    ] {
+        for size in 0..bytes.len() {
+            let text: String = base64.encode(&bytes[..size]);
+            assert_eq!(base64.decode(&text).unwrap(), bytes[..size]);
+        }
+    }
+
+    let data: [u8; 6] = [0xFB, 0xEF, 0xBE, 0xFF, 0xFF, 0xFF];
+    assert_eq!(Base64::Standard.encode(&data), "++++////");
+    assert_eq!(Base64::UrlSafe.encode(&data), "----____");
+    assert_eq!(Base64::default(), Base64::Standard);
+
+    assert_eq!(
+        invalid_character(Base64::Standard.decode("--==")),
+        (0, b'-')
+    );
+    assert_eq!(invalid_character(Base64::UrlSafe.decode("ab/+")), (2, b'/'));
+    assert_eq!(
+        invalid_character(Base64::Standard.decode("Zm9v\nYmF")),
+        (4, b'\n')
+    );
+}
+
+#[test]
+fn base64_padding() {
+    // Missing or unexpected padding
+    assert!(matches!(
+        Base64::Standard.decode("Zm8"),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(
+        invalid_character(Base64::StandardNoPad.decode("Zm8=")),
+        (3, b'=')
+    );
+    assert_eq!(
+        invalid_character(Base64::Standard.decode("Z===")),
+        (1, b'=')
+    );
+    assert_eq!(
+        invalid_character(Base64::Standard.decode("Zm==Zm8=")),
+        (2, b'=')
+    );
+    assert!(Base64::Standard.decode("====").is_err());
+
+    // A single character cannot encode a byte
+    assert!(matches!(
+        Base64::StandardNoPad.decode("Zm9vY"),
+        Err(Error::NotValid)
+    ));
+
+    // Non-zero unused bits
+    assert!(matches!(
+        Base64::Standard.decode("Zh=="),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        Base64::StandardNoPad.decode("Zm9"),
+        Err(Error::NotValid)
+    ));
+}
+
+#[test]
+fn reader_and_writer() -> Result<()> {
+    let fixture: OwnedReader = ByteReader::from_hex(
+        "
+        01 00 00 00
+        2A 00
+        ",
+    )?;
+    assert!(ByteReader::from_hex("01 # comment").is_err());
+
+    let mut reader: ByteReader = fixture.reader();
+    reader.set_endian(Endian::Little);
+    assert_eq!(reader.read::<(u32, u16)>()?, (1, 42));
+    assert!(reader.is_eof());
+
+    let payload: OwnedReader = ByteReader::from_base64("AAEC_w", Base64::UrlSafeNoPad)?;
+    assert_eq!(payload.as_slice(), [0x00, 0x01, 0x02, 0xFF]);
+    assert!(ByteReader::from_base64("AAEC_w==", Base64::Standard).is_err());
+
+    let mut buffer: [u8; 16] = [0u8; 16];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    assert_eq!(writer.to_hex(), "");
+    assert_eq!(writer.to_base64(Base64::Standard), "");
+
+    writer.write(&0x0001_02FFu32)?;
+    assert_eq!(writer.to_hex(), "000102ff");
+    assert_eq!(writer.to_base64(Base64::UrlSafeNoPad), "AAEC_w");
+    assert_eq!(writer.to_base64(Base64::Standard), "AAEC/w==");
+
+    writer.set_position(2)?;
+    assert_eq!(writer.to_hex(), "0001");
+
+    let owned: OwnedReader = ByteReader::from_hex(&writer.to_hex())?;
+    assert_eq!(owned.as_slice(), [0x00, 0x01]);
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use bytecraft::common::Endian;
+use bytecraft::error::Result;
+use bytecraft::hexdump::HexDump;
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+const DATA: &[u8] = b"The quick brown fox\x00\x01\xFF";
+
+#[test]
+fn canonical() {
+    let dump: String = HexDump::new(DATA).to_string();
+    let lines: Vec<&str> = dump.lines().collect();
+
+    assert_eq!(
+        lines,
+        [
+            "00000000  54 68 65 20 71 75 69 63  6b 20 62 72 6f 77 6e 20  |The quick brown |",
+            "00000010  66 6f 78 00 01 ff                                 |fox...|",
+        ]
+    );
+    assert_eq!(HexDump::new(&[]).to_string(), "");
+    assert_eq!(HexDump::new(DATA).range(5..5).to_string(), "");
+}
+
+#[test]
+fn layout() {
+    let dump: String = HexDump::new(DATA)
+        .width(8)
+        .group(2)
+        .header(true)
+        .uppercase(true)
+        .range(6..)
+        .to_string();
+
+    assert_eq!(
+        dump,
+        "          00 01  02 03  04 05  06 07\n\
+         00000000                       69 63  |ic|\n\
+         00000008  6B 20  62 72  6F 77  6E 20  |k brown |\n\
+         00000010  66 6F  78 00  01 FF         |fox...|"
+    );
+
+    let dump: String = HexDump::new(DATA)
+        .width(4)
+        .group(0)
+        .ascii(false)
+        .address(0x1_0000_0000)
+        .range(2..=9)
+        .to_string();
+
+    assert_eq!(
+        dump,
+        "0000000100000000        65 20\n\
+         0000000100000004  71 75 69 63\n\
+         0000000100000008  6b 20"
+    );
+
+    // Out of range selections are clamped
+    assert_eq!(
+        HexDump::new(DATA).width(0).range(20..100).to_string(),
+        "00000014  01  |.|\n00000015  ff  |.|"
+    );
+}
+
+#[test]
+fn highlight() {
+    let dump = |dump: HexDump| dump.width(8).group(4).ascii(false).to_string();
+
+    assert_eq!(
+        dump(HexDump::new(&DATA[..8]).highlight(0..2).highlight(2..3)),
+        "00000000 [54 68 65]20  71 75 69 63"
+    );
+    assert_eq!(
+        dump(HexDump::new(&DATA[..8]).highlight(3..5)),
+        "00000000  54 68 65[20  71]75 69 63"
+    );
+    assert_eq!(
+        dump(HexDump::new(&DATA[..8]).highlight(3..4).highlight(5..=7)),
+        "00000000  54 68 65[20] 71[75 69 63]"
+    );
+    assert_eq!(
+        dump(HexDump::new(&DATA[..12]).highlight(6..10)),
+        "00000000  54 68 65 20  71 75[69 63]\n00000008 [6b 20]62 72"
+    );
+    assert_eq!(
+        HexDump::new(&DATA[..4]).highlight(3..).to_string(),
+        "00000000  54 68 65[20]                                      |The |"
+    );
+
+    // Highlights outside the selected range are not shown
+    assert_eq!(
+        dump(HexDump::new(&DATA[..8]).highlight(1..3).range(2..)),
+        "00000000       [65]20  71 75 69 63"
+    );
+}
+
+#[test]
+fn color() {
+    let dump: String = HexDump::new(b"A\x00\xFF")
+        .highlight(1..2)
+        .color(true)
+        .to_string();
+
+    assert_eq!(
+        dump,
+        "00000000  \x1b[36m41\x1b[0m \x1b[7;90m00\x1b[0m \x1b[33mff\x1b[0m                                          \
+         |\x1b[36mA\x1b[0m\x1b[7;90m.\x1b[0m\x1b[33m.\x1b[0m|"
+    );
+    assert!(!dump.contains(" ["));
+}
+
+#[test]
+fn reader_and_writer() -> Result<()> {
+    let mut reader: ByteReader = ByteReader::new(DATA);
+    assert!(reader.hexdump().to_string().starts_with("00000000 [54]68"));
+
+    reader.skip(16)?;
+    assert_eq!(
+        reader.hexdump().range(16..).to_string(),
+        "00000010 [66]6f 78 00 01 ff                                 |fox...|"
+    );
+
+    reader.skip(6)?;
+    assert_eq!(reader.hexdump().to_string(), HexDump::new(DATA).to_string());
+
+    let mut buffer: [u8; 6] = [0u8; 6];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&0x0102_0304u32)?;
+
+    assert_eq!(
+        writer.hexdump().group(0).to_string(),
+        "00000000  01 02 03 04[00]00                                |......|"
+    );
+    assert_eq!(
+        writer
+            .hexdump()
+            .clear_highlights()
+            .range(..4)
+            .ascii(false)
+            .to_string(),
+        "00000000  01 02 03 04"
+    );
+
+    Ok(())
+}
@@ -465,3 +465,235 @@ fn fmt() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn try_read() -> Result<()> {
+    let data: [u8; 5] = [0x01, 0x02, 0x03, 0x04, 0x05];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    reader.skip(2)?;
+    assert!(matches!(
+        reader.try_read::<u64>(),
+        Err(Error::InsufficientData {
+            requested: 8,
+            available: 3
+        })
+    ));
+    assert_eq!(reader.position(), 2);
+
+    assert!(reader.try_read::<(u16, u16)>().is_err());
+    assert_eq!(reader.position(), 2);
+
+    assert_eq!(reader.try_read::<u16>()?, 0x0403);
+    assert_eq!(reader.position(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn checkpoint() -> Result<()> {
+    let data: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    {
+        let mut checkpoint = reader.checkpoint();
+        checkpoint.set_endian(Endian::Big);
+        assert_eq!(checkpoint.read::<u16>()?, 0x0102);
+        assert_eq!(checkpoint.start(), 0);
+        assert_eq!(checkpoint.consumed(), 2);
+    }
+    assert_eq!(reader.position(), 0);
+    assert_eq!(reader.endian(), Endian::Little);
+
+    {
+        let mut checkpoint = reader.checkpoint();
+        assert_eq!(checkpoint.read::<u16>()?, 0x0201);
+
+        {
+            let mut nested = checkpoint.checkpoint();
+            nested.skip(2)?;
+            assert!(nested.is_eof());
+        }
+        assert_eq!(checkpoint.position(), 2);
+
+        checkpoint.commit();
+    }
+    assert_eq!(reader.position(), 2);
+
+    let checkpoint = reader.checkpoint();
+    checkpoint.rollback();
+    assert_eq!(reader.position(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn alt() -> Result<()> {
+    #[derive(Debug, PartialEq)]
+    enum Value {
+        Flag(bool),
+        Short(u16),
+        Long(u32),
+    }
+
+    impl From<bool> for Value {
+        fn from(value: bool) -> Self {
+            Value::Flag(value)
+        }
+    }
+
+    impl From<u16> for Value {
+        fn from(value: u16) -> Self {
+            Value::Short(value)
+        }
+    }
+
+    impl From<u32> for Value {
+        fn from(value: u32) -> Self {
+            Value::Long(value)
+        }
+    }
+
+    let data: [u8; 3] = [0x01, 0x02, 0x03];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    let value: Value = reader.alt::<_, (u32, bool, u16)>()?;
+    assert_eq!(value, Value::Flag(true));
+    assert_eq!(reader.position(), 1);
+
+    let value: Value = reader.alt::<_, (u32, bool, u16)>()?;
+    assert_eq!(value, Value::Short(0x0302));
+    assert_eq!(reader.position(), 3);
+
+    reader.rewind(1)?;
+    assert!(matches!(
+        reader.alt::<Value, (u32, u16)>(),
+        Err(Error::InsufficientData {
+            requested: 2,
+            available: 1
+        })
+    ));
+    assert_eq!(reader.position(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn context() -> Result<()> {
+    struct Header {
+        pointer_size: u8,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Pointer(u64);
+
+    impl<'a> Readable<'a> for Pointer {
+        fn read<'r>(mut s: bytecraft::reader::ReadStream<'a, 'r>) -> Result<Self> {
+            match s.context::<Header>().ok_or(Error::NotValid)?.pointer_size {
+                4 => Ok(Pointer(s.read::<u32>()? as u64)),
+                8 => Ok(Pointer(s.read::<u64>()?)),
+                _ => Err(Error::NotValid),
+            }
+        }
+    }
+
+    let data: [u8; 12] = [1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(reader.context::<Header>().is_none());
+    assert!(matches!(reader.read::<Pointer>(), Err(Error::NotValid)));
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    reader.set_context(Header { pointer_size: 4 });
+    assert_eq!(
+        reader.read::<(Pointer, Pointer)>()?,
+        (Pointer(1), Pointer(2))
+    );
+
+    // Context is shared by clones and ignored by comparison
+    let mut clone: ByteReader = reader.clone();
+    assert_eq!(clone.context::<Header>().unwrap().pointer_size, 4);
+    clone.clear_context();
+    assert!(clone.context::<Header>().is_none());
+    assert_eq!(clone, reader);
+
+    // Rollback restores the previous context
+    reader.set_position(0)?;
+    {
+        let mut checkpoint = reader.checkpoint();
+        checkpoint.set_context(Header { pointer_size: 8 });
+        assert_eq!(
+            checkpoint.read::<Pointer>()?,
+            Pointer(0x0000_0002_0000_0001)
+        );
+    }
+    assert_eq!(reader.context::<Header>().unwrap().pointer_size, 4);
+
+    let reader: ByteReader = ByteReader::with_context(&data, 16u16);
+    assert_eq!(reader.context::<u16>(), Some(&16));
+    assert_eq!(reader.context::<Header>().map(|h| h.pointer_size), None);
+
+    Ok(())
+}
+
+#[test]
+fn read_with() -> Result<()> {
+    use bytecraft::readable::ReadableWith;
+
+    struct Block<'a> {
+        kind: u8,
+        payload: &'a [u8],
+    }
+
+    impl<'a> ReadableWith<'a, bool> for Block<'a> {
+        fn read_with<'r>(mut s: bytecraft::reader::ReadStream<'a, 'r>, wide: bool) -> Result<Self> {
+            let kind: u8 = s.read()?;
+            let len: usize = match wide {
+                true => s.read::<u16>()? as usize,
+                false => s.read::<u8>()? as usize,
+            };
+            Ok(Block {
+                kind,
+                payload: s.read_with(len)?,
+            })
+        }
+    }
+
+    let data: [u8; 9] = [0x01, 0x02, 0xAA, 0xBB, 0x02, 0x01, 0x00, 0xCC, 0x00];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    let block: Block = reader.read_with(false)?;
+    assert_eq!((block.kind, block.payload), (1, &[0xAA, 0xBB][..]));
+    let block: Block = reader.read_with(true)?;
+    assert_eq!((block.kind, block.payload), (2, &[0xCC][..]));
+
+    reader.reset();
+    let value: u16 = reader.read_with(())?;
+    assert_eq!(value, 0x0201);
+
+    let values: Vec<u8> = reader.read_with(3)?;
+    assert_eq!(values, vec![0xAA, 0xBB, 0x02]);
+    let values: Vec<u16> = reader.read_with(0)?;
+    assert!(values.is_empty());
+
+    assert!(matches!(
+        reader.read_with::<&[u8], _>(5),
+        Err(Error::InsufficientData {
+            requested: 5,
+            available: 4
+        })
+    ));
+    assert!(reader.read_with::<Vec<u16>, _>(3).is_err());
+
+    // A huge count does not preallocate memory
+    let mut reader: ByteReader = ByteReader::new(&[0x01, 0x02, 0x03]);
+    assert!(matches!(
+        reader.read_with::<Vec<u64>, _>(usize::MAX / 16),
+        Err(Error::InsufficientData {
+            requested: 8,
+            available: 3
+        })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::readable::Readable;
+use bytecraft::reader::{ByteReader, ReadStream};
+
+#[derive(Debug, PartialEq)]
+struct Packet(u8);
+
+impl<'a> Readable<'a> for Packet {
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        match s.read::<[u8; 2]>()? {
+            [0x47, value] => Ok(Packet(value)),
+            _ => Err(Error::NotValid),
+        }
+    }
+}
+
+#[test]
+fn iter() {
+    let data: [u8; 6] = [0x00, 0x01, 0x00, 0x02, 0x00, 0x03];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+
+    let values: Vec<u16> = reader.iter::<u16>().collect::<Result<_>>().unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+    assert!(reader.is_eof());
+    assert!(reader.iter::<u16>().next().is_none());
+
+    // Incomplete last record
+    let mut reader: ByteReader = ByteReader::new(&data[..5]);
+    let mut records = reader.iter::<u16>();
+    assert!(records.next().unwrap().is_ok());
+    assert!(records.next().unwrap().is_ok());
+    assert!(matches!(
+        records.next(),
+        Some(Err(Error::InsufficientData { .. }))
+    ));
+    assert!(records.next().is_none());
+    assert_eq!(reader.position(), 4);
+
+    // Records consuming no bytes end the iteration
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert_eq!(reader.iter::<()>().count(), 1);
+    assert_eq!(reader.iter::<[u8; 0]>().count(), 1);
+    assert_eq!(reader.iter_n::<()>(3).count(), 3);
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn iter_n() {
+    let data: [u8; 4] = [0x0A, 0x0B, 0x0C, 0x0D];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let items: Vec<u8> = reader.iter_n::<u8>(2).collect::<Result<_>>().unwrap();
+    assert_eq!(items, vec![0x0A, 0x0B]);
+    assert_eq!(reader.position(), 2);
+
+    assert_eq!(reader.iter_n::<u8>(0).count(), 0);
+    assert_eq!(reader.iter_n::<u8>(5).size_hint(), (0, Some(5)));
+
+    let results: Vec<Result<u8>> = reader.iter_n::<u8>(3).collect();
+    assert_eq!(results.len(), 3);
+    assert!(results[2].is_err());
+    assert!(reader.is_eof());
+}
+
+#[test]
+fn skip_on_error() {
+    let data: [u8; 9] = [0x47, 0x01, 0xAA, 0xBB, 0x47, 0x02, 0x47, 0x03, 0xCC];
+    let mut reader: ByteReader = ByteReader::new(&data);
+
+    let resync = |r: &mut ByteReader| -> Result<()> {
+        r.skip(1)?;
+        r.skip_until(&[0x47]).map(|_| ())
+    };
+
+    let results: Vec<Result<Packet>> = reader.iter::<Packet>().skip_on_error(resync).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(results[0].as_ref().unwrap(), &Packet(1));
+    assert_eq!(results[1].as_ref().unwrap(), &Packet(2));
+    assert_eq!(results[2].as_ref().unwrap(), &Packet(3));
+    // No sync marker after the trailing garbage
+    assert!(matches!(results[3], Err(Error::NotValid)));
+    assert!(reader.is_eof());
+
+    // Resync without progress ends the iteration with the record error
+    let mut reader: ByteReader = ByteReader::new(&data[2..]);
+    let mut records = reader.iter::<Packet>().skip_on_error(|_| Ok(()));
+    assert!(matches!(records.next(), Some(Err(Error::NotValid))));
+    assert!(records.next().is_none());
+
+    // Default policy can be restored
+    let mut reader: ByteReader = ByteReader::new(&data[2..]);
+    let mut records = reader
+        .iter::<Packet>()
+        .skip_on_error(resync)
+        .stop_on_error();
+    assert!(matches!(records.next(), Some(Err(Error::NotValid))));
+    assert!(records.next().is_none());
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn iter_in_readable() {
+    struct Table(Vec<u16>);
+
+    impl<'a> Readable<'a> for Table {
+        fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+            let count: u8 = s.read()?;
+            Ok(Table(
+                s.iter_n::<u16>(count as usize).collect::<Result<_>>()?,
+            ))
+        }
+    }
+
+    let data: [u8; 5] = [0x02, 0x01, 0x00, 0x02, 0x00];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    let table: Table = reader.read().unwrap();
+    assert_eq!(table.0, vec![1, 2]);
+
+    let mut reader: ByteReader = ByteReader::new(&data[..4]);
+    assert!(reader.try_read::<Table>().is_err());
+    assert_eq!(reader.position(), 0);
+}
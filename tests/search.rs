@@ -0,0 +1,112 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+
+#[test]
+fn find() {
+    let data: &[u8] = b"\x00\xFF\xD8\xFF\xD8\xFF\xE0\x00";
+    let mut reader: ByteReader = ByteReader::new(data);
+
+    assert_eq!(reader.find(b"\xFF\xD8"), Some(1));
+    assert_eq!(reader.rfind(b"\xFF\xD8"), Some(3));
+    assert_eq!(reader.find(b"\xFF\xE0\x00"), Some(5));
+    assert_eq!(reader.find(b"\xFF\xE0\x00\x00"), None);
+    assert_eq!(reader.find_byte(0xE0), Some(6));
+    assert_eq!(reader.find(b""), Some(0));
+    assert_eq!(reader.rfind(b""), Some(8));
+
+    reader.skip(2).unwrap();
+    assert_eq!(reader.find(b"\xFF\xD8"), Some(3));
+    assert_eq!(reader.find_byte(0x00), Some(7));
+
+    reader.skip(2).unwrap();
+    assert_eq!(reader.find(b"\xFF\xD8"), None);
+    assert_eq!(reader.rfind(b"\xFF\xD8"), None);
+    assert_eq!(reader.position(), 4);
+}
+
+#[test]
+fn find_matches_naive_search() {
+    let data: Vec<u8> = (0..4096u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 29) as u8)
+        .collect();
+    let reader: ByteReader = ByteReader::new(&data);
+
+    for len in 1..6 {
+        for start in (0..data.len() - len).step_by(97) {
+            let pattern: &[u8] = &data[start..start + len];
+            let first: Option<usize> = data.windows(len).position(|w| w == pattern);
+            let last: Option<usize> = data.windows(len).rposition(|w| w == pattern);
+
+            assert_eq!(reader.find(pattern), first);
+            assert_eq!(reader.rfind(pattern), last);
+        }
+    }
+
+    assert_eq!(reader.find(&[0xAA; 8]), None);
+    assert_eq!(reader.rfind(&[0xAA; 8]), None);
+}
+
+#[test]
+fn skip_and_read_until() -> Result<()> {
+    let data: &[u8] = b"junk\r\nGET / HTTP/1.1\r\nHost: x\r\n\r\nbody";
+    let mut reader: ByteReader = ByteReader::new(data);
+
+    assert_eq!(reader.skip_until(b"GET")?, 6);
+    assert_eq!(reader.read_until(b"\r\n")?, b"GET / HTTP/1.1");
+    assert_eq!(reader.read_until(b"\r\n")?, b"Host: x");
+    assert_eq!(reader.read_until(b"\r\n")?, b"");
+    assert_eq!(reader.rest_bytes(), b"body");
+
+    let position: usize = reader.position();
+    assert!(matches!(reader.read_until(b"\r\n"), Err(Error::NotValid)));
+    assert!(matches!(reader.skip_until(b"\r\n"), Err(Error::NotValid)));
+    assert_eq!(reader.position(), position);
+
+    Ok(())
+}
+
+#[test]
+fn find_aligned() -> Result<()> {
+    let data: [u8; 12] = [
+        0x78, 0x56, 0x34, 0x12, 0x00, 0x00, 0x12, 0x34, 0x56, 0x78, 0x00, 0x00,
+    ];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+
+    assert_eq!(reader.find_aligned(&0x12345678u32, 1)?, Some(6));
+    assert_eq!(reader.find_aligned(&0x12345678u32, 2)?, Some(6));
+    assert_eq!(reader.find_aligned(&0x12345678u32, 4)?, None);
+    assert!(matches!(
+        reader.find_aligned(&0x12345678u32, 3),
+        Err(Error::NotValid)
+    ));
+
+    reader.set_endian(Endian::Little);
+    assert_eq!(reader.find_aligned(&0x12345678u32, 4)?, Some(0));
+
+    reader.skip(1)?;
+    assert_eq!(reader.find_aligned(&0x12345678u32, 4)?, None);
+    assert_eq!(reader.find_aligned(&0u16, 4)?, Some(4));
+    assert_eq!(reader.position(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn split() {
+    let mut reader: ByteReader = ByteReader::new(b"a,,bc,d,");
+    let pieces: Vec<&[u8]> = reader.split(b",").collect();
+    assert_eq!(pieces, vec![&b"a"[..], b"", b"bc", b"d"]);
+    assert!(reader.is_eof());
+
+    let mut reader: ByteReader = ByteReader::new(b"head::tail");
+    assert_eq!(reader.split(b"::").next(), Some(&b"head"[..]));
+    assert_eq!(reader.rest_bytes(), b"tail");
+
+    let mut reader: ByteReader = ByteReader::new(b"whole");
+    let pieces: Vec<&[u8]> = reader.split(b"").collect();
+    assert_eq!(pieces, vec![&b"whole"[..]]);
+
+    let mut reader: ByteReader = ByteReader::new(b"");
+    assert_eq!(reader.split(b",").count(), 0);
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "serde")]
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::trace::ParseMap;
+use bytecraft::reader::ByteReader;
+use bytecraft::serde::{
+    from_reader, from_slice, from_slice_with_endian, to_vec, to_vec_with_endian, to_writer,
+};
+use bytecraft::writer::ByteWriter;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(f32),
+    Rect(u16, u16),
+    Named { id: u8, label: String },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Unit;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Wrapper(i64);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Document<'a> {
+    version: u8,
+    title: &'a str,
+    #[serde(with = "serde_bytes_slice")]
+    payload: &'a [u8],
+    tags: Vec<String>,
+    shapes: Vec<Shape>,
+    attributes: BTreeMap<u8, bool>,
+    parent: Option<Wrapper>,
+    symbol: char,
+    unit: Unit,
+    big: (u128, i128),
+}
+
+mod serde_bytes_slice {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_bytes(bytes)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<&'de [u8], D::Error> {
+        <&'de [u8] as serde::Deserialize>::deserialize(d)
+    }
+}
+
+#[test]
+fn roundtrip() -> Result<()> {
+    let document: Document = Document {
+        version: 3,
+        title: "report",
+        payload: &[0xDE, 0xAD],
+        tags: vec!["a".to_string(), "✅".to_string()],
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle(1.5),
+            Shape::Rect(2, 3),
+            Shape::Named {
+                id: 7,
+                label: "x".to_string(),
+            },
+        ],
+        attributes: BTreeMap::from([(1, true), (2, false)]),
+        parent: Some(Wrapper(-1)),
+        symbol: '🦀',
+        unit: Unit,
+        big: (u128::MAX, i128::MIN),
+    };
+
+    for endian in [Endian::Little, Endian::Big, Endian::Native] {
+        let bytes: Vec<u8> = to_vec_with_endian(&document, endian)?;
+        let decoded: Document = from_slice_with_endian(&bytes, endian)?;
+        assert_eq!(decoded, document);
+
+        // The computed size matches the written data exactly
+        let mut buffer: [u8; 256] = [0u8; 256];
+        let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], endian);
+        to_writer(&mut writer, &document)?;
+        let end: usize = writer.position();
+        assert_eq!(bytes, buffer[..end]);
+    }
+
+    // Large output is written at once
+    let large: Vec<u64> = (0..1000).collect();
+    let bytes: Vec<u8> = to_vec(&large)?;
+    assert_eq!(bytes.len(), 4 + 8 * 1000);
+    assert_eq!(from_slice::<Vec<u64>>(&bytes)?, large);
+
+    Ok(())
+}
+
+#[test]
+fn format() -> Result<()> {
+    let bytes: Vec<u8> = to_vec_with_endian(&(1u16, "ab", Some(true), None::<u8>), Endian::Big)?;
+    assert_eq!(
+        bytes,
+        [0x00, 0x01, 0x00, 0x00, 0x00, 0x02, b'a', b'b', 0x01, 0x01, 0x00]
+    );
+
+    let bytes: Vec<u8> = to_vec_with_endian(&Shape::Rect(1, 2), Endian::Little)?;
+    assert_eq!(bytes, [0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00]);
+
+    // Same layout as the Writable implementations
+    let mut buffer: [u8; 16] = [0u8; 16];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&vec![1u16, 2u16])?;
+    writer.write(&"hi".to_string())?;
+    let end: usize = writer.position();
+    assert_eq!(
+        to_vec_with_endian(&(vec![1u16, 2u16], "hi"), Endian::Big)?,
+        buffer[..end]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn reader_and_writer() -> Result<()> {
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    to_writer(&mut writer, &0x0102u16)?;
+    to_writer(&mut writer, &'é')?;
+    assert_eq!(writer.position(), 4);
+    assert!(matches!(
+        to_writer(&mut writer, &u64::MAX),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+    assert_eq!(from_reader::<u16>(&mut reader)?, 0x0102);
+    assert_eq!(from_reader::<char>(&mut reader)?, 'é');
+    assert_eq!(reader.position(), 4);
+
+    // Failed deserialization keeps the position
+    assert!(from_reader::<(u32, u8)>(&mut reader).is_err());
+    assert_eq!(reader.position(), 4);
+
+    // The tracer of the reader is kept
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+    reader.start_trace();
+    reader.read_field::<u8>("before")?;
+    assert_eq!(from_reader::<u16>(&mut reader)?, 0xC301);
+    reader.read_field::<u8>("after")?;
+    let map: ParseMap = reader.take_trace().unwrap();
+    let spans: Vec<(Option<&str>, Range<usize>)> = map
+        .spans()
+        .iter()
+        .map(|span| (span.field(), span.range()))
+        .collect();
+    assert_eq!(spans, [(Some("before"), 0..1), (Some("after"), 3..4)]);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_data() {
+    assert!(matches!(from_slice::<bool>(&[2]), Err(Error::NotValid)));
+    assert!(matches!(
+        from_slice::<Option<u8>>(&[2, 0]),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(from_slice::<u8>(&[1, 2]), Err(Error::NotValid)));
+    assert!(matches!(
+        from_slice_with_endian::<&str>(&[0, 0, 0, 1, 0xFF], Endian::Big),
+        Err(Error::NotValidUTF8(_))
+    ));
+    assert!(matches!(
+        from_slice::<u32>(&[1, 2]),
+        Err(Error::InsufficientData { .. })
+    ));
+    assert!(matches!(
+        from_slice_with_endian::<Shape>(&[0, 0, 0, 9], Endian::Big),
+        Err(Error::Custom(_))
+    ));
+    assert!(matches!(
+        from_slice::<serde::de::IgnoredAny>(&[0]),
+        Err(Error::Custom(_))
+    ));
+}
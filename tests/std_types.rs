@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::num::{NonZeroI16, NonZeroU32, NonZeroU8, Wrapping};
+use std::ops::Range;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+use bytecraft::writer::ByteWriter;
+
+#[test]
+fn test_char_roundtrip() -> Result<()> {
+    let chars: [char; 4] = ['a', 'é', '✅', '🦀'];
+
+    let mut buffer: [u8; 10] = [0u8; 10];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&chars)?;
+    assert_eq!(writer.position(), 10);
+
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    assert_eq!(reader.peek::<char>()?, 'a');
+    assert_eq!(reader.read::<[char; 4]>()?, chars);
+    assert!(reader.is_eof());
+
+    // Truncated and invalid sequences
+    let mut reader: ByteReader = ByteReader::new(&buffer[6..8]);
+    assert!(matches!(
+        reader.read::<char>(),
+        Err(Error::InsufficientData { .. })
+    ));
+    let mut reader: ByteReader = ByteReader::new(&[0xFF]);
+    assert!(matches!(reader.read::<char>(), Err(Error::NotValidUTF8(_))));
+    let mut reader: ByteReader = ByteReader::new(&[0xC3, 0x41]);
+    assert!(matches!(
+        reader.try_read::<char>(),
+        Err(Error::NotValidUTF8(_))
+    ));
+    assert_eq!(reader.position(), 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_zero_sized() -> Result<()> {
+    let mut buffer: [u8; 1] = [0u8; 1];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&())?;
+    writer.write(&PhantomData::<str>)?;
+    assert_eq!(writer.position(), 0);
+
+    let mut reader: ByteReader = ByteReader::new(&[]);
+    reader.read::<()>()?;
+    reader.peek::<()>()?;
+    let _: PhantomData<str> = reader.read()?;
+    let _: PhantomData<u8> = reader.peek()?;
+
+    Ok(())
+}
+
+#[test]
+fn test_option_roundtrip() -> Result<()> {
+    let values: (Option<u16>, Option<u16>, Option<Option<bool>>) = (Some(0x0102), None, Some(None));
+
+    let mut buffer: [u8; 6] = [0u8; 6];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&values)?;
+    assert_eq!(buffer, [0x01, 0x01, 0x02, 0x00, 0x01, 0x00]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<Option<u16>>()?, Some(0x0102));
+    assert_eq!(
+        reader.read::<(Option<u16>, Option<u16>, Option<Option<bool>>)>()?,
+        values
+    );
+
+    let mut reader: ByteReader = ByteReader::new(&[0x02]);
+    assert!(matches!(reader.read::<Option<u8>>(), Err(Error::NotValid)));
+
+    Ok(())
+}
+
+#[test]
+fn test_pointers_roundtrip() -> Result<()> {
+    let mut buffer: [u8; 6] = [0u8; 6];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&Box::new(1u16))?;
+    writer.write(&Rc::new(2u16))?;
+    writer.write(&Arc::new(3u16))?;
+    assert_eq!(buffer, [1, 0, 2, 0, 3, 0]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+    assert_eq!(reader.peek::<Box<u16>>()?, Box::new(1));
+    assert_eq!(*reader.read::<Box<u16>>()?, 1);
+    assert_eq!(*reader.read::<Rc<u16>>()?, 2);
+    assert_eq!(*reader.read::<Arc<u16>>()?, 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_numeric_wrappers() -> Result<()> {
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&NonZeroU32::new(7).unwrap())?;
+    writer.write(&NonZeroI16::new(-1).unwrap())?;
+    writer.write(&Wrapping(0xABu8))?;
+    assert_eq!(buffer, [0, 0, 0, 7, 0xFF, 0xFF, 0xAB, 0]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<NonZeroU32>()?.get(), 7);
+    assert_eq!(reader.read::<NonZeroU32>()?.get(), 7);
+    assert_eq!(reader.read::<NonZeroI16>()?.get(), -1);
+    assert_eq!(reader.peek::<Wrapping<u8>>()?, Wrapping(0xAB));
+    assert_eq!(reader.read::<Wrapping<u8>>()?, Wrapping(0xAB));
+
+    assert!(matches!(reader.peek::<NonZeroU8>(), Err(Error::NotValid)));
+    assert!(matches!(reader.read::<NonZeroU8>(), Err(Error::NotValid)));
+
+    Ok(())
+}
+
+#[test]
+fn test_cow_roundtrip() -> Result<()> {
+    let bytes: Cow<[u8]> = Cow::Owned(vec![0xAA, 0xBB]);
+    let text: Cow<str> = Cow::Borrowed("hi");
+
+    let mut buffer: [u8; 12] = [0u8; 12];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&bytes)?;
+    writer.write(&text)?;
+    assert_eq!(buffer, [2, 0, 0, 0, 0xAA, 0xBB, 2, 0, 0, 0, b'h', b'i']);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+    assert_eq!(reader.peek::<Cow<[u8]>>()?, bytes);
+    let read_bytes: Cow<[u8]> = reader.read()?;
+    let read_text: Cow<str> = reader.read()?;
+    assert!(matches!(read_bytes, Cow::Borrowed(_)));
+    assert!(matches!(read_text, Cow::Borrowed("hi")));
+
+    // Same format as Vec<u8> and String
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+    assert_eq!(
+        reader.read::<(Vec<u8>, String)>()?,
+        (vec![0xAA, 0xBB], "hi".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_range_roundtrip() -> Result<()> {
+    let range: Range<u16> = 3..10;
+
+    let mut buffer: [u8; 4] = [0u8; 4];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&range)?;
+    assert_eq!(buffer, [0, 3, 0, 10]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<Range<u16>>()?, range);
+    assert_eq!(reader.read::<Range<u16>>()?, range);
+
+    Ok(())
+}
+
+#[test]
+fn test_peek_composite() -> Result<()> {
+    let data: [u8; 14] = [
+        2, 0, 0, 0, b'o', b'k', // String
+        1, 2, // [u8; 2]
+        b'c', 0, // CString
+        1, 0, 0, 0, // Vec<u8> length without data
+    ];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    assert_eq!(reader.peek::<String>()?, "ok");
+    assert_eq!(reader.peek::<(u32, [u8; 2])>()?, (2, [b'o', b'k']));
+    assert_eq!(reader.peek::<Vec<u8>>()?, b"ok");
+    assert_eq!(reader.position(), 0);
+
+    reader.skip(6)?;
+    assert_eq!(reader.peek::<[u8; 2]>()?, [1, 2]);
+    reader.skip(2)?;
+    assert_eq!(reader.peek::<CString>()?, CString::new("c").unwrap());
+    reader.skip(2)?;
+    assert!(matches!(
+        reader.peek::<Vec<u8>>(),
+        Err(Error::InsufficientData { .. })
+    ));
+    assert_eq!(reader.position(), 10);
+
+    Ok(())
+}
+
+#[test]
+fn test_net_addr_roundtrip() -> Result<()> {
+    let v4: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 0x1234);
+    let v6: SocketAddrV6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0);
+
+    for endian in [Endian::Little, Endian::Big] {
+        let mut buffer: [u8; 48] = [0u8; 48];
+        let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], endian);
+
+        writer.write(&v4)?;
+        writer.write(&SocketAddr::V6(v6))?;
+        writer.write(&IpAddr::V4(Ipv4Addr::BROADCAST))?;
+        assert_eq!(writer.position(), 6 + 19 + 5);
+
+        assert_eq!(buffer[..6], [10, 0, 0, 1, 0x12, 0x34]);
+        assert_eq!(buffer[6], 6);
+        assert_eq!(buffer[22..25], [1, 0x01, 0xBB]);
+        assert_eq!(buffer[25..30], [4, 255, 255, 255, 255]);
+
+        let mut reader: ByteReader = ByteReader::with_endian(&buffer, endian);
+        assert_eq!(reader.peek::<Ipv4Addr>()?, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(reader.read::<SocketAddrV4>()?, v4);
+        assert_eq!(reader.peek::<SocketAddr>()?, SocketAddr::V6(v6));
+        assert_eq!(reader.read::<SocketAddr>()?, SocketAddr::V6(v6));
+        assert_eq!(reader.read::<IpAddr>()?, IpAddr::V4(Ipv4Addr::BROADCAST));
+    }
+
+    let data: [u8; 5] = [5, 127, 0, 0, 1];
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert!(matches!(reader.read::<IpAddr>(), Err(Error::NotValid)));
+
+    Ok(())
+}
+
+#[test]
+fn test_socket_addr_v6_drops_scope() -> Result<()> {
+    let addr: SocketAddrV6 = SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 80, 7, 3);
+
+    let mut buffer: [u8; 18] = [0u8; 18];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&addr)?;
+
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    let read: SocketAddrV6 = reader.read()?;
+    assert_eq!(read, SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 80, 0, 0));
+    assert!(reader.is_eof());
+
+    Ok(())
+}
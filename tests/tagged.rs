@@ -0,0 +1,243 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+use bytecraft::tagged::UnknownTag;
+use bytecraft::tagged_enum;
+use bytecraft::types::{VarU32, VarU64};
+use bytecraft::writer::ByteWriter;
+
+tagged_enum! {
+    /// A record of a test protocol.
+    #[derive(Debug, Clone, PartialEq)]
+    pub enum Record<'a>: u16 {
+        /// Keep-alive record without payload.
+        Ping = 0x0001,
+        Value(u32) = 0x0002,
+        Pair((u8, u8)) = 0x0003,
+        Name(&'a str) = 0x0100,
+        _ => Unknown,
+    }
+}
+
+tagged_enum! {
+    #[derive(Debug, PartialEq)]
+    enum Opcode: VarU64 {
+        Nop = VarU64(0),
+        Jump(i32) = VarU64(300),
+    }
+}
+
+tagged_enum! {
+    #[derive(Debug, PartialEq)]
+    enum Setting<'a>: &'a str {
+        Enabled(bool) = "on",
+        Label(&'a str) = "label",
+    }
+}
+
+const KIND_A: u8 = 0xA0;
+
+tagged_enum! {
+    #[derive(Debug, PartialEq)]
+    enum Kind: u8 {
+        A = KIND_A,
+        B = KIND_A + 1,
+    }
+}
+
+#[test]
+fn roundtrip() -> Result<()> {
+    let records: Vec<Record> = vec![
+        Record::Ping,
+        Record::Value(7),
+        Record::Pair((1, 2)),
+        Record::Name("ab"),
+    ];
+
+    let mut buffer: [u8; 20] = [0u8; 20];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    for record in &records {
+        writer.write(record)?;
+    }
+    assert_eq!(writer.position(), 20);
+    assert_eq!(
+        buffer,
+        [
+            0x00, 0x01, // Ping
+            0x00, 0x02, 0x00, 0x00, 0x00, 0x07, // Value
+            0x00, 0x03, 0x01, 0x02, // Pair
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x02, b'a', b'b', // Name
+        ]
+    );
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<Record>()?, Record::Ping);
+    for record in &records {
+        assert_eq!(&reader.read::<Record>()?, record);
+    }
+    assert!(reader.is_eof());
+
+    assert_eq!(Record::Ping.tag(), 0x0001);
+    assert_eq!(Record::Name("x").tag(), 0x0100);
+
+    Ok(())
+}
+
+#[test]
+fn unknown_fallback() -> Result<()> {
+    let data: [u8; 5] = [0x12, 0x34, 0xAA, 0xBB, 0xCC];
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+    let record: Record = reader.read()?;
+    assert_eq!(record, Record::Unknown(0x1234, &[0xAA, 0xBB, 0xCC]));
+    assert_eq!(record.tag(), 0x1234);
+    assert!(reader.is_eof());
+
+    let mut buffer: [u8; 5] = [0u8; 5];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&record)?;
+    assert_eq!(buffer, data);
+
+    // Known tags with a truncated payload are still errors
+    let mut reader: ByteReader = ByteReader::with_endian(&[0x00, 0x02, 0x01], Endian::Big);
+    assert!(matches!(
+        reader.read::<Record>(),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_fallback_nested() -> Result<()> {
+    // Length-prefixed records: u8 length, u16 tag, payload
+    let data: [u8; 15] = [
+        0x02, 0x12, 0x34, 0xAA, 0xBB, // Unknown
+        0x04, 0x00, 0x02, 0x00, 0x00, 0x00, 0x07, // Value
+        0x00, 0x00, 0x01, // Ping
+    ];
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+    let mut records: Vec<Record> = Vec::new();
+    while !reader.is_eof() {
+        let len: u8 = reader.read()?;
+        records.push(reader.read_with(len as usize)?);
+    }
+    assert_eq!(
+        records,
+        [
+            Record::Unknown(0x1234, &[0xAA, 0xBB]),
+            Record::Value(7),
+            Record::Ping
+        ]
+    );
+
+    // A known payload not matching the length
+    let mut reader: ByteReader = ByteReader::with_endian(&data[6..], Endian::Big);
+    assert!(matches!(
+        reader.read_with::<Record, _>(2),
+        Err(Error::NotValid)
+    ));
+
+    // Without a length, the fallback takes the rest of the stream
+    let mut reader: ByteReader = ByteReader::with_endian(&data[1..5], Endian::Big);
+    assert!(matches!(
+        reader.read_with::<Vec<Record>, _>(2),
+        Err(Error::InsufficientData { .. })
+    ));
+    let mut reader: ByteReader = ByteReader::with_endian(&data[1..], Endian::Big);
+    assert_eq!(
+        reader.read::<Record>()?,
+        Record::Unknown(0x1234, &data[3..])
+    );
+    assert!(reader.is_eof());
+
+    Ok(())
+}
+
+#[test]
+fn unknown_error() {
+    let mut reader: ByteReader = ByteReader::new(&[0x05]);
+
+    match reader.read::<Kind>() {
+        Err(Error::Custom(err)) => {
+            let err: &UnknownTag = err.downcast_ref().unwrap();
+            assert_eq!(err.name(), "Kind");
+            assert_eq!(err.tag(), "5");
+            assert_eq!(err.to_string(), "Unknown tag 5 for Kind");
+        }
+        other => panic!("unexpected result: {other:?}"),
+    }
+
+    let mut reader: ByteReader = ByteReader::new(&[0xA0, 0xA1]);
+    assert_eq!(reader.read::<Kind>().unwrap(), Kind::A);
+    assert_eq!(reader.read::<Kind>().unwrap(), Kind::B);
+}
+
+#[test]
+fn varint_and_string_tags() -> Result<()> {
+    let mut buffer: [u8; 32] = [0u8; 32];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&Opcode::Jump(-2))?;
+    writer.write(&Opcode::Nop)?;
+    writer.write(&Setting::Enabled(true))?;
+    writer.write(&Setting::Label("x"))?;
+    let written: usize = writer.position();
+    assert_eq!(&buffer[..7], &[0xAC, 0x02, 0xFE, 0xFF, 0xFF, 0xFF, 0x00]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer[..written], Endian::Little);
+    assert_eq!(reader.read::<Opcode>()?, Opcode::Jump(-2));
+    assert_eq!(reader.read::<Opcode>()?, Opcode::Nop);
+    assert_eq!(reader.read::<Setting>()?, Setting::Enabled(true));
+    assert_eq!(reader.read::<Setting>()?, Setting::Label("x"));
+    assert!(reader.is_eof());
+
+    assert_eq!(Opcode::Jump(0).tag(), VarU64(300));
+    assert_eq!(Setting::Enabled(false).tag(), "on");
+
+    let mut reader: ByteReader = ByteReader::new(&[0x01]);
+    assert!(matches!(reader.read::<Opcode>(), Err(Error::Custom(_))));
+
+    Ok(())
+}
+
+#[test]
+fn varint() -> Result<()> {
+    let values: [u64; 6] = [0, 1, 127, 128, 300, u64::MAX];
+    let lengths: [usize; 6] = [1, 1, 1, 2, 2, 10];
+
+    let mut buffer: [u8; 32] = [0u8; 32];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    for (value, len) in values.iter().zip(lengths) {
+        let start: usize = writer.position();
+        writer.write(&VarU64(*value))?;
+        assert_eq!(writer.position() - start, len);
+        assert_eq!(VarU64(*value).encoded_len(), len);
+    }
+
+    let mut reader: ByteReader = ByteReader::new(&buffer);
+    for value in values {
+        assert_eq!(reader.peek::<VarU64>()?, VarU64(value));
+        assert_eq!(u64::from(reader.read::<VarU64>()?), value);
+    }
+
+    let mut buffer: [u8; 5] = [0u8; 5];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&VarU32(u32::MAX))?;
+    assert_eq!(buffer, [0xFF, 0xFF, 0xFF, 0xFF, 0x0F]);
+
+    // Value overflow
+    let mut reader: ByteReader = ByteReader::new(&[0xFF, 0xFF, 0xFF, 0xFF, 0x10]);
+    assert!(matches!(reader.read::<VarU32>(), Err(Error::NotValid)));
+    // Too many bytes
+    let mut reader: ByteReader = ByteReader::new(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x00]);
+    assert!(matches!(reader.read::<VarU32>(), Err(Error::NotValid)));
+    // Missing last byte
+    let mut reader: ByteReader = ByteReader::new(&[0x80]);
+    assert!(matches!(
+        reader.read::<VarU32>(),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::readable::Readable;
+use bytecraft::reader::trace::{ParseMap, Span};
+use bytecraft::reader::{ByteReader, ReadStream};
+
+#[derive(Debug, PartialEq)]
+struct Record<'a> {
+    tag: u8,
+    value: &'a [u8],
+}
+
+impl<'a> Readable<'a> for Record<'a> {
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        let tag: u8 = s.read_field("tag")?;
+        let length: u8 = s.read_field("length")?;
+        let value: &[u8] = s.field("value", |s| s.read_exact(length as usize))?;
+
+        Ok(Record { tag, value })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Packet<'a> {
+    version: u16,
+    records: [Record<'a>; 2],
+}
+
+impl<'a> Readable<'a> for Packet<'a> {
+    fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+        Ok(Packet {
+            version: s.read_field("version")?,
+            records: s.read_field("records")?,
+        })
+    }
+}
+
+const PACKET: &[u8] = &[
+    0x00, 0x01, // version
+    0x10, 0x02, 0xAA, 0xBB, // first record
+    0x20, 0x01, 0xCC, // second record
+];
+
+fn trace<'a, T: Readable<'a>>(data: &'a [u8]) -> (Result<T>, ParseMap) {
+    let mut reader: ByteReader = ByteReader::with_endian(data, Endian::Big);
+    reader.start_trace();
+    let value: Result<T> = reader.read_field("packet");
+    (value, reader.take_trace().unwrap())
+}
+
+#[test]
+fn tree() {
+    let (packet, map) = trace::<Packet>(PACKET);
+    assert_eq!(packet.unwrap().records[1].value, [0xCC]);
+
+    let tree: String = map.to_string();
+    let lines: Vec<&str> = tree.lines().collect();
+    assert_eq!(lines.len(), 11);
+    // The names of user types depend on the compiler
+    assert!(lines[0].starts_with("packet: trace::Packet") && lines[0].ends_with(" [0..9]"));
+    assert_eq!(lines[1], "  version: u16 [0..2]");
+    assert!(lines[2].starts_with("  records: [trace::Record") && lines[2].ends_with("; 2] [2..9]"));
+    assert!(lines[3].starts_with("    trace::Record") && lines[3].ends_with(" [2..6]"));
+    assert_eq!(lines[4], "      tag: u8 [2..3]");
+    assert_eq!(lines[5], "      length: u8 [3..4]");
+    assert_eq!(lines[6], "      value: &[u8] [4..6]");
+
+    let path: Vec<Option<&str>> = map.path(5).iter().map(|span| span.field()).collect();
+    assert_eq!(path, [Some("packet"), Some("records"), None, Some("value")]);
+    assert_eq!(map.label(5).unwrap(), "packet.records.value: &[u8]");
+    assert_eq!(map.label(1).unwrap(), "packet.version: u16");
+    assert_eq!(map.label(9), None);
+
+    let span: &Span = map.path(7).last().copied().unwrap();
+    assert_eq!(span.range(), 7..8);
+    assert_eq!(span.len(), 1);
+    assert_eq!(span.type_name(), "u8");
+    assert!(span.children().is_empty());
+}
+
+#[test]
+fn errors() {
+    let (packet, map) = trace::<Packet>(&PACKET[..8]);
+    assert!(matches!(packet, Err(Error::InsufficientData { .. })));
+
+    let path: Vec<&Span> = map.path(7);
+    assert_eq!(path.len(), 4);
+    assert!(path[..3].iter().all(|span| span.is_error()));
+    assert!(!path[3].is_error());
+    assert_eq!(map.spans()[0].range(), 0..8);
+    assert_eq!(map.label(7).unwrap(), "packet.records.length: u8");
+
+    let failed: &Span = path[2].children().last().unwrap();
+    assert_eq!(failed.field(), Some("value"));
+    assert!(failed.is_error() && failed.is_empty());
+
+    // The first record was read successfully
+    assert!(!map.path(2)[2].is_error());
+}
+
+#[test]
+fn rollback() {
+    let mut reader: ByteReader = ByteReader::with_endian(&PACKET[..6], Endian::Big);
+    reader.start_trace();
+
+    assert!(reader.try_read::<[u8; 16]>().is_err());
+    assert!(reader.take_trace().unwrap().is_empty());
+
+    reader.start_trace();
+    let value: u64 = reader.alt::<u64, (u64, u32)>().unwrap();
+    assert_eq!(value, 0x00011002);
+
+    {
+        let mut checkpoint = reader.checkpoint();
+        let _: u16 = checkpoint.read().unwrap();
+    }
+    let _: u8 = reader.peek::<u8>().unwrap();
+    let _: u8 = reader.read().unwrap();
+
+    let map: ParseMap = reader.take_trace().unwrap();
+    assert_eq!(map.to_string(), "u32 [0..4]\nu8 [4..5]");
+    assert!(!reader.is_tracing());
+}
+
+#[test]
+fn preview_and_clone() {
+    struct Probe(bool);
+
+    impl<'a> Readable<'a> for Probe {
+        fn read<'r>(mut s: ReadStream<'a, 'r>) -> Result<Self> {
+            let traced: bool = s.is_tracing();
+            let _: u8 = s.read()?;
+            Ok(Probe(traced))
+        }
+    }
+
+    let mut reader: ByteReader = ByteReader::new(PACKET);
+    assert!(!reader.read::<Probe>().unwrap().0);
+    assert_eq!(reader.take_trace(), None);
+
+    reader.start_trace();
+    assert!(reader.read::<Probe>().unwrap().0);
+
+    let mut clone: ByteReader = reader.clone();
+    assert!(!clone.read::<Probe>().unwrap().0);
+    assert_eq!(clone, reader);
+    assert_eq!(
+        format!("{:?}", ByteReader::new(&[1])),
+        "ByteReader { data: [1], pos: 0, endian: Native }"
+    );
+
+    let map: ParseMap = reader.take_trace().unwrap();
+    assert_eq!(map.spans().len(), 1);
+    assert_eq!(map.spans()[0].children()[0].range(), 1..2);
+}
+
+#[test]
+fn annotate() {
+    let (_, map) = trace::<Packet>(PACKET);
+    let reader: ByteReader = ByteReader::new(PACKET);
+
+    assert_eq!(
+        map.annotate(&reader.hexdump().clear_highlights().width(4).group(0)),
+        "00000000  00 01 10 02  |....|  packet.version: u16, packet.records.tag: u8, packet.records.length: u8\n\
+         00000004  aa bb 20 01  |.. .|  packet.records.value: &[u8], packet.records.tag: u8, packet.records.length: u8\n\
+         00000008  cc           |.|  packet.records.value: &[u8]"
+    );
+
+    // Only the selected bytes are annotated
+    assert_eq!(
+        map.annotate(&reader.hexdump().clear_highlights().ascii(false).range(4..6)),
+        "00000000              aa bb  packet.records.value: &[u8]"
+    );
+}
+
+#[test]
+fn json() {
+    let mut reader: ByteReader = ByteReader::new(&[0x01, 0x02]);
+    reader.start_trace();
+    let _: u8 = reader.read_field("quote\"d\n").unwrap();
+    let _: Option<u8> = reader.field("rest", |s| s.read()).ok();
+    let map: ParseMap = reader.take_trace().unwrap();
+
+    assert_eq!(
+        map.to_json(),
+        "[{\"start\":0,\"end\":1,\"type\":\"u8\",\"field\":\"quote\\\"d\\n\",\"error\":false,\"children\":[]},\
+          {\"start\":1,\"end\":2,\"type\":\"u8\",\"field\":\"rest\",\"error\":false,\"children\":[\
+          {\"start\":1,\"end\":2,\"type\":\"u8\",\"field\":null,\"error\":false,\"children\":[]}]}]"
+    );
+    assert_eq!(ParseMap::default().to_json(), "[]");
+    assert_eq!(ParseMap::default().to_string(), "");
+}
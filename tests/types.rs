@@ -0,0 +1,667 @@
+use bytecraft::common::Endian;
+use bytecraft::error::{Error, Result};
+use bytecraft::reader::ByteReader;
+use bytecraft::types::{
+    AsciiDecimal, AsciiHex, Bcd, DosDateTime, F16Dot16, F2Dot14, FileTime, Fixed, HfsTime,
+    InvalidDigit, MacAddr, MsGuid, NtpTimestamp, SNorm16, UNorm8, UnixNanos, UnixTime32,
+    UnixTime64, Uuid, BF16, F16, I24, I48, Q15, U24, U48,
+};
+use bytecraft::writer::ByteWriter;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn f16_roundtrip_all() {
+    for bits in 0..=u16::MAX {
+        let value: F16 = F16::from_bits(bits);
+        let converted: F16 = F16::from_f32(value.to_f32());
+
+        if value.is_nan() {
+            assert!(converted.is_nan());
+            assert_eq!(converted.to_bits() & 0x8000, bits & 0x8000);
+        } else {
+            assert_eq!(converted.to_bits(), bits);
+            assert_eq!(value.to_f64(), value.to_f32() as f64);
+        }
+    }
+}
+
+#[test]
+fn f16_rounding() {
+    // Midpoints between consecutive positive values round to even
+    for bits in 0..0x7BFFu16 {
+        let low: f32 = F16::from_bits(bits).to_f32();
+        let high: f32 = F16::from_bits(bits + 1).to_f32();
+        let mid: f32 = (low + high) / 2.0;
+        let even: u16 = if bits % 2 == 0 { bits } else { bits + 1 };
+
+        assert_eq!(F16::from_f32(mid).to_bits(), even);
+        assert_eq!(F16::from_f32(-mid).to_bits(), even | 0x8000);
+        assert_eq!(
+            F16::from_f32(f32::from_bits(mid.to_bits() - 1)).to_bits(),
+            bits
+        );
+        assert_eq!(
+            F16::from_f32(f32::from_bits(mid.to_bits() + 1)).to_bits(),
+            bits + 1
+        );
+    }
+
+    // Overflow and underflow
+    assert_eq!(F16::from_f32(65504.0), F16::MAX);
+    assert_eq!(F16::from_f32(65519.0), F16::MAX);
+    assert_eq!(F16::from_f32(65520.0), F16::INFINITY);
+    assert_eq!(F16::from_f32(-1e10), F16::NEG_INFINITY);
+    assert_eq!(F16::from_f32(1e-10).to_bits(), 0x0000);
+    assert_eq!(F16::from_f32(-1e-10).to_bits(), 0x8000);
+    assert_eq!(F16::from_f32(f32::MIN_POSITIVE / 2.0).to_bits(), 0x0000);
+    assert_eq!(F16::from_f32(3.0 * 2f32.powi(-26)).to_bits(), 0x0001);
+    assert_eq!(F16::MIN_POSITIVE.to_f32(), 2f32.powi(-14));
+
+    // Special values
+    assert!(F16::from_f32(f32::NAN).is_nan());
+    assert!(F16::NAN.to_f32().is_nan());
+    assert!(F16::from_f32(f32::from_bits(0x7F80_0001)).is_nan());
+    assert!(F16::from_f32(f32::INFINITY).is_infinite());
+    assert_ne!(F16::NAN, F16::NAN);
+    assert_eq!(F16::ZERO, F16::from_bits(0x8000));
+    assert!(F16::ONE > F16::ZERO);
+}
+
+#[test]
+fn bf16_conversion() {
+    for bits in 0..=u16::MAX {
+        let value: BF16 = BF16::from_bits(bits);
+        let converted: BF16 = BF16::from_f32(value.to_f32());
+
+        if value.is_nan() {
+            assert!(converted.is_nan());
+        } else {
+            assert_eq!(converted.to_bits(), bits);
+        }
+    }
+
+    assert_eq!(BF16::from_f32(1.0), BF16::ONE);
+    assert_eq!(
+        BF16::from_f32(f32::from_bits(0x3F80_8000)).to_bits(),
+        0x3F80
+    );
+    assert_eq!(
+        BF16::from_f32(f32::from_bits(0x3F81_8000)).to_bits(),
+        0x3F82
+    );
+    assert_eq!(
+        BF16::from_f32(f32::from_bits(0x3F80_8001)).to_bits(),
+        0x3F81
+    );
+    assert_eq!(BF16::from_f32(f32::MAX), BF16::INFINITY);
+    assert_eq!(
+        BF16::from_f32(f32::from_bits(0x0000_0001)).to_bits(),
+        0x0000
+    );
+    assert!(BF16::from_f32(f32::from_bits(0xFF80_0001)).is_nan());
+    assert!(BF16::NAN.is_nan() && !BF16::INFINITY.is_nan());
+    assert!(BF16::NEG_INFINITY.is_infinite());
+    assert_eq!(
+        format!("{:?} {}", BF16::ONE, F16::from_f32(-2.5)),
+        "1.0 -2.5"
+    );
+}
+
+#[test]
+fn float_read_write() -> Result<()> {
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&F16::from_f32(-2.0))?;
+    writer.write(&BF16::from_f32(-2.0))?;
+    writer.set_endian(Endian::Little);
+    writer.write(&F16::ONE)?;
+    writer.write(&BF16::ONE)?;
+    assert_eq!(buffer, [0xC0, 0x00, 0xC0, 0x00, 0x00, 0x3C, 0x80, 0x3F]);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<F16>()?.to_f32(), -2.0);
+    assert_eq!(reader.read::<F16>()?.to_f32(), -2.0);
+    assert_eq!(f32::from(reader.read::<BF16>()?), -2.0);
+    reader.set_endian(Endian::Little);
+    assert_eq!(reader.read::<[F16; 1]>()?, [F16::ONE]);
+    assert_eq!(f64::from(reader.read::<BF16>()?), 1.0);
+
+    Ok(())
+}
+
+#[test]
+fn uint_n() -> Result<()> {
+    let data: [u8; 8] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+    assert_eq!(reader.read_uint_n(3)?, 0x010203);
+    assert_eq!(reader.read_uint_n(5)?, 0x0405060708);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+    assert_eq!(reader.read_uint_n(1)?, 0x01);
+    assert_eq!(reader.read_uint_n(7)?, 0x08070605040302);
+
+    reader.reset();
+    assert_eq!(reader.read_uint_n(8)?, 0x0807060504030201);
+
+    reader.reset();
+    assert!(matches!(reader.read_uint_n(0), Err(Error::NotValid)));
+    assert!(matches!(reader.read_uint_n(9), Err(Error::NotValid)));
+    assert_eq!(reader.position(), 0);
+
+    reader.set_position(6)?;
+    assert!(matches!(
+        reader.read_uint_n(3),
+        Err(Error::InsufficientData { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn int_n() -> Result<()> {
+    let data: [u8; 6] = [0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Little);
+
+    assert_eq!(reader.read_int_n(3)?, 0x7FFFFF);
+    assert_eq!(reader.read_int_n(3)?, -0x800000);
+
+    reader.reset();
+    assert_eq!(reader.read_int_n(1)?, -1);
+    assert_eq!(reader.read_int_n(2)?, 0x7FFF);
+
+    reader.reset();
+    assert_eq!(reader.read_int_n(6)?, -0x7FFF_FF80_0001);
+
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+
+    writer.write_int_n(-2, 3)?;
+    writer.write_uint_n(0x0102, 2)?;
+    writer.write_int_n(-128, 1)?;
+
+    assert!(matches!(writer.write_int_n(128, 1), Err(Error::NotValid)));
+    assert!(matches!(writer.write_int_n(-129, 1), Err(Error::NotValid)));
+    assert!(matches!(
+        writer.write_uint_n(0x100, 1),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(writer.write_uint_n(0, 0), Err(Error::NotValid)));
+    assert_eq!(writer.position(), 6);
+
+    assert_eq!(&buffer[..6], &[0xFF, 0xFF, 0xFE, 0x01, 0x02, 0x80]);
+
+    Ok(())
+}
+
+#[test]
+fn int24_int48() -> Result<()> {
+    assert_eq!(U24::MAX.get(), 0xFF_FFFF);
+    assert_eq!(I24::MIN.get(), -0x80_0000);
+    assert_eq!(I48::MAX.get(), 0x7FFF_FFFF_FFFF);
+    assert_eq!(U24::BITS, 24);
+    assert_eq!(I24::new(0x80_0000), None);
+    assert!(matches!(U48::try_from(1u64 << 48), Err(Error::NotValid)));
+    assert_eq!(I48::try_from(-5i64)?.to_string(), "-5");
+
+    let mut buffer: [u8; 18] = [0u8; 18];
+    let mut writer: ByteWriter<&mut [u8]> =
+        ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+
+    writer.write(&U24::MAX)?;
+    writer.write(&I24::new(-1).unwrap())?;
+    writer.write(&U48::new(0x0102_0304_0506).unwrap())?;
+    writer.write(&I48::MIN)?;
+
+    assert_eq!(
+        buffer,
+        [
+            0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x80
+        ]
+    );
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Little);
+
+    assert_eq!(reader.peek::<U24>()?, U24::MAX);
+    assert_eq!(reader.read::<U24>()?, U24::MAX);
+    assert_eq!(i32::from(reader.read::<I24>()?), -1);
+    assert_eq!(u64::from(reader.read::<U48>()?), 0x0102_0304_0506);
+    assert_eq!(reader.read::<I48>()?, I48::MIN);
+    assert!(reader.is_eof());
+
+    Ok(())
+}
+
+#[test]
+fn fixed_conversion() {
+    assert_eq!(F2Dot14::from_bits(0x7000).to_f32(), 1.75);
+    assert_eq!(F2Dot14::from_bits(0x0001).to_f64(), 1.0 / 16384.0);
+    assert_eq!(F2Dot14::from_f32(-2.0).to_bits(), -0x8000);
+    assert_eq!(F16Dot16::from_f64(-1.25).to_bits(), -0x0001_4000);
+
+    // Round to nearest, ties to even
+    assert_eq!(Fixed::<i8, 1>::from_f32(0.25).to_bits(), 0);
+    assert_eq!(Fixed::<i8, 1>::from_f32(0.75).to_bits(), 2);
+    assert_eq!(Fixed::<i8, 1>::from_f32(-0.75).to_bits(), -2);
+    assert_eq!(Fixed::<i8, 1>::from_f32(0.8).to_bits(), 2);
+
+    // Saturation
+    assert_eq!(Q15::from_f32(1.0).to_bits(), i16::MAX);
+    assert_eq!(Q15::from_f32(-5.0).to_bits(), i16::MIN);
+    assert_eq!(Fixed::<u16, 8>::from_f32(-1.0).to_bits(), 0);
+    assert_eq!(Fixed::<u16, 8>::from_f32(f32::INFINITY).to_bits(), u16::MAX);
+    assert_eq!(Fixed::<i32, 16>::from_f32(f32::NAN).to_bits(), 0);
+
+    assert!(F2Dot14::from_f32(-1.0) < F2Dot14::from_f32(0.5));
+    assert_eq!(format!("{}", F16Dot16::from_f32(2.5)), "2.5");
+}
+
+#[test]
+fn norm_conversion() {
+    assert_eq!(UNorm8::from_f32(1.0), UNorm8::MAX);
+    assert_eq!(UNorm8::from_f32(0.0), UNorm8::MIN);
+    assert_eq!(UNorm8::from_f64(0.2).to_bits(), 51);
+    assert_eq!(UNorm8::from_bits(51).to_f64(), 0.2);
+    assert_eq!(UNorm8::from_f32(f32::NAN).to_bits(), 0);
+
+    assert_eq!(SNorm16::from_f32(1.0).to_bits(), 32767);
+    assert_eq!(SNorm16::from_f32(-2.0).to_bits(), -32767);
+    assert_eq!(SNorm16::from_bits(-32768).to_f32(), -1.0);
+    assert_eq!(SNorm16::from_bits(-32767).to_f32(), -1.0);
+    assert_eq!(SNorm16::from_f32(0.5).to_bits(), 16384);
+
+    for bits in 0..=u8::MAX {
+        let value: UNorm8 = UNorm8::from_bits(bits);
+        assert_eq!(UNorm8::from_f32(value.to_f32()), value);
+    }
+}
+
+#[test]
+fn fixed_read_write() -> Result<()> {
+    let mut buffer: [u8; 9] = [0u8; 9];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+
+    writer.write(&F2Dot14::from_f32(0.5))?;
+    writer.write(&F16Dot16::from_f32(-1.0))?;
+    writer.write(&UNorm8::MAX)?;
+    writer.write(&SNorm16::from_f32(-1.0))?;
+
+    assert_eq!(
+        buffer,
+        [0x20, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0xFF, 0x80, 0x01]
+    );
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+
+    assert_eq!(reader.peek::<F2Dot14>()?.to_f32(), 0.5);
+    assert_eq!(f32::from(reader.read::<F2Dot14>()?), 0.5);
+    assert_eq!(f64::from(reader.read::<F16Dot16>()?), -1.0);
+    assert_eq!(reader.read::<UNorm8>()?, UNorm8::MAX);
+    assert_eq!(reader.read::<SNorm16>()?.to_f32(), -1.0);
+    assert!(reader.is_eof());
+
+    Ok(())
+}
+
+fn invalid_digit<T: std::fmt::Debug>(result: Result<T>) -> InvalidDigit {
+    match result {
+        Err(Error::Custom(err)) => *err.downcast_ref::<InvalidDigit>().unwrap(),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn bcd() -> Result<()> {
+    let data: [u8; 11] = [
+        0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x99, 0x01,
+    ];
+
+    let mut reader: ByteReader = ByteReader::new(&data);
+    assert_eq!(reader.read_bcd(9)?, 999_999_999_999_999_999);
+    assert_eq!(reader.read_bcd(0)?, 0);
+
+    reader.reset();
+    assert!(matches!(reader.read_bcd(10), Err(Error::NotValid)));
+
+    let mut reader: ByteReader = ByteReader::new(&[0x12, 0xF4]);
+    let err: InvalidDigit = invalid_digit(reader.read_bcd(2));
+    assert_eq!((err.index(), err.value()), (2, 0x0F));
+    assert_eq!(err.to_string(), "Invalid digit 0x0F at index 2");
+
+    let mut buffer: [u8; 4] = [0u8; 4];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+
+    writer.write_bcd(7, 1)?;
+    writer.write(&Bcd::<2>(9876))?;
+    assert!(matches!(writer.write_bcd(100, 1), Err(Error::NotValid)));
+    assert!(matches!(
+        writer.write_bcd(1, 2),
+        Err(Error::InsufficientData { .. })
+    ));
+    assert!(matches!(
+        writer.write_bcd(1, usize::MAX),
+        Err(Error::InsufficientData { .. })
+    ));
+    assert_eq!(writer.position(), 3);
+    assert_eq!(buffer, [0x07, 0x98, 0x76, 0x00]);
+
+    let reader: ByteReader = ByteReader::new(&buffer);
+    assert_eq!(reader.peek::<Bcd<3>>()?, Bcd(79876));
+
+    Ok(())
+}
+
+#[test]
+fn ascii_digits() -> Result<()> {
+    let mut reader: ByteReader = ByteReader::new(b"0123456789abcdefABCDEF");
+    assert_eq!(
+        reader.read::<AsciiDecimal<10>>()?,
+        AsciiDecimal(123_456_789)
+    );
+    assert_eq!(reader.read::<AsciiHex<12>>()?, AsciiHex(0xABCD_EFAB_CDEF));
+
+    let mut reader: ByteReader = ByteReader::new(b"12a4");
+    let err: InvalidDigit = invalid_digit(reader.read::<AsciiDecimal<4>>());
+    assert_eq!((err.index(), err.value()), (2, b'a'));
+
+    let reader: ByteReader = ByteReader::new(b" FFG");
+    let err: InvalidDigit = invalid_digit(reader.peek::<AsciiHex<4>>());
+    assert_eq!((err.index(), err.value()), (0, b' '));
+
+    let mut reader: ByteReader = ByteReader::new(&[b'1', 0xC3, 0xA9]);
+    assert!(matches!(
+        reader.read::<AsciiDecimal<3>>(),
+        Err(Error::NotValidAscii)
+    ));
+
+    let mut reader: ByteReader = ByteReader::new(b"99999999999999999999");
+    assert!(matches!(
+        reader.read::<AsciiDecimal<20>>(),
+        Err(Error::NotValid)
+    ));
+
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::new(&mut buffer[..]);
+
+    writer.write(&AsciiDecimal::<3>(7))?;
+    writer.write(&AsciiHex::<5>(0xABC))?;
+    assert!(matches!(
+        writer.write(&AsciiDecimal::<2>(100)),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(&buffer, b"00700ABC");
+
+    Ok(())
+}
+
+#[test]
+fn unix_time() -> Result<()> {
+    let time: SystemTime = UNIX_EPOCH + Duration::new(1_700_000_000, 999_999_999);
+
+    assert_eq!(
+        UnixTime32::from_system_time(time)?,
+        UnixTime32(1_700_000_000)
+    );
+    assert_eq!(UnixTime64::try_from(time)?, UnixTime64(1_700_000_000));
+    assert_eq!(
+        UnixNanos::from_system_time(time)?,
+        UnixNanos(1_700_000_000_999_999_999)
+    );
+    assert_eq!(
+        SystemTime::try_from(UnixNanos(1_700_000_000_999_999_999))?,
+        time
+    );
+
+    let before: SystemTime = UNIX_EPOCH - Duration::new(10, 1);
+    assert!(matches!(
+        UnixTime32::from_system_time(before),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(UnixTime64::from_system_time(before)?, UnixTime64(-11));
+    assert_eq!(
+        UnixTime64(-11).to_system_time()?,
+        UNIX_EPOCH - Duration::from_secs(11)
+    );
+    assert!(matches!(
+        UnixTime64(-11).to_duration(),
+        Err(Error::NotValid)
+    ));
+
+    let late: SystemTime = UNIX_EPOCH + Duration::from_secs(1 << 32);
+    assert!(matches!(
+        UnixTime32::from_system_time(late),
+        Err(Error::NotValid)
+    ));
+    assert!(matches!(
+        UnixNanos::from_system_time(late + Duration::from_secs(1 << 34)),
+        Err(Error::NotValid)
+    ));
+
+    assert_eq!(
+        UnixTime32::from_duration(Duration::from_millis(2500))?,
+        UnixTime32(2)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn file_ntp_hfs_time() -> Result<()> {
+    let time: SystemTime = UNIX_EPOCH + Duration::new(1_000_000_000, 123_456_789);
+
+    let file_time: FileTime = FileTime::from_system_time(time)?;
+    assert_eq!(file_time, FileTime(126_444_736_001_234_567));
+    assert_eq!(file_time.to_system_time()?, time - Duration::from_nanos(89));
+    assert_eq!(FileTime(0).to_duration()?, Duration::ZERO);
+    assert_eq!(
+        FileTime(0).to_system_time()?,
+        UNIX_EPOCH - Duration::from_secs(11_644_473_600)
+    );
+    assert!(FileTime::from_system_time(UNIX_EPOCH - Duration::from_secs(11_644_473_601)).is_err());
+
+    let ntp: NtpTimestamp = NtpTimestamp::from_system_time(time)?;
+    assert_eq!(ntp.seconds(), 3_208_988_800);
+    assert_eq!(ntp.fraction(), 530_242_871);
+    assert_eq!(
+        ntp.to_duration()?,
+        Duration::new(3_208_988_800, 123_456_788)
+    );
+    assert_eq!(NtpTimestamp::new(0, 0).to_duration()?, Duration::ZERO);
+    assert!(
+        NtpTimestamp::from_system_time(UNIX_EPOCH + Duration::from_secs(2_100_000_000)).is_err()
+    );
+
+    assert_eq!(HfsTime::from_system_time(time)?, HfsTime(3_082_844_800));
+    assert_eq!(HfsTime(0).to_duration()?, Duration::ZERO);
+    assert_eq!(
+        HfsTime(0).to_system_time()?,
+        UNIX_EPOCH - Duration::from_secs(2_082_844_800)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dos_date_time() -> Result<()> {
+    let time: DosDateTime = DosDateTime::new(2000, 2, 29, 23, 59, 59).unwrap();
+    assert_eq!((time.year(), time.month(), time.day()), (2000, 2, 29));
+    assert_eq!((time.hour(), time.minute(), time.second()), (23, 59, 58));
+    assert_eq!(
+        time.to_system_time()?,
+        UNIX_EPOCH + Duration::from_secs(951_868_798)
+    );
+    assert_eq!(
+        DosDateTime::from_system_time(UNIX_EPOCH + Duration::from_secs(951_868_799))?,
+        time
+    );
+
+    assert_eq!(
+        DosDateTime::new(1980, 1, 1, 0, 0, 0)
+            .unwrap()
+            .to_duration()?,
+        Duration::ZERO
+    );
+    assert_eq!(
+        DosDateTime::new(2107, 12, 31, 23, 59, 58).unwrap().date,
+        0xFF9F
+    );
+    assert_eq!(DosDateTime::new(2100, 2, 29, 0, 0, 0), None);
+    assert_eq!(DosDateTime::new(1979, 12, 31, 0, 0, 0), None);
+    assert_eq!(DosDateTime::new(2000, 13, 1, 0, 0, 0), None);
+    assert_eq!(DosDateTime::new(2000, 4, 31, 0, 0, 0), None);
+    assert_eq!(DosDateTime::new(2000, 1, 1, 24, 0, 0), None);
+
+    assert!(matches!(
+        DosDateTime::default().to_system_time(),
+        Err(Error::NotValid)
+    ));
+    assert!(DosDateTime::from_system_time(UNIX_EPOCH).is_err());
+
+    let mut buffer: [u8; 4] = [0u8; 4];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&time)?;
+    assert_eq!(buffer, [0xBF, 0x7D, 0x28, 0x5D]);
+
+    let reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<DosDateTime>()?, time);
+
+    Ok(())
+}
+
+#[test]
+fn time_read_write() -> Result<()> {
+    let mut buffer: [u8; 36] = [0u8; 36];
+    let mut writer: ByteWriter<&mut [u8]> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+
+    writer.write(&UnixTime32(1))?;
+    writer.write(&NtpTimestamp::new(2, 3))?;
+    writer.write(&Duration::new(4, 5))?;
+    writer.write(&HfsTime(6))?;
+    writer.write(&FileTime(7))?;
+
+    assert_eq!(
+        buffer,
+        [
+            0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 5, 0, 0, 0, 6, 0,
+            0, 0, 0, 0, 0, 0, 7
+        ]
+    );
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.read::<UnixTime32>()?, UnixTime32(1));
+    assert_eq!(reader.read::<NtpTimestamp>()?, NtpTimestamp::new(2, 3));
+    assert_eq!(reader.peek::<Duration>()?, Duration::new(4, 5));
+    assert_eq!(reader.read::<Duration>()?, Duration::new(4, 5));
+    assert_eq!(reader.read::<HfsTime>()?, HfsTime(6));
+    assert_eq!(reader.read::<FileTime>()?, FileTime(7));
+
+    let data: [u8; 12] = [0, 0, 0, 0, 0, 0, 0, 0, 0x3B, 0x9A, 0xCA, 0x00];
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+    assert!(matches!(reader.read::<Duration>(), Err(Error::NotValid)));
+
+    Ok(())
+}
+
+#[test]
+fn mac_addr() -> Result<()> {
+    let mac: MacAddr = "02:00:5E:10:00:ff".parse()?;
+    assert_eq!(mac, MacAddr([0x02, 0x00, 0x5E, 0x10, 0x00, 0xFF]));
+    assert_eq!(mac.to_string(), "02:00:5e:10:00:ff");
+    assert!(mac.is_local() && !mac.is_multicast() && !mac.is_broadcast());
+    assert!(MacAddr::BROADCAST.is_broadcast());
+
+    for text in [
+        "02:00:5e:10:00",
+        "02:00:5e:10:00:ff:01",
+        "02:00:5e:10:00:f",
+        "+2:00:5e:10:00:ff",
+        "",
+    ] {
+        assert!(
+            matches!(text.parse::<MacAddr>(), Err(Error::NotValid)),
+            "{}",
+            text
+        );
+    }
+
+    let mut buffer: [u8; 6] = [0u8; 6];
+    let mut writer: ByteWriter<&mut [u8]> =
+        ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&mac)?;
+    assert_eq!(buffer, mac.octets());
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.peek::<MacAddr>()?, mac);
+    assert_eq!(reader.read::<MacAddr>()?, mac);
+
+    Ok(())
+}
+
+#[test]
+fn uuid_and_guid() -> Result<()> {
+    let text: &str = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b";
+    let uuid: Uuid = text.parse()?;
+
+    assert_eq!(uuid.to_string(), text);
+    assert_eq!(uuid.version(), 1);
+    assert_eq!(uuid.as_bytes()[..4], [0xC1, 0x2A, 0x73, 0x28]);
+    assert_eq!(
+        Uuid::NIL.to_string(),
+        "00000000-0000-0000-0000-000000000000"
+    );
+    assert_eq!(
+        Uuid::MAX.to_string(),
+        "ffffffff-ffff-ffff-ffff-ffffffffffff"
+    );
+
+    for text in [
+        "c12a7328f81f11d2ba4b00a0c93ec93b",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93b-00",
+        "c12a732-8f81f-11d2-ba4b-00a0c93ec93b",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93g",
+        "{c12a7328-f81f-11d2-ba4b-00a0c93ec93b}",
+    ] {
+        assert!(
+            matches!(text.parse::<Uuid>(), Err(Error::NotValid)),
+            "{}",
+            text
+        );
+    }
+
+    // EFI system partition type GUID as stored in a GPT partition entry
+    let data: [u8; 32] = [
+        0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11, 0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9,
+        0x3B, 0xC1, 0x2A, 0x73, 0x28, 0xF8, 0x1F, 0x11, 0xD2, 0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E,
+        0xC9, 0x3B,
+    ];
+
+    let mut reader: ByteReader = ByteReader::with_endian(&data, Endian::Big);
+    let guid: MsGuid = reader.peek()?;
+    assert_eq!(reader.read::<MsGuid>()?, guid);
+    assert_eq!(reader.read::<Uuid>()?, uuid);
+    assert_eq!(Uuid::from(guid), uuid);
+    assert_eq!(guid, "{C12A7328-F81F-11D2-BA4B-00A0C93EC93B}".parse()?);
+    assert_eq!(
+        guid,
+        MsGuid::from_fields(
+            0xC12A7328,
+            0xF81F,
+            0x11D2,
+            [0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B]
+        )
+    );
+    assert!("{c12a7328-f81f-11d2-ba4b-00a0c93ec93b"
+        .parse::<MsGuid>()
+        .is_err());
+
+    let mut buffer: [u8; 32] = [0u8; 32];
+    let mut writer: ByteWriter<&mut [u8]> =
+        ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&MsGuid::from(uuid))?;
+    writer.write(&uuid)?;
+    assert_eq!(buffer, data);
+
+    Ok(())
+}
@@ -2,8 +2,11 @@ use bytecraft::common::{Endian, SeekFrom};
 use bytecraft::error::{Error, Result};
 use bytecraft::readable::Readable;
 use bytecraft::reader::ByteReader;
-use bytecraft::writer::writable::Writable;
-use bytecraft::writer::ByteWriter;
+use bytecraft::writer::writable::{DynWritable, Writable};
+use bytecraft::writer::{ByteWriter, WriteStream};
+use std::ffi::{CStr, CString};
+use std::path::Path;
+use std::rc::Rc;
 
 #[test]
 fn constructor() {
@@ -173,7 +176,7 @@ fn write_chars() -> Result<()> {
         writer.write(&c)?;
     }
 
-    assert_eq!("Привет World".as_bytes().len(), 18);
+    assert_eq!("Привет World".len(), 18);
     assert_eq!(buffer, "Привет World".as_bytes());
     Ok(())
 }
@@ -357,3 +360,304 @@ fn write_custom() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn context() -> Result<()> {
+    struct Compact;
+
+    struct Length(usize);
+
+    impl Writable for Length {
+        fn write<T: AsRef<[u8]> + AsMut<[u8]>>(
+            mut s: bytecraft::writer::WriteStream<T>,
+            val: &Self,
+        ) -> Result<()> {
+            match s.context::<Compact>() {
+                Some(_) => s.write(&(val.0 as u8)),
+                None => s.write(&(val.0 as u32)),
+            }
+        }
+    }
+
+    let mut buffer: [u8; 8] = [0u8; 8];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&Length(1))?;
+    assert_eq!(writer.position(), 4);
+
+    writer.set_context(Compact);
+    assert!(writer.context::<Compact>().is_some());
+    writer.write(&Length(2))?;
+    assert_eq!(writer.position(), 5);
+
+    writer.clear_context();
+    assert!(writer.context::<Compact>().is_none());
+    assert_eq!(buffer, [1, 0, 0, 0, 2, 0, 0, 0]);
+
+    let mut buffer: [u8; 1] = [0u8; 1];
+    let mut writer: ByteWriter<_> = ByteWriter::with_context(&mut buffer[..], Compact);
+    writer.write(&Length(3))?;
+    assert_eq!(buffer, [3]);
+
+    // A header switches the following fields to the compact form
+    struct Header;
+
+    impl Writable for Header {
+        fn write<T: AsRef<[u8]> + AsMut<[u8]>>(
+            mut s: bytecraft::writer::WriteStream<T>,
+            _val: &Self,
+        ) -> Result<()> {
+            s.write(&0xFFu8)?;
+            s.set_context(Compact);
+            Ok(())
+        }
+    }
+
+    let mut buffer: [u8; 2] = [0u8; 2];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&Header)?;
+    assert!(writer.context::<Compact>().is_some());
+    writer.write(&Length(4))?;
+    assert_eq!(buffer, [0xFF, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn write_with() -> Result<()> {
+    use bytecraft::writer::writable::WritableWith;
+
+    struct Block<'a> {
+        kind: u8,
+        payload: &'a [u8],
+    }
+
+    impl WritableWith<bool> for Block<'_> {
+        fn write_with<T: AsRef<[u8]> + AsMut<[u8]>>(
+            mut s: bytecraft::writer::WriteStream<T>,
+            val: &Self,
+            wide: bool,
+        ) -> Result<()> {
+            s.write(&val.kind)?;
+            match wide {
+                true => s.write(&(val.payload.len() as u16))?,
+                false => s.write(&(val.payload.len() as u8))?,
+            }
+            s.write_with(val.payload, val.payload.len())
+        }
+    }
+
+    let mut buffer: [u8; 9] = [0u8; 9];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+
+    writer.write_with(
+        &Block {
+            kind: 1,
+            payload: &[0xAA, 0xBB],
+        },
+        false,
+    )?;
+    writer.write_with(
+        &Block {
+            kind: 2,
+            payload: &[0xCC],
+        },
+        true,
+    )?;
+    writer.write_with(&0x11u8, ())?;
+    assert!(matches!(
+        writer.write_with(&vec![0u8; 2], 1),
+        Err(Error::NotValid)
+    ));
+    assert_eq!(writer.position(), 9);
+    assert_eq!(
+        buffer,
+        [0x01, 0x02, 0xAA, 0xBB, 0x02, 0x01, 0x00, 0xCC, 0x11]
+    );
+
+    let mut buffer: [u8; 4] = [0u8; 4];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    let values: Vec<u16> = vec![0x0102, 0x0304];
+    writer.write_with(&values, 2)?;
+    assert_eq!(buffer, [1, 2, 3, 4]);
+
+    Ok(())
+}
+
+#[test]
+fn write_unsized() -> Result<()> {
+    let text: &str = "abc";
+    let items: &[u16] = &[0x0102, 0x0304];
+    let cstr: &CStr = c"xy";
+    let path: &Path = Path::new("d/e");
+
+    let mut buffer: Vec<u8> = vec![0u8; 64];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(text)?;
+    writer.write(items)?;
+    writer.write(cstr)?;
+    writer.write(path)?;
+    writer.write(&vec!["f", "gh"])?;
+    let written: usize = writer.position();
+
+    // Same bytes as the owned counterparts
+    let mut expected: Vec<u8> = vec![0u8; 64];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut expected[..], Endian::Big);
+    writer.write(&text.to_string())?;
+    writer.write(&items.to_vec())?;
+    writer.write(&cstr.to_owned())?;
+    writer.write(&path.to_path_buf())?;
+    writer.write(&vec!["f".to_string(), "gh".to_string()])?;
+    assert_eq!(writer.position(), written);
+    assert_eq!(buffer, expected);
+
+    let mut reader: ByteReader = ByteReader::with_endian(&buffer, Endian::Big);
+    assert_eq!(reader.read::<String>()?, "abc");
+    assert_eq!(reader.read::<Vec<u16>>()?, items);
+    assert_eq!(reader.read::<CString>()?.as_c_str(), cstr);
+    assert_eq!(reader.read::<String>()?, "d/e");
+    assert_eq!(reader.read::<Vec<String>>()?, ["f", "gh"]);
+
+    // Unsized values behind pointers
+    let boxed: Box<str> = "ok".into();
+    let shared: Rc<[u8]> = Rc::from(&[9u8][..]);
+    let mut buffer: [u8; 11] = [0u8; 11];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&boxed)?;
+    writer.write(&shared)?;
+    assert_eq!(buffer, [2, 0, 0, 0, b'o', b'k', 1, 0, 0, 0, 9]);
+
+    Ok(())
+}
+
+#[test]
+fn write_trait_object() -> Result<()> {
+    trait Record {
+        fn tag(&self) -> u8;
+        fn body(&self) -> &[u8];
+    }
+
+    struct Ping;
+    struct Data(Vec<u8>);
+
+    impl Record for Ping {
+        fn tag(&self) -> u8 {
+            0x01
+        }
+
+        fn body(&self) -> &[u8] {
+            &[]
+        }
+    }
+
+    impl Record for Data {
+        fn tag(&self) -> u8 {
+            0x02
+        }
+
+        fn body(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl Writable for dyn Record {
+        fn write<T>(mut stream: WriteStream<T>, val: &Self) -> Result<()>
+        where
+            T: AsRef<[u8]> + AsMut<[u8]>,
+        {
+            stream.write(&val.tag())?;
+            stream.write(&(val.body().len() as u8))?;
+            stream.write_exact(val.body())
+        }
+    }
+
+    let records: Vec<Box<dyn Record>> = vec![Box::new(Ping), Box::new(Data(vec![0xAA, 0xBB]))];
+
+    let mut buffer: [u8; 10] = [0u8; 10];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Little);
+    writer.write(&records)?;
+    assert_eq!(buffer, [2, 0, 0, 0, 0x01, 0x00, 0x02, 0x02, 0xAA, 0xBB]);
+
+    let single: &dyn Record = &Ping;
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(single)?;
+    assert_eq!(writer.position(), 2);
+
+    Ok(())
+}
+
+#[test]
+fn write_dyn() -> Result<()> {
+    struct Header {
+        kind: u8,
+        size: u16,
+    }
+
+    impl Writable for Header {
+        fn write<T>(mut stream: WriteStream<T>, val: &Self) -> Result<()>
+        where
+            T: AsRef<[u8]> + AsMut<[u8]>,
+        {
+            stream.write(&val.kind)?;
+            stream.write(&val.size)
+        }
+    }
+
+    let records: Vec<Box<dyn DynWritable>> = vec![
+        Box::new(Header { kind: 7, size: 2 }),
+        Box::new([0xAAu8, 0xBB]),
+        Box::new(Some(0x0102u16)),
+    ];
+
+    // Slice buffer
+    let mut buffer: [u8; 12] = [0u8; 12];
+    let mut writer: ByteWriter<_> = ByteWriter::with_endian(&mut buffer[..], Endian::Big);
+    writer.write(&records)?;
+    assert_eq!(writer.position(), 12);
+    assert_eq!(buffer, [0, 0, 0, 3, 7, 0, 2, 0xAA, 0xBB, 1, 1, 2]);
+
+    // Owned buffer with another type produces the same bytes
+    let mut writer: ByteWriter<Vec<u8>> = ByteWriter::with_endian(vec![0u8; 12], Endian::Big);
+    for record in &records {
+        writer.write_dyn(record.as_ref())?;
+    }
+    assert_eq!(writer.position(), 8);
+    assert_eq!(&writer.into_inner()[..8], &buffer[4..]);
+
+    // Errors are propagated and the position is kept consistent
+    let shared: Vec<Box<dyn DynWritable + Send + Sync>> = vec![Box::new(1u32), Box::new(2u32)];
+    let mut buffer: [u8; 6] = [0u8; 6];
+    let mut writer: ByteWriter<_> = ByteWriter::new(&mut buffer[..]);
+    writer.write(&*shared[0])?;
+    assert!(matches!(
+        writer.write(&*shared[1]),
+        Err(Error::InsufficientData { .. })
+    ));
+    assert_eq!(writer.position(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn write_dyn_context() -> Result<()> {
+    struct Scaled(u16);
+
+    impl Writable for Scaled {
+        fn write<T>(mut stream: WriteStream<T>, val: &Self) -> Result<()>
+        where
+            T: AsRef<[u8]> + AsMut<[u8]>,
+        {
+            let scale: u16 = *stream.context::<u16>().unwrap_or(&1);
+            stream.write(&(val.0 * scale))
+        }
+    }
+
+    let value: Box<dyn DynWritable> = Box::new(Scaled(3));
+
+    let mut buffer: [u8; 2] = [0u8; 2];
+    let mut writer: ByteWriter<_> = ByteWriter::with_context(&mut buffer[..], 10u16);
+    writer.set_endian(Endian::Little);
+    writer.write(&value)?;
+    assert_eq!(buffer, [30, 0]);
+
+    Ok(())
+}